@@ -0,0 +1,129 @@
+//! Compares the time of linting a large crate module by module and in parallel.
+//!
+//! Run with `cargo bench --bench parallel_linting`. The size of the generated crate can be changed
+//! with the `MODULES` and `FUNCTIONS` env variables.
+
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use cairo_lang_compiler::db::RootDatabase;
+use cairo_lang_filesystem::db::init_dev_corelib;
+use cairo_lang_filesystem::ids::CrateId;
+use cairo_lang_semantic::inline_macros::get_default_plugin_suite;
+use cairo_lang_semantic::plugin::PluginSuite;
+use cairo_lang_semantic::test_utils::setup_test_crate_ex;
+use cairo_lang_semantic::SemanticDiagnostic;
+use cairo_lang_test_plugin::test_plugin_suite;
+use cairo_lint::config::LintConfig;
+use cairo_lint::context::get_unique_allowed_names;
+use cairo_lint::parallel::{collect_diagnostics, collect_diagnostics_in_parallel};
+use cairo_lint::plugin::{CairoLint, CairoLintAttributes};
+use cairo_lint::CairoLintConfig;
+
+#[path = "../tests/helpers/scarb.rs"]
+mod scarb;
+
+const DEFAULT_MODULES: usize = 64;
+const DEFAULT_FUNCTIONS: usize = 32;
+
+const FUNCTION_TEMPLATE: &str = r#"
+    fn function_{index}(a: u32, b: Option<u32>) -> u32 {
+        let c = a.clone();
+        let _d = ((c));
+        if a == c {
+            println!("equal");
+        } else if a == c {
+            println!("still equal");
+        }
+        let mut i = 0;
+        while i < a {
+            i += 1;
+        }
+        let e = match b {
+            Option::Some(v) => v,
+            Option::None => 0,
+        };
+        a - a + e
+    }
+"#;
+
+fn main() {
+    let Some(corelib) = find_corelib() else {
+        eprintln!("Missing corelib path. CORELIB_PATH env or Scarb managed corelib is required.");
+        return;
+    };
+    let modules = env_or("MODULES", DEFAULT_MODULES);
+    let functions = env_or("FUNCTIONS", DEFAULT_FUNCTIONS);
+    let code = generate_code(modules, functions);
+
+    println!("Linting {modules} modules with {functions} functions each.");
+    let (sequential_time, sequential_count) = measure(&code, &corelib, collect_diagnostics);
+    println!("module by module: {sequential_time:?} ({sequential_count} diagnostics)");
+    let (parallel_time, parallel_count) = measure(&code, &corelib, collect_diagnostics_in_parallel);
+    println!(
+        "in parallel ({} threads): {parallel_time:?} ({parallel_count} diagnostics)",
+        rayon::current_num_threads()
+    );
+    println!(
+        "speedup: {:.2}x",
+        sequential_time.as_secs_f64() / parallel_time.as_secs_f64()
+    );
+}
+
+/// Lints the code on a fresh database, so none of the queries are memoized beforehand.
+/// The generated crate is a virtual file, so the plugin has to check the compiler generated files too.
+fn measure(
+    code: &str,
+    corelib: &Path,
+    collect: fn(&RootDatabase, &[CrateId]) -> Vec<SemanticDiagnostic>,
+) -> (Duration, usize) {
+    let (db, crate_id) = setup_db(code, corelib);
+    let start = Instant::now();
+    let diagnostics = collect(&db, &[crate_id]);
+    (start.elapsed(), diagnostics.len())
+}
+
+fn setup_db(code: &str, corelib: &Path) -> (RootDatabase, CrateId) {
+    let tool_metadata: CairoLintConfig = get_unique_allowed_names()
+        .into_iter()
+        .map(|name| (name.to_string(), LintConfig::Enabled(true)))
+        .collect();
+    let mut lint_suite = PluginSuite::default();
+    lint_suite.add_analyzer_plugin_ex(Arc::new(CairoLint::new(true, tool_metadata)));
+    lint_suite.add_plugin::<CairoLintAttributes>();
+    let mut db = RootDatabase::builder()
+        .with_default_plugin_suite(get_default_plugin_suite())
+        .with_default_plugin_suite(test_plugin_suite())
+        .with_default_plugin_suite(lint_suite)
+        .build()
+        .unwrap();
+    init_dev_corelib(&mut db, corelib.to_path_buf());
+    let crate_id = setup_test_crate_ex(&db, code, None, None);
+    (db, crate_id)
+}
+
+fn generate_code(modules: usize, functions: usize) -> String {
+    (0..modules)
+        .map(|module| {
+            let body: String = (0..functions)
+                .map(|index| FUNCTION_TEMPLATE.replace("{index}", &index.to_string()))
+                .collect();
+            format!("mod module_{module} {{{body}}}\n")
+        })
+        .collect()
+}
+
+fn find_corelib() -> Option<PathBuf> {
+    std::env::var("CORELIB_PATH")
+        .map(PathBuf::from)
+        .ok()
+        .or_else(scarb::find_scarb_managed_core)
+}
+
+fn env_or(name: &str, default: usize) -> usize {
+    std::env::var(name)
+        .ok()
+        .and_then(|value| value.parse().ok())
+        .unwrap_or(default)
+}
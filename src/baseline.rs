@@ -0,0 +1,266 @@
+//! # Baseline for Cairo Lint
+//!
+//! The baseline is a record of the lint findings existing in the project at some point. It allows
+//! to adopt the Cairo lint in a codebase that has lots of findings at once: the existing findings
+//! are saved in the baseline file, and the later runs only report the new ones.
+//!
+//! Each finding is identified by the lint name, the file, and a fingerprint of the code it points to.
+//! The fingerprint is made of the code of the diagnostic and the path of the items enclosing it
+//! (e.g. `MyTrait::my_function`), but not its position, so the baseline survives line shifts.
+
+use std::collections::HashMap;
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use cairo_lang_diagnostics::DiagnosticEntry;
+use cairo_lang_filesystem::db::FilesGroup;
+use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_semantic::diagnostic::SemanticDiagnosticKind;
+use cairo_lang_semantic::SemanticDiagnostic;
+use cairo_lang_syntax::node::db::SyntaxGroup;
+use cairo_lang_syntax::node::kind::SyntaxKind;
+use cairo_lang_syntax::node::{ast, SyntaxNode, TypedSyntaxNode};
+use cairo_lang_utils::Upcast;
+use serde::{Deserialize, Serialize};
+
+use crate::context::LintRegistry;
+
+/// Version of the baseline file format.
+pub const BASELINE_VERSION: u32 = 1;
+
+/// A single lint finding recorded in the baseline.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct BaselineEntry {
+    /// Name of the lint rule which emitted the diagnostic.
+    pub lint: String,
+    /// Path of the file the diagnostic points to, relative to the root of the project if possible.
+    pub file: String,
+    /// Fingerprint of the code the diagnostic points to.
+    pub fingerprint: String,
+}
+
+/// The lint findings existing in the project at some point.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Baseline {
+    pub version: u32,
+    pub entries: Vec<BaselineEntry>,
+}
+
+/// Result of comparing the diagnostics with the baseline.
+#[derive(Debug, Default)]
+pub struct BaselineComparison {
+    /// The diagnostics which are not recorded in the baseline, including all the diagnostics
+    /// which do not come from the Cairo lint.
+    pub new_diagnostics: Vec<SemanticDiagnostic>,
+    /// The baseline entries which did not match any of the diagnostics, e.g. because the finding
+    /// was fixed. They can be removed from the baseline.
+    pub stale_entries: Vec<BaselineEntry>,
+}
+
+impl Baseline {
+    /// Creates the baseline from the Cairo lint diagnostics. The other diagnostics are skipped.
+    ///
+    /// # Arguments
+    ///
+    /// * `db` - The reference to the `dyn SemanticGroup` that the diagnostics were based upon.
+    /// * `diagnostics` - The diagnostics to record.
+    /// * `root` - The root of the project. The paths of the files are recorded relative to it.
+    /// * `lint_registry` - The lint registry of the plugin which reported the diagnostics.
+    pub fn new(
+        db: &(dyn SemanticGroup + 'static),
+        diagnostics: &[SemanticDiagnostic],
+        root: &Path,
+        lint_registry: &LintRegistry,
+    ) -> Self {
+        let mut entries = diagnostics
+            .iter()
+            .filter_map(|diagnostic| BaselineEntry::new(db, diagnostic, root, lint_registry))
+            .collect::<Vec<_>>();
+        entries.sort();
+        Self {
+            version: BASELINE_VERSION,
+            entries,
+        }
+    }
+
+    /// Reads the baseline from the file.
+    pub fn load(path: &Path) -> Result<Self> {
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read the baseline file {}", path.display()))?;
+        let baseline: Self = serde_json::from_str(&content)
+            .with_context(|| format!("Failed to parse the baseline file {}", path.display()))?;
+        anyhow::ensure!(
+            baseline.version == BASELINE_VERSION,
+            "Unsupported version of the baseline file {}: {}. Expected {}.",
+            path.display(),
+            baseline.version,
+            BASELINE_VERSION
+        );
+        Ok(baseline)
+    }
+
+    /// Writes the baseline to the file.
+    pub fn save(&self, path: &Path) -> Result<()> {
+        // Serializing a struct with string keys cannot fail.
+        let content = serde_json::to_string_pretty(self).unwrap() + "\n";
+        std::fs::write(path, content)
+            .with_context(|| format!("Failed to write the baseline file {}", path.display()))
+    }
+
+    /// Compares the diagnostics with the baseline.
+    /// Each baseline entry can match only a single diagnostic.
+    ///
+    /// # Arguments
+    ///
+    /// * `db` - The reference to the `dyn SemanticGroup` that the diagnostics were based upon.
+    /// * `diagnostics` - The diagnostics to compare with the baseline.
+    /// * `root` - The root of the project, the same as the one the baseline was created with.
+    /// * `lint_registry` - The lint registry of the plugin which reported the diagnostics.
+    pub fn compare(
+        &self,
+        db: &(dyn SemanticGroup + 'static),
+        diagnostics: Vec<SemanticDiagnostic>,
+        root: &Path,
+        lint_registry: &LintRegistry,
+    ) -> BaselineComparison {
+        let mut unmatched_entries: HashMap<&BaselineEntry, usize> = HashMap::new();
+        for entry in &self.entries {
+            *unmatched_entries.entry(entry).or_default() += 1;
+        }
+
+        let mut comparison = BaselineComparison::default();
+        for diagnostic in diagnostics {
+            let matched =
+                BaselineEntry::new(db, &diagnostic, root, lint_registry).is_some_and(|entry| {
+                    match unmatched_entries.get_mut(&entry) {
+                        Some(count) if *count > 0 => {
+                            *count -= 1;
+                            true
+                        }
+                        _ => false,
+                    }
+                });
+            if !matched {
+                comparison.new_diagnostics.push(diagnostic);
+            }
+        }
+
+        comparison.stale_entries = self
+            .entries
+            .iter()
+            .filter(|entry| {
+                let count = unmatched_entries.get_mut(entry).unwrap();
+                if *count > 0 {
+                    *count -= 1;
+                    true
+                } else {
+                    false
+                }
+            })
+            .cloned()
+            .collect();
+        comparison
+    }
+}
+
+impl BaselineEntry {
+    /// Creates the baseline entry for the diagnostic.
+    /// Returns `None` if the diagnostic does not come from any of the rules of the lint registry.
+    pub fn new(
+        db: &(dyn SemanticGroup + 'static),
+        diagnostic: &SemanticDiagnostic,
+        root: &Path,
+        lint_registry: &LintRegistry,
+    ) -> Option<Self> {
+        let SemanticDiagnosticKind::PluginDiagnostic(plugin_diag) = &diagnostic.kind else {
+            return None;
+        };
+        let lint = lint_registry.find_lint_for_diagnostic(plugin_diag)?;
+
+        let files_db: &dyn FilesGroup = db.upcast();
+        let path = diagnostic
+            .location(db)
+            .user_location(files_db)
+            .file_id
+            .full_path(files_db);
+        let file = Path::new(&path)
+            .strip_prefix(root)
+            .map_or(path.clone(), |path| {
+                path.to_string_lossy().replace('\\', "/")
+            });
+
+        let syntax_db: &dyn SyntaxGroup = db.upcast();
+        let node = plugin_diag.stable_ptr.lookup(syntax_db);
+        Some(Self {
+            lint: lint.allowed_name().to_string(),
+            file,
+            fingerprint: fingerprint(syntax_db, &node),
+        })
+    }
+}
+
+/// Computes the fingerprint of the code of the node, which does not depend on its position in the file.
+fn fingerprint(db: &dyn SyntaxGroup, node: &SyntaxNode) -> String {
+    let code = node
+        .get_text_without_trivia(db)
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ");
+    let items_path = enclosing_items_path(db, node);
+    format!(
+        "{:016x}",
+        fnv1a_hash(format!("{items_path}\n{code}").as_bytes())
+    )
+}
+
+/// Returns the path of the named items enclosing the node, e.g. `my_module::MyTrait::my_function`.
+fn enclosing_items_path(db: &dyn SyntaxGroup, node: &SyntaxNode) -> String {
+    let mut names = Vec::new();
+    let mut current_node = node.clone();
+    while let Some(parent) = current_node.parent(db) {
+        let name = match parent.kind(db) {
+            SyntaxKind::FunctionWithBody => Some(
+                ast::FunctionWithBody::from_syntax_node(db, parent.clone())
+                    .declaration(db)
+                    .name(db)
+                    .text(db),
+            ),
+            SyntaxKind::TraitItemFunction => Some(
+                ast::TraitItemFunction::from_syntax_node(db, parent.clone())
+                    .declaration(db)
+                    .name(db)
+                    .text(db),
+            ),
+            SyntaxKind::ItemModule => Some(
+                ast::ItemModule::from_syntax_node(db, parent.clone())
+                    .name(db)
+                    .text(db),
+            ),
+            SyntaxKind::ItemTrait => Some(
+                ast::ItemTrait::from_syntax_node(db, parent.clone())
+                    .name(db)
+                    .text(db),
+            ),
+            SyntaxKind::ItemImpl => Some(
+                ast::ItemImpl::from_syntax_node(db, parent.clone())
+                    .name(db)
+                    .text(db),
+            ),
+            _ => None,
+        };
+        names.extend(name);
+        current_node = parent;
+    }
+    names.reverse();
+    names.join("::")
+}
+
+/// The 64-bit FNV-1a hash. Unlike the hashers from the standard library, it is guaranteed to be
+/// the same on every platform and in every version, so it can be stored in the baseline file.
+fn fnv1a_hash(bytes: &[u8]) -> u64 {
+    const OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+    const PRIME: u64 = 0x100000001b3;
+    bytes.iter().fold(OFFSET_BASIS, |hash, byte| {
+        (hash ^ u64::from(*byte)).wrapping_mul(PRIME)
+    })
+}
@@ -0,0 +1,136 @@
+//! # Changed lines for Cairo Lint
+//!
+//! This module describes the lines of the files changed e.g. in a pull request. When they are
+//! passed to the [`crate::plugin::CairoLint`] plugin, it reports only the diagnostics pointing to
+//! the changed code. The changed lines can be read from a unified diff, e.g. the output of `git diff`.
+
+use std::collections::HashMap;
+use std::ops::RangeInclusive;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+
+/// The changed lines of the files. The lines are 1-based.
+///
+/// The paths of the files are usually relative to the root of the repository, so a file matches
+/// every path that ends with it.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ChangedLines {
+    files: HashMap<PathBuf, Vec<RangeInclusive<usize>>>,
+}
+
+impl ChangedLines {
+    /// Marks the range of the lines of the file as changed.
+    pub fn add_lines(&mut self, path: impl Into<PathBuf>, lines: RangeInclusive<usize>) {
+        self.files.entry(path.into()).or_default().push(lines);
+    }
+
+    /// Marks the whole file as changed.
+    pub fn add_file(&mut self, path: impl Into<PathBuf>) {
+        self.add_lines(path, 1..=usize::MAX);
+    }
+
+    /// Checks if any of the lines are changed in the file.
+    pub fn intersects(&self, path: &Path, lines: RangeInclusive<usize>) -> bool {
+        self.files
+            .iter()
+            .filter(|(changed_path, _)| path.ends_with(changed_path))
+            .flat_map(|(_, changed_lines)| changed_lines)
+            .any(|changed_lines| {
+                changed_lines.start() <= lines.end() && lines.start() <= changed_lines.end()
+            })
+    }
+
+    /// Reads the changed lines from the file with a unified diff.
+    pub fn from_patch_file(path: &Path) -> Result<Self> {
+        let diff = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read the patch file {}", path.display()))?;
+        Self::from_unified_diff(&diff)
+    }
+
+    /// Reads the changed lines from a unified diff. Only the added lines are marked as changed,
+    /// as the removed ones do not exist in the new version of the file.
+    /// The `a/` and `b/` prefixes of the paths (as in the output of `git diff`) are stripped.
+    pub fn from_unified_diff(diff: &str) -> Result<Self> {
+        let mut changed_lines = Self::default();
+        let mut current_file: Option<PathBuf> = None;
+        let mut lines = diff.lines();
+
+        while let Some(line) = lines.next() {
+            if let Some(path) = line.strip_prefix("+++ ") {
+                current_file = parse_diff_path(path);
+                continue;
+            }
+            let Some(hunk_header) = line.strip_prefix("@@ ") else {
+                continue;
+            };
+            let (mut old_remaining, mut new_remaining, mut line_number) =
+                parse_hunk_header(hunk_header)
+                    .with_context(|| format!("Invalid hunk header in the diff: {line}"))?;
+
+            let mut first_added_line: Option<usize> = None;
+            while old_remaining > 0 || new_remaining > 0 {
+                let Some(line) = lines.next() else {
+                    anyhow::bail!("Unexpected end of the diff inside a hunk");
+                };
+                if !line.starts_with('+') {
+                    if let (Some(start), Some(path)) = (first_added_line.take(), &current_file) {
+                        changed_lines.add_lines(path.clone(), start..=line_number - 1);
+                    }
+                }
+                match line.chars().next() {
+                    Some('+') => {
+                        first_added_line.get_or_insert(line_number);
+                        line_number += 1;
+                        new_remaining = new_remaining.saturating_sub(1);
+                    }
+                    Some('-') => old_remaining = old_remaining.saturating_sub(1),
+                    // A line with no newline at the end of the file.
+                    Some('\\') => {}
+                    // Some tools strip the space of the empty context lines.
+                    Some(' ') | None => {
+                        line_number += 1;
+                        old_remaining = old_remaining.saturating_sub(1);
+                        new_remaining = new_remaining.saturating_sub(1);
+                    }
+                    Some(_) => anyhow::bail!("Invalid line inside a hunk of the diff: {line}"),
+                }
+            }
+            if let (Some(start), Some(path)) = (first_added_line, &current_file) {
+                changed_lines.add_lines(path.clone(), start..=line_number - 1);
+            }
+        }
+        Ok(changed_lines)
+    }
+}
+
+/// Parses the path from the `+++` line of the diff. Returns `None` for the removed files.
+fn parse_diff_path(path: &str) -> Option<PathBuf> {
+    // The path can be followed by the timestamp, separated with a tab.
+    let path = path.split('\t').next().unwrap_or_default().trim_end();
+    if path == "/dev/null" {
+        return None;
+    }
+    let path = path
+        .strip_prefix("b/")
+        .or_else(|| path.strip_prefix("a/"))
+        .unwrap_or(path);
+    Some(PathBuf::from(path))
+}
+
+/// Parses the hunk header, e.g. `-1,3 +1,4 @@`.
+/// Returns the number of the old and new lines in the hunk, and the first new line number.
+fn parse_hunk_header(header: &str) -> Option<(usize, usize, usize)> {
+    let mut ranges = header.split_whitespace();
+    let (_, old_count) = parse_hunk_range(ranges.next()?.strip_prefix('-')?)?;
+    let (new_start, new_count) = parse_hunk_range(ranges.next()?.strip_prefix('+')?)?;
+    Some((old_count, new_count, new_start))
+}
+
+/// Parses the range of the hunk, e.g. `1,3`. The count is 1 if it is omitted.
+fn parse_hunk_range(range: &str) -> Option<(usize, usize)> {
+    match range.split_once(',') {
+        Some((start, count)) => Some((start.parse().ok()?, count.parse().ok()?)),
+        None => Some((range.parse().ok()?, 1)),
+    }
+}
@@ -0,0 +1,351 @@
+use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::DiagnosticEntry;
+use cairo_lang_filesystem::db::FilesGroup;
+use cairo_lang_filesystem::ids::FileId;
+use cairo_lang_filesystem::span::{TextPosition, TextSpan};
+use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_semantic::diagnostic::SemanticDiagnosticKind;
+use cairo_lang_semantic::SemanticDiagnostic;
+use cairo_lang_syntax::node::db::SyntaxGroup;
+use cairo_lang_syntax::node::kind::SyntaxKind;
+use cairo_lang_syntax::node::SyntaxNode;
+use cairo_lang_utils::Upcast;
+use itertools::Itertools;
+
+use crate::context::{Lint, LintRegistry};
+use crate::fixer::select_fixes;
+use crate::fixes::{fix_semantic_diagnostic_with_lint_registry, Edit, Suggestion};
+
+/// Kinds of the syntax nodes which can carry the lint level attributes.
+const ITEM_KINDS: [SyntaxKind; 12] = [
+    SyntaxKind::FunctionWithBody,
+    SyntaxKind::TraitItemFunction,
+    SyntaxKind::ItemConstant,
+    SyntaxKind::ItemModule,
+    SyntaxKind::ItemStruct,
+    SyntaxKind::ItemEnum,
+    SyntaxKind::ItemImpl,
+    SyntaxKind::ItemImplAlias,
+    SyntaxKind::ItemTrait,
+    SyntaxKind::ItemTypeAlias,
+    SyntaxKind::ItemExternFunction,
+    SyntaxKind::ItemExternType,
+];
+
+/// Kind of the code action, matching the `CodeActionKind` of the Language Server Protocol.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CodeActionKind {
+    /// Fixes or suppresses a single diagnostic.
+    QuickFix,
+    /// Fixes all of the diagnostics of a lint rule in the file.
+    SourceFixAll,
+}
+
+impl CodeActionKind {
+    /// The name of the kind used by the Language Server Protocol.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CodeActionKind::QuickFix => "quickfix",
+            CodeActionKind::SourceFixAll => "source.fixAll",
+        }
+    }
+}
+
+/// Position in the file. Both the line and the column are 0-based, as in the Language Server Protocol.
+/// The column is counted in the UTF-16 code units, which is the default position encoding of the protocol.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Position {
+    pub line: usize,
+    pub character: usize,
+}
+
+/// Range in the file, the end is exclusive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Range {
+    pub start: Position,
+    pub end: Position,
+}
+
+/// A single change of the file text, the text in the range is replaced with the new text.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TextEdit {
+    pub range: Range,
+    pub new_text: String,
+}
+
+/// An action which the language server can offer for a lint diagnostic.
+/// All of its edits change the same file and should be applied together.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CodeAction {
+    /// Human-readable description of the action, shown in the editor.
+    pub title: String,
+    pub kind: CodeActionKind,
+    /// Whether the action is the machine applicable fix of the diagnostic.
+    pub is_preferred: bool,
+    /// The file changed by the edits.
+    pub file_id: FileId,
+    /// Non-overlapping edits of the file.
+    pub edits: Vec<TextEdit>,
+}
+
+/// Gets the code actions for the lint diagnostic:
+/// * the fix of the diagnostic, if the lint rule has a fixer,
+/// * the action inserting `#[allow(lint_name)]` on the item enclosing the diagnostic,
+/// * the action fixing all of the diagnostics of the same lint rule in the file,
+///   if there is more than one of them that can be fixed.
+///
+/// The actions are based only on the content of the files in the database, so the overridden
+/// (not saved) content of the files is used. Nothing is written to the filesystem.
+/// Returns no actions for the diagnostics not emitted by the Cairo lint.
+///
+/// # Arguments
+///
+/// * `db` - The reference to the `dyn SemanticGroup` that the diagnostics were based upon.
+/// * `diagnostic` - The diagnostic to get the actions for.
+/// * `diagnostics` - All of the diagnostics of the file, used to fix all of the diagnostics of the same kind.
+/// * `lint_registry` - The registry of the lint rules used by the Cairo lint analyzer plugin.
+pub fn get_code_actions_for_diagnostic(
+    db: &(dyn SemanticGroup + 'static),
+    diagnostic: &SemanticDiagnostic,
+    diagnostics: &[SemanticDiagnostic],
+    lint_registry: &LintRegistry,
+) -> Vec<CodeAction> {
+    let Some((plugin_diag, lint)) = as_lint_diagnostic(diagnostic, lint_registry) else {
+        return vec![];
+    };
+    let file_id = diagnostic.location(db).file_id;
+    let mut actions = Vec::new();
+
+    if let Some(suggestion) =
+        fix_semantic_diagnostic_with_lint_registry(db, diagnostic, lint_registry)
+    {
+        let is_preferred = suggestion.applicability.is_machine_applicable();
+        actions.extend(CodeAction::new(
+            db.upcast(),
+            suggestion.title,
+            CodeActionKind::QuickFix,
+            is_preferred,
+            file_id,
+            &suggestion.edits,
+        ));
+    }
+
+    let node = plugin_diag.stable_ptr.lookup(db.upcast());
+    if let Some(edit) = allow_attribute_edit(db.upcast(), node, file_id, lint.allowed_name()) {
+        actions.extend(CodeAction::new(
+            db.upcast(),
+            format!("Allow `{}` for this item", lint.allowed_name()),
+            CodeActionKind::QuickFix,
+            false,
+            file_id,
+            &[edit],
+        ));
+    }
+
+    let same_kind_fixes = diagnostics
+        .iter()
+        .filter(|other| {
+            as_lint_diagnostic(other, lint_registry)
+                .is_some_and(|(_, other_lint)| other_lint.id() == lint.id())
+                && other.location(db).file_id == file_id
+        })
+        .filter_map(|other| fix_semantic_diagnostic_with_lint_registry(db, other, lint_registry))
+        .collect();
+    let same_kind_fixes = select_fixes(same_kind_fixes, false);
+    if same_kind_fixes.len() > 1 {
+        let edits = same_kind_fixes
+            .into_iter()
+            .flat_map(|fix| fix.edits)
+            .collect_vec();
+        actions.extend(CodeAction::new(
+            db.upcast(),
+            format!("Fix all `{}` issues in the file", lint.allowed_name()),
+            CodeActionKind::SourceFixAll,
+            false,
+            file_id,
+            &edits,
+        ));
+    }
+
+    actions
+}
+
+/// Gets the code actions for all of the lint diagnostics intersecting with the span in the file,
+/// e.g. the cursor position or the selection in the editor.
+/// The duplicated actions, like fixing all of the diagnostics of the same kind, are returned once.
+///
+/// See [`get_code_actions_for_diagnostic`] for the details.
+///
+/// # Arguments
+///
+/// * `db` - The reference to the `dyn SemanticGroup` that the diagnostics were based upon.
+/// * `file_id` - The file the span points to.
+/// * `span` - The span to get the actions for. It can be empty, i.e. point to a single position.
+/// * `diagnostics` - All of the diagnostics of the file.
+/// * `lint_registry` - The registry of the lint rules used by the Cairo lint analyzer plugin.
+pub fn get_code_actions_for_span(
+    db: &(dyn SemanticGroup + 'static),
+    file_id: FileId,
+    span: TextSpan,
+    diagnostics: &[SemanticDiagnostic],
+    lint_registry: &LintRegistry,
+) -> Vec<CodeAction> {
+    diagnostics
+        .iter()
+        .filter(|diagnostic| {
+            let location = diagnostic.location(db);
+            location.file_id == file_id
+                && location.span.start <= span.end
+                && span.start <= location.span.end
+        })
+        .flat_map(|diagnostic| {
+            get_code_actions_for_diagnostic(db, diagnostic, diagnostics, lint_registry)
+        })
+        .unique()
+        .collect()
+}
+
+impl CodeAction {
+    /// Creates the action from the edits of the file.
+    /// Returns `None` if the file is not present in the database.
+    fn new(
+        db: &dyn FilesGroup,
+        title: impl Into<String>,
+        kind: CodeActionKind,
+        is_preferred: bool,
+        file_id: FileId,
+        edits: &[Edit],
+    ) -> Option<Self> {
+        Some(Self {
+            title: title.into(),
+            kind,
+            is_preferred,
+            file_id,
+            edits: edits
+                .iter()
+                .map(|edit| TextEdit::new(db, edit, file_id))
+                .collect::<Option<_>>()?,
+        })
+    }
+
+    /// Converts the action back to a suggestion, e.g. to apply it with [`crate::get_fixed_file_content`].
+    /// Returns `None` if the file is not present in the database.
+    pub fn to_suggestion(&self, db: &dyn FilesGroup) -> Option<Suggestion> {
+        let edits = self
+            .edits
+            .iter()
+            .map(|edit| {
+                let span = TextSpan {
+                    start: edit
+                        .range
+                        .start
+                        .to_text_position(db, self.file_id)?
+                        .offset_in_file(db, self.file_id)?,
+                    end: edit
+                        .range
+                        .end
+                        .to_text_position(db, self.file_id)?
+                        .offset_in_file(db, self.file_id)?,
+                };
+                Some(Edit::replace(span, edit.new_text.clone()))
+            })
+            .collect::<Option<_>>()?;
+        Some(Suggestion::new(self.title.clone(), edits))
+    }
+}
+
+impl TextEdit {
+    fn new(db: &dyn FilesGroup, edit: &Edit, file_id: FileId) -> Option<Self> {
+        let position = edit.span.position_in_file(db, file_id)?;
+        Some(Self {
+            range: Range {
+                start: Position::from_text_position(db, file_id, position.start)?,
+                end: Position::from_text_position(db, file_id, position.end)?,
+            },
+            new_text: edit.replacement.clone(),
+        })
+    }
+}
+
+impl Position {
+    /// Converts the position in the file, whose column is counted in bytes.
+    fn from_text_position(
+        db: &dyn FilesGroup,
+        file_id: FileId,
+        position: TextPosition,
+    ) -> Option<Self> {
+        let line = file_line(db, file_id, position.line)?;
+        Some(Self {
+            line: position.line,
+            character: line.get(..position.col)?.encode_utf16().count(),
+        })
+    }
+
+    /// Converts the position back to the one whose column is counted in bytes.
+    /// The column past the end of the line points to the end of the line, as in the Language Server Protocol.
+    fn to_text_position(self, db: &dyn FilesGroup, file_id: FileId) -> Option<TextPosition> {
+        let line = file_line(db, file_id, self.line)?;
+        let mut col = line.len();
+        let mut code_units = 0;
+        for (index, c) in line.char_indices() {
+            if code_units >= self.character {
+                col = index;
+                break;
+            }
+            code_units += c.len_utf16();
+        }
+        Some(TextPosition {
+            line: self.line,
+            col,
+        })
+    }
+}
+
+/// Returns the text of the line of the file, without the line break.
+fn file_line(db: &dyn FilesGroup, file_id: FileId, line: usize) -> Option<String> {
+    db.file_content(file_id)?
+        .split('\n')
+        .nth(line)
+        .map(ToString::to_string)
+}
+
+/// Returns the plugin diagnostic and the lint rule which emitted it.
+fn as_lint_diagnostic<'a>(
+    diagnostic: &'a SemanticDiagnostic,
+    lint_registry: &'a LintRegistry,
+) -> Option<(&'a PluginDiagnostic, &'a dyn Lint)> {
+    let SemanticDiagnosticKind::PluginDiagnostic(plugin_diag) = &diagnostic.kind else {
+        return None;
+    };
+    let lint = lint_registry.find_lint_for_diagnostic(plugin_diag)?;
+    Some((plugin_diag, lint))
+}
+
+/// Creates the edit inserting the `allow` attribute of the lint above the item enclosing the node,
+/// with the same indentation as the item.
+fn allow_attribute_edit(
+    db: &dyn SyntaxGroup,
+    node: SyntaxNode,
+    file_id: FileId,
+    lint_name: &str,
+) -> Option<Edit> {
+    let item = node
+        .ancestors_with_self(db)
+        .find(|node| ITEM_KINDS.contains(&node.kind(db)))?;
+    let content = db.file_content(file_id)?;
+    let item_start = item.span_without_trivia(db).to_str_range().start;
+    let line_start = content[..item_start]
+        .rfind('\n')
+        .map_or(0, |index| index + 1);
+    let indentation = &content[line_start..item_start];
+    // Items placed after other code in the same line are not indented.
+    let indentation = if indentation.trim().is_empty() {
+        indentation
+    } else {
+        ""
+    };
+    Some(Edit::insert(
+        item.span_start_without_trivia(db),
+        format!("#[allow({lint_name})]\n{indentation}"),
+    ))
+}
@@ -0,0 +1,339 @@
+//! # Cairo Lint Configuration
+//!
+//! This module describes the values that can be set for the lint rules inside
+//! the `[tool.cairo-lint]` table of the `Scarb.toml` manifest.
+
+use std::fmt;
+use std::str::FromStr;
+
+use anyhow::{anyhow, bail, Error, Result};
+use cairo_lang_diagnostics::Severity;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::helper::{PANIC_PATH, PANIC_WITH_BYTE_ARRAY_PATH};
+
+/// The level on which a lint rule is reported.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum LintLevel {
+    /// The lint rule is not reported at all.
+    Allow,
+    /// The lint rule is reported as a warning.
+    Warn,
+    /// The lint rule is reported as an error.
+    Deny,
+    /// The lint rule is reported as an error, and it cannot be suppressed with `#[allow(...)]`.
+    Forbid,
+}
+
+impl LintLevel {
+    /// All the supported lint levels, from the least to the most strict one.
+    pub const ALL: [LintLevel; 4] = [
+        LintLevel::Allow,
+        LintLevel::Warn,
+        LintLevel::Deny,
+        LintLevel::Forbid,
+    ];
+
+    /// The name of the level, as it is written in the configuration.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LintLevel::Allow => "allow",
+            LintLevel::Warn => "warn",
+            LintLevel::Deny => "deny",
+            LintLevel::Forbid => "forbid",
+        }
+    }
+
+    /// Severity of the diagnostic emitted for a lint on this level.
+    /// Returns `None` if the lint should not be reported.
+    pub fn severity(&self) -> Option<Severity> {
+        match self {
+            LintLevel::Allow => None,
+            LintLevel::Warn => Some(Severity::Warning),
+            LintLevel::Deny | LintLevel::Forbid => Some(Severity::Error),
+        }
+    }
+
+    /// The default level of the lint rule, based on whether it is enabled by default.
+    pub fn from_enabled(enabled: bool) -> Self {
+        if enabled {
+            LintLevel::Warn
+        } else {
+            LintLevel::Allow
+        }
+    }
+}
+
+impl fmt::Display for LintLevel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for LintLevel {
+    type Err = Error;
+
+    fn from_str(value: &str) -> Result<Self> {
+        LintLevel::ALL
+            .into_iter()
+            .find(|level| level.as_str() == value)
+            .ok_or_else(|| {
+                anyhow!(
+                    "Unknown lint level '{}'. Expected one of: {}.",
+                    value,
+                    LintLevel::ALL.map(|level| level.as_str()).join(", ")
+                )
+            })
+    }
+}
+
+/// The key of the `[tool.cairo-lint]` table under which the call pattern rules are defined.
+pub const CALL_PATTERNS_KEY: &str = "call_patterns";
+
+/// A value assigned to a lint rule inside the `[tool.cairo-lint]` table.
+///
+/// ```toml
+/// [tool.cairo-lint]
+/// panic = true
+/// bool_comparison = false
+/// clone_on_copy = "deny"
+/// enum_variant_names = { level = "warn", min_variants = 3 }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum LintConfig {
+    /// `true` reports the lint rule as a warning, `false` disables it.
+    Enabled(bool),
+    /// One of the [`LintLevel`] names.
+    Level(String),
+    /// The call pattern rules, allowed only under the [`CALL_PATTERNS_KEY`].
+    CallPatterns(Vec<CallPatternConfig>),
+    /// The level of the lint rule together with its options, see [`LintOptions`].
+    Options(LintOptionsConfig),
+}
+
+impl LintConfig {
+    /// Gets the level configured by this value.
+    /// Returns `None` if the value configures only the options of the lint rule,
+    /// and an error if the level name is not supported.
+    pub fn level(&self) -> Result<Option<LintLevel>> {
+        match self {
+            LintConfig::Enabled(enabled) => Ok(Some(LintLevel::from_enabled(*enabled))),
+            LintConfig::Level(level) => level.parse().map(Some),
+            LintConfig::CallPatterns(_) => Err(anyhow!(
+                "Expected a boolean or a lint level, found a list of the call pattern rules."
+            )),
+            LintConfig::Options(config) => match &config.level {
+                Some(level) => level.level(),
+                None => Ok(None),
+            },
+        }
+    }
+
+    /// Gets the options configured by this value, if there are any.
+    pub fn options(&self) -> Option<&Map<String, Value>> {
+        match self {
+            LintConfig::Options(config) if !config.options.is_empty() => Some(&config.options),
+            _ => None,
+        }
+    }
+}
+
+/// A table assigned to a lint rule inside the `[tool.cairo-lint]` table, which configures its options
+/// and, optionally, its level.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LintOptionsConfig {
+    /// The level of the lint rule. If not specified, the level is not changed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub level: Option<Box<LintConfig>>,
+    /// The options of the lint rule, parsed into the options struct of the rule, see [`LintOptions`].
+    #[serde(flatten)]
+    pub options: Map<String, Value>,
+}
+
+/// The options of the predefined lint rules, which tune their behavior beyond the level.
+/// Each lint rule receives them through the context of its checking function,
+/// see [`crate::context::CheckContext`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LintOptions {
+    pub enum_variant_names: EnumVariantNamesOptions,
+    pub inefficient_while_comp: InefficientWhileCompOptions,
+    pub panic: PanicOptions,
+}
+
+impl LintOptions {
+    /// The names of the lint rules which accept options.
+    pub const LINT_NAMES: [&'static str; 3] =
+        ["enum_variant_names", "inefficient_while_comp", "panic"];
+
+    /// Sets the options of the lint rule with the given name.
+    /// Fails if the lint rule does not accept options or any of the options is invalid.
+    pub fn set(&mut self, lint_name: &str, options: &Map<String, Value>) -> Result<()> {
+        match lint_name {
+            "enum_variant_names" => self.enum_variant_names = parse_options(options)?,
+            "inefficient_while_comp" => self.inefficient_while_comp = parse_options(options)?,
+            "panic" => self.panic = parse_options(options)?,
+            _ => bail!("The lint does not accept any options."),
+        }
+        Ok(())
+    }
+}
+
+fn parse_options<T: DeserializeOwned>(options: &Map<String, Value>) -> Result<T> {
+    Ok(serde_json::from_value(Value::Object(options.clone()))?)
+}
+
+/// Options of the `enum_variant_names` lint rule.
+///
+/// ```toml
+/// [tool.cairo-lint]
+/// enum_variant_names = { min_variants = 3, check_suffix = false }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct EnumVariantNamesOptions {
+    /// The enums with fewer variants are not checked.
+    pub min_variants: usize,
+    /// Whether the variants starting with the same words are reported.
+    pub check_prefix: bool,
+    /// Whether the variants ending with the same words are reported.
+    pub check_suffix: bool,
+}
+
+impl Default for EnumVariantNamesOptions {
+    fn default() -> Self {
+        Self {
+            min_variants: 2,
+            check_prefix: true,
+            check_suffix: true,
+        }
+    }
+}
+
+/// Options of the `inefficient_while_comp` lint rule.
+///
+/// ```toml
+/// [tool.cairo-lint]
+/// inefficient_while_comp = { operators = ["<", ">"] }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct InefficientWhileCompOptions {
+    /// The comparison operators reported inside the exit conditions of the `while` loops.
+    pub operators: Vec<ComparisonOperator>,
+}
+
+impl Default for InefficientWhileCompOptions {
+    fn default() -> Self {
+        Self {
+            operators: ComparisonOperator::ALL.to_vec(),
+        }
+    }
+}
+
+/// An ordering comparison operator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ComparisonOperator {
+    #[serde(rename = "<")]
+    Lt,
+    #[serde(rename = "<=")]
+    Le,
+    #[serde(rename = ">")]
+    Gt,
+    #[serde(rename = ">=")]
+    Ge,
+}
+
+impl ComparisonOperator {
+    pub const ALL: [ComparisonOperator; 4] = [
+        ComparisonOperator::Lt,
+        ComparisonOperator::Le,
+        ComparisonOperator::Gt,
+        ComparisonOperator::Ge,
+    ];
+
+    /// The operator, as it is written in the code.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ComparisonOperator::Lt => "<",
+            ComparisonOperator::Le => "<=",
+            ComparisonOperator::Gt => ">",
+            ComparisonOperator::Ge => ">=",
+        }
+    }
+}
+
+/// Options of the `panic` lint rule.
+///
+/// ```toml
+/// [tool.cairo-lint]
+/// panic = { level = "warn", functions = ["core::panics::panic", "core::panic_with_felt252"] }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct PanicOptions {
+    /// Full paths of the functions whose calls are reported as panics.
+    pub functions: Vec<String>,
+}
+
+impl Default for PanicOptions {
+    fn default() -> Self {
+        Self {
+            functions: vec![
+                PANIC_PATH.to_string(),
+                PANIC_WITH_BYTE_ARRAY_PATH.to_string(),
+            ],
+        }
+    }
+}
+
+/// Configuration of a declarative lint rule, which reports all the calls of a certain function.
+/// The rules are defined in the `[tool.cairo-lint]` table, under the [`CALL_PATTERNS_KEY`].
+///
+/// ```toml
+/// [[tool.cairo-lint.call_patterns]]
+/// name = "replace_class_outside_upgrades"
+/// function = "core::starknet::syscalls::replace_class_syscall"
+/// message = "Replacing the class is allowed only in the `upgrades` module."
+/// level = "deny"
+/// excluded_modules = ["my_crate::upgrades"]
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CallPatternConfig {
+    /// Name of the rule, used in the tool metadata and in the level attributes.
+    pub name: String,
+    /// Full path of the called function, e.g. `my_crate::unsafe_helper`.
+    /// The path ending with `::*` matches all the functions inside the module, trait or impl.
+    /// The generic arguments of the called function are skipped when matching.
+    pub function: String,
+    /// Message of the diagnostics emitted by the rule.
+    pub message: String,
+    /// Default level of the rule. It can be overridden in the tool metadata, same as for the predefined rules.
+    #[serde(default)]
+    pub level: Option<LintConfig>,
+    /// Name of the category of the rule. Defaults to `correctness`.
+    #[serde(default)]
+    pub category: Option<String>,
+    /// Paths of the modules where the rule is checked, together with their submodules.
+    /// If empty, the rule is checked in all of the modules.
+    #[serde(default)]
+    pub modules: Vec<String>,
+    /// Paths of the modules where the rule is not checked, together with their submodules.
+    #[serde(default)]
+    pub excluded_modules: Vec<String>,
+}
+
+impl From<bool> for LintConfig {
+    fn from(enabled: bool) -> Self {
+        LintConfig::Enabled(enabled)
+    }
+}
+
+impl From<LintLevel> for LintConfig {
+    fn from(level: LintLevel) -> Self {
+        LintConfig::Level(level.as_str().to_string())
+    }
+}
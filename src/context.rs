@@ -1,9 +1,13 @@
+use crate::config::{CallPatternConfig, LintConfig, LintLevel, LintOptions, CALL_PATTERNS_KEY};
+use crate::diagnostics::{LintDiagnostic, LintDiagnosticDetails};
+use crate::fixes::Suggestion;
 use crate::lints::bitwise_for_parity_check::check_bitwise_for_parity;
 use crate::lints::bitwise_for_parity_check::BitwiseForParity;
 use crate::lints::bool_comparison::check_bool_comparison;
 use crate::lints::bool_comparison::BoolComparison;
 use crate::lints::breaks::check_break;
 use crate::lints::breaks::BreakUnit;
+use crate::lints::call_patterns::CallPatternRule;
 use crate::lints::clone_on_copy::{check_clone_on_copy, CloneOnCopy};
 use crate::lints::double_comparison::check_double_comparison;
 use crate::lints::double_comparison::ContradictoryComparison;
@@ -40,29 +44,36 @@ use crate::lints::int_op_one::IntegerGreaterEqualMinusOne;
 use crate::lints::int_op_one::IntegerGreaterEqualPlusOne;
 use crate::lints::int_op_one::IntegerLessEqualMinusOne;
 use crate::lints::int_op_one::IntegerLessEqualPlusOne;
+use crate::lints::lint_attributes::{
+    RemovedLintName, RenamedLintName, UnfulfilledLintExpectation, UnknownLintName, UnusedLintAllow,
+};
 use crate::lints::loops::loop_for_while::check_loop_for_while;
 use crate::lints::loops::loop_for_while::LoopForWhile;
 use crate::lints::loops::loop_match_pop_front::check_loop_match_pop_front;
 use crate::lints::loops::loop_match_pop_front::LoopMatchPopFront;
 use crate::lints::manual::manual_assert::check_manual_assert;
 use crate::lints::manual::manual_assert::ManualAssert;
-use crate::lints::manual::manual_err::check_manual_err;
 use crate::lints::manual::manual_err::ManualErr;
-use crate::lints::manual::manual_expect::check_manual_expect;
+use crate::lints::manual::manual_err::{check_manual_err, check_manual_err_if};
 use crate::lints::manual::manual_expect::ManualExpect;
-use crate::lints::manual::manual_expect_err::check_manual_expect_err;
+use crate::lints::manual::manual_expect::{check_manual_expect, check_manual_expect_if};
 use crate::lints::manual::manual_expect_err::ManualExpectErr;
-use crate::lints::manual::manual_is::check_manual_is;
+use crate::lints::manual::manual_expect_err::{
+    check_manual_expect_err, check_manual_expect_err_if,
+};
 use crate::lints::manual::manual_is::ManualIsErr;
 use crate::lints::manual::manual_is::ManualIsNone;
 use crate::lints::manual::manual_is::ManualIsOk;
 use crate::lints::manual::manual_is::ManualIsSome;
-use crate::lints::manual::manual_ok::check_manual_ok;
+use crate::lints::manual::manual_is::{check_manual_is, check_manual_is_if};
 use crate::lints::manual::manual_ok::ManualOk;
-use crate::lints::manual::manual_ok_or::check_manual_ok_or;
+use crate::lints::manual::manual_ok::{check_manual_ok, check_manual_ok_if};
 use crate::lints::manual::manual_ok_or::ManualOkOr;
-use crate::lints::manual::manual_unwrap_or_default::check_manual_unwrap_or_default;
+use crate::lints::manual::manual_ok_or::{check_manual_ok_or, check_manual_ok_or_if};
 use crate::lints::manual::manual_unwrap_or_default::ManualUnwrapOrDefault;
+use crate::lints::manual::manual_unwrap_or_default::{
+    check_manual_unwrap_or_default, check_manual_unwrap_or_default_if,
+};
 use crate::lints::panic::check_panic_usage;
 use crate::lints::panic::PanicInCode;
 use crate::lints::performance::inefficient_while_comp::check_inefficient_while_comp;
@@ -71,21 +82,73 @@ use crate::lints::redundant_brackets_in_enum_call::check_redundant_brackets_in_e
 use crate::lints::redundant_brackets_in_enum_call::RedundantBracketsInEnumCall;
 use crate::lints::redundant_op::check_redundant_operation;
 use crate::lints::redundant_op::RedundantOperation;
-use crate::lints::single_match::check_single_matches;
+use crate::lints::single_match::check_single_match;
 use crate::lints::single_match::DestructMatch;
 use crate::lints::single_match::EqualityMatch;
+use crate::queries::FunctionBodyVisitor;
+use crate::CairoLintConfig;
+use anyhow::{bail, Result};
 use cairo_lang_defs::{ids::ModuleItemId, plugin::PluginDiagnostic};
 use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_syntax::node::ids::SyntaxStablePtrId;
 use cairo_lang_syntax::node::SyntaxNode;
 use itertools::Itertools;
+use serde::Serialize;
 use std::collections::HashMap;
-use std::sync::LazyLock;
+use std::sync::{Arc, LazyLock, Mutex};
 
 /// Type describing a linter group's rule checking function.
-type CheckingFunction = fn(&dyn SemanticGroup, &ModuleItemId, &mut Vec<PluginDiagnostic>);
+pub type CheckingFunction =
+    fn(&dyn SemanticGroup, &ModuleItemId, &CheckContext, &mut Vec<LintDiagnostic>);
+
+/// The context shared by all of the checking functions (and visitor callbacks) during a single
+/// run of the linter.
+#[derive(Debug, Clone, Copy)]
+pub struct CheckContext<'a> {
+    options: &'a LintOptions,
+    call_pattern_rules: &'a [Arc<CallPatternRule>],
+}
+
+impl<'a> CheckContext<'a> {
+    pub fn new(options: &'a LintOptions) -> Self {
+        Self {
+            options,
+            call_pattern_rules: &[],
+        }
+    }
+
+    /// Sets the call pattern rules checked inside the current item.
+    pub fn with_call_pattern_rules(
+        mut self,
+        call_pattern_rules: &'a [Arc<CallPatternRule>],
+    ) -> Self {
+        self.call_pattern_rules = call_pattern_rules;
+        self
+    }
+
+    /// The options of the lint rules, as configured in the tool metadata.
+    pub fn options(&self) -> &'a LintOptions {
+        self.options
+    }
+
+    /// The call pattern rules checked inside the current item.
+    pub fn call_pattern_rules(&self) -> &'a [Arc<CallPatternRule>] {
+        self.call_pattern_rules
+    }
+}
+
+/// The way a lint rule group looks for its diagnostics.
+#[derive(Clone, Copy)]
+pub enum LintChecker {
+    /// A function checking the whole module item on its own.
+    Item(CheckingFunction),
+    /// Callbacks fired during the single walk over the function bodies of the module item,
+    /// shared with all of the other groups. See [`walk_function_bodies`](crate::queries::walk_function_bodies).
+    FunctionBody(FunctionBodyVisitor),
+}
 
 /// Enum representing the kind of a linter. Some lint rules might have the same kind.
-#[derive(Debug, PartialEq, Clone, Copy)]
+#[derive(Debug, PartialEq, Clone, Copy, Serialize)]
 pub enum CairoLintKind {
     DestructMatch,
     MatchForEquality,
@@ -126,16 +189,114 @@ pub enum CairoLintKind {
     EnumVariantNames,
     CloneOnCopy,
     EnumEmptyVariantBrackets,
+    UnfulfilledLintExpectation,
+    UnusedLintAllow,
+    UnknownLintName,
+    RenamedLintName,
+    RemovedLintName,
+    /// Lint rules registered by the third-party crates.
+    Custom,
+    /// Declarative lint rules reporting the calls of certain functions.
+    CallPattern,
+}
+
+/// User-facing category of a lint rule.
+/// All of the lint rules from a certain category can be configured at once by using the category name.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum LintCategory {
+    /// Code that is outright wrong or useless.
+    Correctness,
+    /// Code that should be written in a more idiomatic way.
+    Style,
+    /// Code that does something simple but in a complex way.
+    Complexity,
+    /// Code that can be written to run faster or cheaper.
+    Perf,
+    /// Lints which are rather strict or have occasional false positives.
+    Pedantic,
+}
+
+impl LintCategory {
+    /// All of the lint categories.
+    pub const ALL: [LintCategory; 5] = [
+        LintCategory::Correctness,
+        LintCategory::Style,
+        LintCategory::Complexity,
+        LintCategory::Perf,
+        LintCategory::Pedantic,
+    ];
+
+    /// The name of the category, as it is used in the configuration and in the `allow` attribute.
+    pub fn name(&self) -> &'static str {
+        match self {
+            LintCategory::Correctness => "correctness",
+            LintCategory::Style => "style",
+            LintCategory::Complexity => "complexity",
+            LintCategory::Perf => "perf",
+            LintCategory::Pedantic => "pedantic",
+        }
+    }
+
+    /// Finds the category by its name.
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|category| category.name() == name)
+    }
+}
+
+/// Documentation of a lint rule, available at runtime.
+/// It is used to explain the lint rule to the user, see [`crate::explain`], and to generate the documentation website.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct LintDocs {
+    /// What the lint rule checks for.
+    pub description: &'static str,
+    /// Why the reported code is a problem, if it is not obvious from the description.
+    pub rationale: Option<&'static str>,
+    /// Code reported by the lint rule.
+    pub bad_example: Option<&'static str>,
+    /// The bad example rewritten, so it is not reported anymore.
+    pub good_example: Option<&'static str>,
 }
 
 pub trait Lint: Sync + Send {
     /// A name that is going to be registered by the compiler as an allowed lint to be ignored.
     /// Some multiple lint rules might have the same allowed name. This way all of the will be ignored with only one allow attribute.
     fn allowed_name(&self) -> &'static str;
-    /// A predefined message that is going to appear in the compiler's diagnostic output. It should be the same as the one in the lint check function.
+    /// A stable identifier of the lint rule, e.g. `CL0012`. Unlike the name, it is unique for every rule.
+    /// It is reported as the code of the diagnostics of the rule in the machine-readable outputs.
+    fn id(&self) -> &'static str;
+    /// A predefined message that is going to appear in the compiler's diagnostic output.
     fn diagnostic_message(&self) -> &'static str;
     /// The kind of the lint rule. Some lint rules might have the same kind.
     fn kind(&self) -> CairoLintKind;
+    /// The user-facing category of the lint rule.
+    fn category(&self) -> LintCategory;
+
+    /// The documentation of the lint rule.
+    /// By default the lint rule is not documented.
+    fn docs(&self) -> LintDocs {
+        LintDocs::default()
+    }
+
+    /// The message of the diagnostic emitted by the lint rule.
+    fn formatted_message(&self) -> String {
+        self.diagnostic_message().to_string()
+    }
+
+    /// A template of the message describing a single occurrence of the linted code,
+    /// e.g. "this `match` can be replaced with `{replacement}`".
+    /// The `{name}` placeholders are filled with the arguments passed to [`Lint::formatted_message_with_args`].
+    /// By default it is the bare [`Lint::diagnostic_message`].
+    fn message_template(&self) -> &'static str {
+        self.diagnostic_message()
+    }
+
+    /// The message of the diagnostic emitted by the lint rule, with the concrete details of the linted code
+    /// filled into the [`Lint::message_template`].
+    fn formatted_message_with_args(&self, args: &[(&str, &str)]) -> String {
+        fill_message_template(self.message_template(), args)
+    }
 
     /// Checks if the lint rule is enabled.
     /// By default all of the rules are enabled.
@@ -143,6 +304,12 @@ pub trait Lint: Sync + Send {
         true
     }
 
+    /// The level of the lint rule if it is not configured in the tool metadata.
+    /// By default it is based on whether the rule is enabled.
+    fn default_level(&self) -> LintLevel {
+        LintLevel::from_enabled(self.is_enabled())
+    }
+
     /// Checks if the instance has a fixer.
     /// By default it return false.
     fn has_fixer(&self) -> bool {
@@ -158,35 +325,402 @@ pub trait Lint: Sync + Send {
     /// # Arguments
     ///
     /// * `db` - A reference to the `dyn SemanticGroup`
-    /// * `diag` - A reference to the SemanticDiagnostic to be fixed
+    /// * `node` - The node the diagnostic points to
     ///
     /// # Returns
-    /// An `Option<(SyntaxNode, String)>` where the `SyntaxNode` represents the node to be
-    /// replaced, and the `String` is the suggested replacement. Returns `None` if no fix
-    /// is available for the given diagnostic.
+    /// An `Option<Suggestion>` with the edits fixing the diagnostic, together with its title and applicability.
+    /// Returns `None` if no fix is available for the given diagnostic.
     ///
     /// By default there is no fixing procedure for a Lint.
     #[expect(unused_variables)]
-    fn fix(&self, db: &dyn SemanticGroup, node: SyntaxNode) -> Option<(SyntaxNode, String)> {
+    fn fix(&self, db: &dyn SemanticGroup, node: SyntaxNode) -> Option<Suggestion> {
         unreachable!("fix() has been called for a lint which has_fixer() returned false")
     }
+
+    /// Attempts to generate a fix for the diagnostic with the given details.
+    /// It lets the fix depend on the details carried by the diagnostic, e.g. on the options
+    /// which the diagnostic was emitted with.
+    ///
+    /// By default it is the [`Lint::fix`] of the node.
+    #[expect(unused_variables)]
+    fn fix_diagnostic(
+        &self,
+        db: &dyn SemanticGroup,
+        node: SyntaxNode,
+        details: &LintDiagnosticDetails,
+    ) -> Option<Suggestion> {
+        self.fix(db, node)
+    }
 }
 
 /// A group of lint rules.
 ///
 /// We want to group lint rules because some lint rules can share an allowed name for compiler or the checking function.
 pub struct LintRuleGroup {
-    /// Collection of `LintRule`s that are directly connected to this group's checking function.
+    /// Collection of `LintRule`s that are directly connected to this group's checker.
     lints: Vec<Box<dyn Lint>>,
-    /// A Function which will be fired during linter plugin analysis.
+    /// A checker which will be fired during linter plugin analysis.
     /// This one should emit certain diagnostics in order to later identify (and maybe fix) the linting problem.
-    check_function: CheckingFunction,
+    checker: LintChecker,
+}
+
+impl LintRuleGroup {
+    /// Creates a group of lint rules, whose diagnostics are emitted by the checking function.
+    pub fn new(lints: Vec<Box<dyn Lint>>, check_function: CheckingFunction) -> Self {
+        Self {
+            lints,
+            checker: LintChecker::Item(check_function),
+        }
+    }
+
+    /// Creates a group of lint rules, whose diagnostics are emitted by the callbacks of the visitor.
+    pub fn with_visitor(lints: Vec<Box<dyn Lint>>, visitor: FunctionBodyVisitor) -> Self {
+        Self {
+            lints,
+            checker: LintChecker::FunctionBody(visitor),
+        }
+    }
+
+    /// The lint rules of the group.
+    #[allow(clippy::borrowed_box)]
+    pub fn lints(&self) -> impl Iterator<Item = &Box<dyn Lint>> {
+        self.lints.iter()
+    }
+
+    /// The checker of the lint rules of the group.
+    pub fn checker(&self) -> LintChecker {
+        self.checker
+    }
+}
+
+impl std::fmt::Debug for LintRuleGroup {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("LintRuleGroup")
+            .field(
+                "lints",
+                &self
+                    .lints
+                    .iter()
+                    .map(|lint| lint.allowed_name())
+                    .collect_vec(),
+            )
+            .finish_non_exhaustive()
+    }
+}
+
+/// A name which does not belong to any lint rule anymore, but it is still accepted in the tool metadata
+/// and in the lint level attributes, so the projects using it don't break.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DeprecatedLintName {
+    /// The lint rule (or category) was renamed. The old name works as an alias of the new one.
+    Renamed {
+        old_name: &'static str,
+        new_name: &'static str,
+    },
+    /// The lint rule was removed. The name does not change the level of any lint rule.
+    Removed {
+        name: &'static str,
+        reason: &'static str,
+    },
+}
+
+impl DeprecatedLintName {
+    /// The name which is not used anymore.
+    pub fn name(&self) -> &'static str {
+        match *self {
+            DeprecatedLintName::Renamed { old_name, .. } => old_name,
+            DeprecatedLintName::Removed { name, .. } => name,
+        }
+    }
+
+    /// The message describing why the name should not be used anymore.
+    pub fn message(&self) -> String {
+        match *self {
+            DeprecatedLintName::Renamed { old_name, new_name } => RenamedLintName
+                .formatted_message_with_args(&[("old_name", old_name), ("new_name", new_name)]),
+            DeprecatedLintName::Removed { name, reason } => {
+                RemovedLintName.formatted_message_with_args(&[("name", name), ("reason", reason)])
+            }
+        }
+    }
+}
+
+/// Registry of the lint rules used by a single instance of the Cairo lint plugin.
+/// It contains all of the predefined lint rules, and the ones registered by the third-party crates.
+///
+/// It also keeps the details of the diagnostics reported by the plugin, e.g. the id of the lint rule
+/// which reported each of them. The clones of the registry share the details.
+#[derive(Debug, Clone, Default)]
+pub struct LintRegistry {
+    custom_lint_groups: Vec<Arc<LintRuleGroup>>,
+    call_pattern_rules: Vec<Arc<CallPatternRule>>,
+    custom_deprecated_lint_names: Vec<DeprecatedLintName>,
+    diagnostic_details: Arc<Mutex<HashMap<DiagnosticKey, LintDiagnosticDetails>>>,
+}
+
+/// The node and the message of the reported diagnostic.
+type DiagnosticKey = (SyntaxStablePtrId, String);
+
+impl LintRegistry {
+    /// Registers a group of custom lint rules.
+    /// Fails if the name or the id of any of the rules is already used by another rule,
+    /// or the name is used by a lint category.
+    pub fn register(&mut self, lint_group: LintRuleGroup) -> Result<()> {
+        for (index, lint) in lint_group.lints.iter().enumerate() {
+            self.check_clashes(lint.as_ref())?;
+            // The lints of the group are not registered yet, so they are compared with each other here.
+            if let Some(other_lint) = lint_group.lints[..index]
+                .iter()
+                .find(|other_lint| lints_clash(other_lint.as_ref(), lint.as_ref()))
+            {
+                bail!(
+                    "The lint '{}' clashes with the lint '{}' of the same group.",
+                    lint.allowed_name(),
+                    other_lint.allowed_name()
+                );
+            }
+        }
+        self.custom_lint_groups.push(Arc::new(lint_group));
+        Ok(())
+    }
+
+    /// Registers a declarative rule reporting the calls of a certain function.
+    /// Fails if the configuration is invalid, or the rule clashes with another one as in [`Self::register`].
+    pub fn register_call_pattern(&mut self, config: CallPatternConfig) -> Result<()> {
+        let rule = CallPatternRule::new(config)?;
+        self.check_clashes(&rule)?;
+        self.call_pattern_rules.push(Arc::new(rule));
+        Ok(())
+    }
+
+    /// Registers the call pattern rules defined in the tool metadata, see [`CALL_PATTERNS_KEY`].
+    pub fn register_call_patterns(&mut self, tool_metadata: &CairoLintConfig) -> Result<()> {
+        match tool_metadata.get(CALL_PATTERNS_KEY) {
+            Some(LintConfig::CallPatterns(configs)) => {
+                for config in configs {
+                    self.register_call_pattern(config.clone())?;
+                }
+                Ok(())
+            }
+            Some(_) => {
+                bail!("Expected a list of the call pattern rules under `{CALL_PATTERNS_KEY}`.")
+            }
+            None => Ok(()),
+        }
+    }
+
+    /// Registers a name of a renamed or removed custom lint rule.
+    /// Fails if the name is still used by a lint rule or a category, it is already deprecated,
+    /// or a renamed lint rule is renamed to an unknown name.
+    pub fn register_deprecated_lint_name(
+        &mut self,
+        deprecated_lint_name: DeprecatedLintName,
+    ) -> Result<()> {
+        let name = deprecated_lint_name.name();
+        if self.lints().any(|lint| lint.allowed_name() == name)
+            || LintCategory::from_name(name).is_some()
+        {
+            bail!("The deprecated lint name '{name}' is still used by a lint rule or a category.");
+        }
+        if self.find_deprecated_lint_name(name).is_some() {
+            bail!("The lint name '{name}' is already deprecated.");
+        }
+        if let DeprecatedLintName::Renamed { new_name, .. } = deprecated_lint_name {
+            if !self.lints().any(|lint| lint.allowed_name() == new_name)
+                && LintCategory::from_name(new_name).is_none()
+            {
+                bail!("The lint '{name}' is renamed to the unknown lint '{new_name}'.");
+            }
+        }
+        self.custom_deprecated_lint_names.push(deprecated_lint_name);
+        Ok(())
+    }
+
+    fn check_clashes(&self, lint: &dyn Lint) -> Result<()> {
+        if let Some(existing_lint) = self
+            .lints()
+            .find(|existing_lint| lints_clash(*existing_lint, lint))
+        {
+            bail!(
+                "The lint '{}' clashes with the already registered lint '{}'.",
+                lint.allowed_name(),
+                existing_lint.allowed_name()
+            );
+        }
+        if lint.allowed_name() == CALL_PATTERNS_KEY {
+            bail!("The lint name '{CALL_PATTERNS_KEY}' is reserved.");
+        }
+        if LintCategory::from_name(lint.allowed_name()).is_some() {
+            bail!(
+                "The lint '{}' has the same name as a lint category.",
+                lint.allowed_name()
+            );
+        }
+        if self
+            .find_deprecated_lint_name(lint.allowed_name())
+            .is_some()
+        {
+            bail!(
+                "The lint name '{}' is deprecated and cannot be used again.",
+                lint.allowed_name()
+            );
+        }
+        Ok(())
+    }
+
+    /// All of the names of the renamed and removed lint rules, including the custom ones.
+    pub fn deprecated_lint_names<'a>(&'a self) -> impl Iterator<Item = &'a DeprecatedLintName> {
+        let lint_context: &'a LintContext = &LINT_CONTEXT;
+        lint_context
+            .deprecated_lint_names
+            .iter()
+            .chain(self.custom_deprecated_lint_names.iter())
+    }
+
+    /// Finds the deprecated lint name, if the name is not used anymore.
+    pub fn find_deprecated_lint_name(&self, name: &str) -> Option<DeprecatedLintName> {
+        self.deprecated_lint_names()
+            .find(|deprecated_lint_name| deprecated_lint_name.name() == name)
+            .copied()
+    }
+
+    /// All of the old names of the lint rule (or category), which now work as its aliases.
+    pub fn old_names(&self, new_name: &str) -> Vec<&'static str> {
+        self.deprecated_lint_names()
+            .filter_map(|deprecated_lint_name| match *deprecated_lint_name {
+                DeprecatedLintName::Renamed {
+                    old_name,
+                    new_name: name,
+                } if name == new_name => Some(old_name),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Creates the fix of the renamed lint name, used inside the lint level attribute.
+    pub fn fix_renamed_lint_name(
+        &self,
+        db: &dyn SemanticGroup,
+        node: SyntaxNode,
+    ) -> Option<Suggestion> {
+        let name = node.get_text_without_trivia(db.upcast());
+        let DeprecatedLintName::Renamed { new_name, .. } = self.find_deprecated_lint_name(&name)?
+        else {
+            return None;
+        };
+        Some(Suggestion::replace_node(
+            db.upcast(),
+            format!("Rename to `{new_name}`"),
+            node,
+            new_name,
+        ))
+    }
+
+    /// All of the lint rules, including the meta lint rules and the custom ones.
+    pub fn lints<'a>(&'a self) -> impl Iterator<Item = &'a dyn Lint> {
+        let lint_context: &'a LintContext = &LINT_CONTEXT;
+        lint_context
+            .lints()
+            .chain(
+                self.custom_lint_groups
+                    .iter()
+                    .flat_map(|group| group.lints.iter()),
+            )
+            .map(|lint| -> &'a dyn Lint { lint.as_ref() })
+            .chain(
+                self.call_pattern_rules
+                    .iter()
+                    .map(|rule| -> &'a dyn Lint { rule.as_ref() }),
+            )
+    }
+
+    /// The declarative rules reporting the calls of certain functions.
+    pub fn call_pattern_rules(&self) -> &[Arc<CallPatternRule>] {
+        &self.call_pattern_rules
+    }
+
+    /// All of the lint rule groups, including the custom ones.
+    pub fn lint_groups<'a>(&'a self) -> impl Iterator<Item = &'a LintRuleGroup> {
+        let lint_context: &'a LintContext = &LINT_CONTEXT;
+        lint_context
+            .lint_groups
+            .iter()
+            .chain(self.custom_lint_groups.iter().map(Arc::as_ref))
+    }
+
+    /// Get all the unique allowed names of the lint rules.
+    pub fn allowed_names(&self) -> Vec<&'static str> {
+        self.lints().map(|lint| lint.allowed_name()).collect()
+    }
+
+    /// Finds the lint rule by its id.
+    pub fn find_lint_by_id(&self, id: &str) -> Option<&dyn Lint> {
+        self.lints().find(|rule| rule.id() == id)
+    }
+
+    /// Keeps the details of the diagnostic reported by the plugin, so they can be found by
+    /// [`Self::diagnostic_details`].
+    pub(crate) fn record_diagnostic_details(
+        &self,
+        diagnostic: &PluginDiagnostic,
+        details: LintDiagnosticDetails,
+    ) {
+        self.diagnostic_details
+            .lock()
+            .unwrap()
+            .insert((diagnostic.stable_ptr, diagnostic.message.clone()), details);
+    }
+
+    /// Gets the details of the diagnostic, if it was reported by the plugin using this registry.
+    pub fn diagnostic_details(
+        &self,
+        diagnostic: &PluginDiagnostic,
+    ) -> Option<LintDiagnosticDetails> {
+        self.diagnostic_details
+            .lock()
+            .unwrap()
+            .get(&(diagnostic.stable_ptr, diagnostic.message.clone()))
+            .cloned()
+    }
+
+    /// Finds the lint rule which reported the diagnostic, by the id kept in its details.
+    /// The diagnostics reported by the plugin using another registry are matched against the predefined
+    /// rules only, see [`find_lint_by_diagnostic_message`].
+    pub fn find_lint_for_diagnostic(&self, diagnostic: &PluginDiagnostic) -> Option<&dyn Lint> {
+        match self.diagnostic_details(diagnostic) {
+            Some(details) => self.find_lint_by_id(details.lint_id),
+            None => find_lint_by_diagnostic_message(&diagnostic.message).map(|rule| rule.as_ref()),
+        }
+    }
+
+    /// Get the fix for the diagnostic.
+    /// For some of the rules there is no fixing function, so it returns `None`.
+    pub fn get_fix_for_diagnostic(
+        &self,
+        db: &dyn SemanticGroup,
+        node: SyntaxNode,
+        diagnostic: &PluginDiagnostic,
+    ) -> Option<Suggestion> {
+        let rule = self
+            .find_lint_for_diagnostic(diagnostic)
+            .filter(|rule| rule.has_fixer())?;
+        // The lint rule itself knows only the predefined renames.
+        if rule.kind() == CairoLintKind::RenamedLintName {
+            return self.fix_renamed_lint_name(db, node);
+        }
+        match self.diagnostic_details(diagnostic) {
+            Some(details) => rule.fix_diagnostic(db, node, &details),
+            None => rule.fix(db, node),
+        }
+    }
 }
 
 /// A global Linter context. It contains all the lint rules.
 struct LintContext {
     lint_groups: Vec<LintRuleGroup>,
-    diagnostic_to_lint_kind_map: HashMap<&'static str, CairoLintKind>,
+    /// Lint rules without a checking function, which diagnostics are emitted directly by the plugin.
+    meta_lints: Vec<Box<dyn Lint>>,
+    deprecated_lint_names: Vec<DeprecatedLintName>,
 }
 
 impl LintContext {
@@ -195,11 +729,14 @@ impl LintContext {
         vec![
             LintRuleGroup {
                 lints: vec![Box::new(DestructMatch), Box::new(EqualityMatch)],
-                check_function: check_single_matches,
+                checker: LintChecker::FunctionBody(FunctionBodyVisitor {
+                    on_match: Some(check_single_match),
+                    ..Default::default()
+                }),
             },
             LintRuleGroup {
                 lints: vec![Box::new(DoubleParens)],
-                check_function: check_double_parens,
+                checker: LintChecker::Item(check_double_parens),
             },
             LintRuleGroup {
                 lints: vec![
@@ -208,67 +745,116 @@ impl LintContext {
                     Box::new(RedundantComparison),
                     Box::new(ContradictoryComparison),
                 ],
-                check_function: check_double_comparison,
+                checker: LintChecker::FunctionBody(FunctionBodyVisitor {
+                    on_logical_op: Some(check_double_comparison),
+                    ..Default::default()
+                }),
             },
             LintRuleGroup {
                 lints: vec![Box::new(EquatableIfLet)],
-                check_function: check_equatable_if_let,
+                checker: LintChecker::FunctionBody(FunctionBodyVisitor {
+                    on_if: Some(check_equatable_if_let),
+                    ..Default::default()
+                }),
             },
             LintRuleGroup {
                 lints: vec![Box::new(BreakUnit)],
-                check_function: check_break,
+                checker: LintChecker::FunctionBody(FunctionBodyVisitor {
+                    on_break: Some(check_break),
+                    ..Default::default()
+                }),
             },
             LintRuleGroup {
                 lints: vec![Box::new(BoolComparison)],
-                check_function: check_bool_comparison,
+                checker: LintChecker::FunctionBody(FunctionBodyVisitor {
+                    on_binary_op: Some(check_bool_comparison),
+                    ..Default::default()
+                }),
             },
             LintRuleGroup {
                 lints: vec![Box::new(CollapsibleIfElse)],
-                check_function: check_collapsible_if_else,
+                checker: LintChecker::FunctionBody(FunctionBodyVisitor {
+                    on_if: Some(check_collapsible_if_else),
+                    ..Default::default()
+                }),
             },
             LintRuleGroup {
                 lints: vec![Box::new(CollapsibleIf)],
-                check_function: check_collapsible_if,
+                checker: LintChecker::FunctionBody(FunctionBodyVisitor {
+                    on_if: Some(check_collapsible_if),
+                    ..Default::default()
+                }),
             },
             LintRuleGroup {
                 lints: vec![Box::new(DuplicateUnderscoreArgs)],
-                check_function: check_duplicate_underscore_args,
+                checker: LintChecker::Item(check_duplicate_underscore_args),
             },
             LintRuleGroup {
                 lints: vec![Box::new(LoopMatchPopFront)],
-                check_function: check_loop_match_pop_front,
+                checker: LintChecker::FunctionBody(FunctionBodyVisitor {
+                    on_loop: Some(check_loop_match_pop_front),
+                    ..Default::default()
+                }),
             },
             LintRuleGroup {
                 lints: vec![Box::new(ManualUnwrapOrDefault)],
-                check_function: check_manual_unwrap_or_default,
+                checker: LintChecker::FunctionBody(FunctionBodyVisitor {
+                    on_match: Some(check_manual_unwrap_or_default),
+                    on_if: Some(check_manual_unwrap_or_default_if),
+                    ..Default::default()
+                }),
             },
             LintRuleGroup {
                 lints: vec![Box::new(BitwiseForParity)],
-                check_function: check_bitwise_for_parity,
+                checker: LintChecker::FunctionBody(FunctionBodyVisitor {
+                    on_binary_op: Some(check_bitwise_for_parity),
+                    ..Default::default()
+                }),
             },
             LintRuleGroup {
                 lints: vec![Box::new(LoopForWhile)],
-                check_function: check_loop_for_while,
+                checker: LintChecker::FunctionBody(FunctionBodyVisitor {
+                    on_loop: Some(check_loop_for_while),
+                    ..Default::default()
+                }),
             },
             LintRuleGroup {
                 lints: vec![Box::new(PanicInCode)],
-                check_function: check_panic_usage,
+                checker: LintChecker::FunctionBody(FunctionBodyVisitor {
+                    on_call: Some(check_panic_usage),
+                    ..Default::default()
+                }),
             },
             LintRuleGroup {
                 lints: vec![Box::new(ErasingOperation)],
-                check_function: check_erasing_operation,
+                checker: LintChecker::FunctionBody(FunctionBodyVisitor {
+                    on_binary_op: Some(check_erasing_operation),
+                    ..Default::default()
+                }),
             },
             LintRuleGroup {
                 lints: vec![Box::new(ManualOkOr)],
-                check_function: check_manual_ok_or,
+                checker: LintChecker::FunctionBody(FunctionBodyVisitor {
+                    on_match: Some(check_manual_ok_or),
+                    on_if: Some(check_manual_ok_or_if),
+                    ..Default::default()
+                }),
             },
             LintRuleGroup {
                 lints: vec![Box::new(ManualOk)],
-                check_function: check_manual_ok,
+                checker: LintChecker::FunctionBody(FunctionBodyVisitor {
+                    on_match: Some(check_manual_ok),
+                    on_if: Some(check_manual_ok_if),
+                    ..Default::default()
+                }),
             },
             LintRuleGroup {
                 lints: vec![Box::new(ManualErr)],
-                check_function: check_manual_err,
+                checker: LintChecker::FunctionBody(FunctionBodyVisitor {
+                    on_match: Some(check_manual_err),
+                    on_if: Some(check_manual_err_if),
+                    ..Default::default()
+                }),
             },
             LintRuleGroup {
                 lints: vec![
@@ -277,19 +863,34 @@ impl LintContext {
                     Box::new(ManualIsOk),
                     Box::new(ManualIsErr),
                 ],
-                check_function: check_manual_is,
+                checker: LintChecker::FunctionBody(FunctionBodyVisitor {
+                    on_match: Some(check_manual_is),
+                    on_if: Some(check_manual_is_if),
+                    ..Default::default()
+                }),
             },
             LintRuleGroup {
                 lints: vec![Box::new(ManualExpect)],
-                check_function: check_manual_expect,
+                checker: LintChecker::FunctionBody(FunctionBodyVisitor {
+                    on_match: Some(check_manual_expect),
+                    on_if: Some(check_manual_expect_if),
+                    ..Default::default()
+                }),
             },
             LintRuleGroup {
                 lints: vec![Box::new(DuplicateIfCondition)],
-                check_function: check_duplicate_if_condition,
+                checker: LintChecker::FunctionBody(FunctionBodyVisitor {
+                    on_if: Some(check_duplicate_if_condition),
+                    ..Default::default()
+                }),
             },
             LintRuleGroup {
                 lints: vec![Box::new(ManualExpectErr)],
-                check_function: check_manual_expect_err,
+                checker: LintChecker::FunctionBody(FunctionBodyVisitor {
+                    on_match: Some(check_manual_expect_err),
+                    on_if: Some(check_manual_expect_err_if),
+                    ..Default::default()
+                }),
             },
             LintRuleGroup {
                 lints: vec![
@@ -298,7 +899,10 @@ impl LintContext {
                     Box::new(IntegerLessEqualPlusOne),
                     Box::new(IntegerLessEqualMinusOne),
                 ],
-                check_function: check_int_op_one,
+                checker: LintChecker::FunctionBody(FunctionBodyVisitor {
+                    on_binary_op: Some(check_int_op_one),
+                    ..Default::default()
+                }),
             },
             LintRuleGroup {
                 lints: vec![
@@ -309,63 +913,90 @@ impl LintContext {
                     Box::new(BitwiseEqualityOperation),
                     Box::new(LogicalEqualityOperation),
                 ],
-                check_function: check_eq_op,
+                checker: LintChecker::FunctionBody(FunctionBodyVisitor {
+                    on_binary_op: Some(check_eq_op),
+                    ..Default::default()
+                }),
             },
             LintRuleGroup {
                 lints: vec![Box::new(InefficientWhileComparison)],
-                check_function: check_inefficient_while_comp,
+                checker: LintChecker::FunctionBody(FunctionBodyVisitor {
+                    on_while: Some(check_inefficient_while_comp),
+                    ..Default::default()
+                }),
             },
             LintRuleGroup {
                 lints: vec![Box::new(RedundantOperation)],
-                check_function: check_redundant_operation,
+                checker: LintChecker::FunctionBody(FunctionBodyVisitor {
+                    on_binary_op: Some(check_redundant_operation),
+                    ..Default::default()
+                }),
             },
             LintRuleGroup {
                 lints: vec![Box::new(EnumVariantNames)],
-                check_function: check_enum_variant_names,
+                checker: LintChecker::Item(check_enum_variant_names),
             },
             LintRuleGroup {
                 lints: vec![Box::new(CloneOnCopy)],
-                check_function: check_clone_on_copy,
+                checker: LintChecker::FunctionBody(FunctionBodyVisitor {
+                    on_call: Some(check_clone_on_copy),
+                    ..Default::default()
+                }),
             },
             LintRuleGroup {
                 lints: vec![Box::new(EmptyEnumBracketsVariant)],
-                check_function: check_empty_enum_brackets_variant,
+                checker: LintChecker::Item(check_empty_enum_brackets_variant),
             },
             LintRuleGroup {
                 lints: vec![Box::new(ManualAssert)],
-                check_function: check_manual_assert,
+                checker: LintChecker::FunctionBody(FunctionBodyVisitor {
+                    on_if: Some(check_manual_assert),
+                    ..Default::default()
+                }),
             },
             LintRuleGroup {
                 lints: vec![Box::new(RedundantBracketsInEnumCall)],
-                check_function: check_redundant_brackets_in_enum_call,
+                checker: LintChecker::FunctionBody(FunctionBodyVisitor {
+                    on_enum_variant_ctor: Some(check_redundant_brackets_in_enum_call),
+                    ..Default::default()
+                }),
             },
         ]
     }
 
-    fn precompute_diagnostic_to_lint_kind_map(mut self) -> Self {
-        let mut result: HashMap<&'static str, CairoLintKind> = HashMap::default();
-        for rule_group in self.lint_groups.iter() {
-            for rule in rule_group.lints.iter() {
-                result.insert(rule.diagnostic_message(), rule.kind());
-            }
-        }
-        self.diagnostic_to_lint_kind_map = result;
-        self
+    /// Lint rules concerning the lint attributes themselves. They are checked by the plugin
+    /// while it resolves the lint levels, so they don't have any checking function.
+    fn get_all_meta_lints() -> Vec<Box<dyn Lint>> {
+        vec![
+            Box::new(UnfulfilledLintExpectation),
+            Box::new(UnusedLintAllow),
+            Box::new(UnknownLintName),
+            Box::new(RenamedLintName),
+            Box::new(RemovedLintName),
+        ]
+    }
+
+    /// Names of the predefined lint rules which were renamed or removed.
+    /// When a rule is renamed or removed, its old name should be added here, so the projects using it don't break.
+    fn get_all_deprecated_lint_names() -> Vec<DeprecatedLintName> {
+        Vec::new()
     }
 
     fn new() -> Self {
-        let new = Self {
+        Self {
             lint_groups: Self::get_all_lints(),
-            diagnostic_to_lint_kind_map: Default::default(),
-        };
-        new.precompute_diagnostic_to_lint_kind_map()
+            meta_lints: Self::get_all_meta_lints(),
+            deprecated_lint_names: Self::get_all_deprecated_lint_names(),
+        }
     }
 
-    fn get_lint_type_from_diagnostic_message(&self, message: &str) -> CairoLintKind {
-        self.diagnostic_to_lint_kind_map
-            .get(message)
-            .copied()
-            .unwrap_or(CairoLintKind::Unknown)
+    /// All of the lint rules, including the meta lint rules.
+    #[allow(clippy::borrowed_box)]
+    fn lints(&self) -> impl Iterator<Item = &Box<dyn Lint>> {
+        self.lint_groups
+            .iter()
+            .flat_map(|group| group.lints.iter())
+            .chain(self.meta_lints.iter())
     }
 }
 
@@ -375,7 +1006,7 @@ static LINT_CONTEXT: LazyLock<LintContext> = LazyLock::new(LintContext::new);
 /// Get the lint type based on the diagnostic message.
 /// If the diagnostic message doesn't match any of the rules, it returns `CairoLintKind::Unknown`.
 pub fn get_lint_type_from_diagnostic_message(message: &str) -> CairoLintKind {
-    LINT_CONTEXT.get_lint_type_from_diagnostic_message(message)
+    find_lint_by_diagnostic_message(message).map_or(CairoLintKind::Unknown, |rule| rule.kind())
 }
 
 /// Get the fixing function based on the diagnostic message.
@@ -384,51 +1015,135 @@ pub fn get_fix_for_diagnostic_message(
     db: &dyn SemanticGroup,
     node: SyntaxNode,
     message: &str,
-) -> Option<(SyntaxNode, String)> {
-    LINT_CONTEXT
-        .lint_groups
-        .iter()
-        .flat_map(|rule_group| &rule_group.lints)
-        .find(|rule| rule.diagnostic_message() == message && rule.has_fixer())
+) -> Option<Suggestion> {
+    find_lint_by_diagnostic_message(message)
+        .filter(|rule| rule.has_fixer())
         .and_then(|rule| rule.fix(db, node))
 }
 
+#[allow(clippy::borrowed_box)]
+/// Get all the lint rules, including the meta lint rules.
+pub fn get_all_lint_rules() -> impl Iterator<Item = &'static Box<dyn Lint>> {
+    LINT_CONTEXT.lints()
+}
+
 /// Get all the unique allowed names for the lint rule groups.
 pub fn get_unique_allowed_names() -> Vec<&'static str> {
     LINT_CONTEXT
-        .lint_groups
+        .lints()
+        .map(|rule| rule.allowed_name())
+        .collect()
+}
+
+/// Get the names of all the lint categories. They can be used in place of a lint name
+/// to configure all of the lint rules from the category at once.
+pub fn get_unique_category_names() -> Vec<&'static str> {
+    LintCategory::ALL
         .iter()
-        .flat_map(|rule_group| rule_group.lints.iter().map(|rule| rule.allowed_name()))
+        .map(|category| category.name())
         .collect()
 }
 
-/// Get all the checking functions that exist for each `LintRuleGroup`.
+/// Get all the checking functions of the `LintRuleGroup`s which check the whole module items.
 pub fn get_all_checking_functions() -> impl Iterator<Item = &'static CheckingFunction> {
     LINT_CONTEXT
         .lint_groups
         .iter()
-        .unique_by(|rule| rule.check_function)
-        .map(|rule_group| &rule_group.check_function)
+        .filter_map(|rule_group| match &rule_group.checker {
+            LintChecker::Item(check_function) => Some(check_function),
+            LintChecker::FunctionBody(_) => None,
+        })
+        .unique()
 }
 
 /// Get lint name based on the diagnostic message.
 pub fn get_name_for_diagnostic_message(message: &str) -> Option<&'static str> {
-    LINT_CONTEXT
-        .lint_groups
-        .iter()
-        .flat_map(|group| group.lints.iter())
-        .find(|rule| rule.diagnostic_message() == message)
-        .map(|rule| rule.allowed_name())
+    find_lint_by_diagnostic_message(message).map(|rule| rule.allowed_name())
 }
 
 /// Checks if the lint related to the diagnostic message is enabled by default.
 pub fn is_lint_enabled_by_default(message: &str) -> Option<bool> {
+    find_lint_by_diagnostic_message(message).map(|rule| rule.is_enabled())
+}
+
+#[allow(clippy::borrowed_box)]
+/// Finds the lint rule by its id.
+pub fn find_lint_by_id(id: &str) -> Option<&'static Box<dyn Lint>> {
+    LINT_CONTEXT.lints().find(|rule| rule.id() == id)
+}
+
+#[allow(clippy::borrowed_box)]
+/// Checks if the lints have the same name or the same id.
+fn lints_clash(lint: &dyn Lint, other_lint: &dyn Lint) -> bool {
+    lint.allowed_name() == other_lint.allowed_name() || lint.id() == other_lint.id()
+}
+
+/// Finds the predefined lint which emitted the diagnostic with the given message.
+/// The message is matched against the [`Lint::message_template`] of every predefined rule.
+///
+/// The message does not always identify the rule, e.g. the `unknown_lints` diagnostics suggesting
+/// a known name are not found. Prefer [`LintRegistry::find_lint_for_diagnostic`], which knows
+/// the rule that reported the diagnostic.
+pub fn find_lint_by_diagnostic_message(message: &str) -> Option<&'static Box<dyn Lint>> {
     LINT_CONTEXT
-        .lint_groups
-        .iter()
-        .flat_map(|group| group.lints.iter())
-        .find(|rule| rule.diagnostic_message() == message)
-        .map(|rule| rule.is_enabled())
+        .lints()
+        .find(|rule| emits_message(rule.as_ref(), message))
+}
+
+/// Checks if the main message of the diagnostic could be emitted by the lint rule,
+/// i.e. it is the [`Lint::message_template`] with the placeholders filled with any values.
+fn emits_message(rule: &dyn Lint, message: &str) -> bool {
+    let mut literals = Vec::new();
+    let mut rest = rule.message_template();
+    while let Some((literal, tail)) = rest
+        .split_once('{')
+        .and_then(|(literal, tail)| Some((literal, tail.split_once('}')?.1)))
+    {
+        literals.push(literal);
+        rest = tail;
+    }
+    let Some(first) = literals.first() else {
+        return message == rest;
+    };
+    let Some(mut remaining) = message.strip_prefix(first) else {
+        return false;
+    };
+    // The literals between the placeholders have to appear in the same order as in the template.
+    for literal in &literals[1..] {
+        let Some(start) = remaining.find(literal) else {
+            return false;
+        };
+        remaining = &remaining[start + literal.len()..];
+    }
+    remaining.ends_with(rest)
+}
+
+/// Replaces the `{name}` placeholders of the message template with the values of the arguments.
+/// The placeholders without a matching argument are left untouched.
+pub fn fill_message_template(template: &str, args: &[(&str, &str)]) -> String {
+    let mut message = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(start) = rest.find('{') {
+        message.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let value = rest.find('}').and_then(|end| {
+            args.iter()
+                .find(|(name, _)| *name == &rest[1..end])
+                .map(|(_, value)| (end, value))
+        });
+        match value {
+            Some((end, value)) => {
+                message.push_str(value);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                message.push('{');
+                rest = &rest[1..];
+            }
+        }
+    }
+    message.push_str(rest);
+    message
 }
 
 #[allow(clippy::borrowed_box)]
@@ -437,8 +1152,6 @@ pub fn is_lint_enabled_by_default(message: &str) -> Option<bool> {
 /// For example, for `crate::lints::bool_comparison::BoolComparison` the struct name is `BoolComparison`.
 pub fn find_lint_by_struct_name(name: &str) -> Option<&Box<dyn Lint>> {
     LINT_CONTEXT
-        .lint_groups
-        .iter()
-        .flat_map(|group| group.lints.iter())
+        .lints()
         .find(|rule| rule.type_name().split("::").last().unwrap() == name)
 }
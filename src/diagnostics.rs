@@ -1,11 +1,410 @@
+use cairo_lang_defs::diagnostic_utils::StableLocation;
+use cairo_lang_defs::plugin::PluginDiagnostic;
 use cairo_lang_diagnostics::format_diagnostics as cairo_format_diagnostics;
-use cairo_lang_diagnostics::DiagnosticEntry;
+use cairo_lang_diagnostics::{DiagnosticEntry, DiagnosticLocation, Severity};
+use cairo_lang_filesystem::db::FilesGroup;
+use cairo_lang_filesystem::ids::FileId;
+use cairo_lang_filesystem::span::{TextPositionSpan, TextSpan};
 use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_semantic::diagnostic::SemanticDiagnosticKind;
 use cairo_lang_semantic::SemanticDiagnostic;
+use cairo_lang_syntax::node::ids::SyntaxStablePtrId;
+use cairo_lang_utils::Upcast;
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+
+use crate::context::{Lint, LintRegistry};
+use crate::fixes::{fix_semantic_diagnostic_with_lint_registry, Applicability, Edit, Suggestion};
 
 pub fn format_diagnostic<'a>(
     diagnostic: &'a SemanticDiagnostic,
     db: &(dyn SemanticGroup + 'static),
 ) -> String {
-    cairo_format_diagnostics(db, &diagnostic.format(db), diagnostic.location(db))
+    format_diagnostic_with_lint_registry(diagnostic, db, &LintRegistry::default())
+}
+
+/// Formats the diagnostic, together with the notes of the lint diagnostic kept in the registry,
+/// see [`LintRegistry::diagnostic_details`].
+pub fn format_diagnostic_with_lint_registry(
+    diagnostic: &SemanticDiagnostic,
+    db: &(dyn SemanticGroup + 'static),
+    lint_registry: &LintRegistry,
+) -> String {
+    let location = diagnostic.location(db);
+    let mut result = cairo_format_diagnostics(db, &diagnostic.format(db), location.clone());
+    // The notes are rendered after the main location of the diagnostic.
+    for note in lint_notes(diagnostic, lint_registry) {
+        let text = format!("{}: {}", note.kind, note.text);
+        let note_location = note.span.and_then(|span| {
+            Some(DiagnosticLocation {
+                file_id: location.file_id,
+                span: span.offset_in_file(db.upcast(), location.file_id)?,
+            })
+        });
+        let rendered = match note_location {
+            Some(note_location) => cairo_format_diagnostics(db, &text, note_location),
+            None => text,
+        };
+        result.push_str(rendered.trim_end_matches('\n'));
+        result.push('\n');
+    }
+    result
+}
+
+/// The notes of the lint diagnostic, or none if it was not reported by the plugin using the registry.
+fn lint_notes(diagnostic: &SemanticDiagnostic, lint_registry: &LintRegistry) -> Vec<LintNote> {
+    match &diagnostic.kind {
+        SemanticDiagnosticKind::PluginDiagnostic(plugin_diag) => lint_registry
+            .diagnostic_details(plugin_diag)
+            .map(|details| details.notes)
+            .unwrap_or_default(),
+        _ => vec![],
+    }
+}
+
+/// Diagnostic reported by a lint rule, before its level is resolved by the plugin.
+///
+/// Unlike the [`PluginDiagnostic`], it knows the lint rule which reported it, and it can carry notes:
+/// the labeled locations of the other relevant pieces of code, and the `note:` and `help:` lines.
+/// The plugin turns it into a [`PluginDiagnostic`] and keeps the [`LintDiagnosticDetails`] in the
+/// [`LintRegistry`], see [`LintRegistry::diagnostic_details`]. The notes are not a part of the message,
+/// so only the formatters of the Cairo lint render them, e.g. [`format_diagnostic_with_lint_registry`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LintDiagnostic {
+    pub stable_ptr: SyntaxStablePtrId,
+    pub message: String,
+    pub details: LintDiagnosticDetails,
+}
+
+/// The data of the lint diagnostic which does not fit into the [`PluginDiagnostic`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LintDiagnosticDetails {
+    /// Id of the lint rule which reported the diagnostic, see [`Lint::id`].
+    pub lint_id: &'static str,
+    pub notes: Vec<LintNote>,
+    /// Named values needed by the fix of the diagnostic, see [`Lint::fix_diagnostic`].
+    pub fix_args: Vec<(&'static str, String)>,
+}
+
+impl LintDiagnosticDetails {
+    /// The value of the fix argument with the given name, see [`LintDiagnostic::with_fix_arg`].
+    pub fn fix_arg(&self, name: &str) -> Option<&str> {
+        self.fix_args
+            .iter()
+            .find(|(arg_name, _)| *arg_name == name)
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+impl LintDiagnostic {
+    /// Creates the diagnostic of the lint rule, with its [`Lint::formatted_message`].
+    pub fn new(lint: &dyn Lint, stable_ptr: SyntaxStablePtrId) -> Self {
+        Self {
+            stable_ptr,
+            message: lint.formatted_message(),
+            details: LintDiagnosticDetails {
+                lint_id: lint.id(),
+                notes: Vec::new(),
+                fix_args: Vec::new(),
+            },
+        }
+    }
+
+    /// Creates the diagnostic of the lint rule, with its [`Lint::formatted_message_with_args`].
+    pub fn with_args(
+        lint: &dyn Lint,
+        stable_ptr: SyntaxStablePtrId,
+        args: &[(&str, &str)],
+    ) -> Self {
+        Self {
+            message: lint.formatted_message_with_args(args),
+            ..Self::new(lint, stable_ptr)
+        }
+    }
+
+    /// Replaces the message of the diagnostic.
+    pub fn with_message(mut self, message: String) -> Self {
+        self.message = message;
+        self
+    }
+
+    /// Points at another relevant piece of code, e.g. the first occurrence of a duplicated condition.
+    /// The code has to be in the same file as the linted code.
+    pub fn with_label(
+        mut self,
+        db: &dyn SemanticGroup,
+        stable_ptr: SyntaxStablePtrId,
+        label: &str,
+    ) -> Self {
+        let location = StableLocation::new(stable_ptr).diagnostic_location(db.upcast());
+        self.details.notes.push(LintNote {
+            kind: LintNoteKind::Note,
+            text: label.to_string(),
+            span: location
+                .span
+                .position_in_file(db.upcast(), location.file_id),
+        });
+        self
+    }
+
+    /// Adds a `note:` line with additional context.
+    pub fn with_note(mut self, note: &str) -> Self {
+        self.details
+            .notes
+            .push(LintNote::new(LintNoteKind::Note, note));
+        self
+    }
+
+    /// Adds a `help:` line describing how to fix the code.
+    pub fn with_help(mut self, help: &str) -> Self {
+        self.details
+            .notes
+            .push(LintNote::new(LintNoteKind::Help, help));
+        self
+    }
+
+    /// Passes a named value to the fix of the diagnostic, e.g. the part of the code to remove,
+    /// so the fix does not have to recompute it.
+    pub fn with_fix_arg(mut self, name: &'static str, value: impl Into<String>) -> Self {
+        self.details.fix_args.push((name, value.into()));
+        self
+    }
+
+    /// Turns the diagnostic into the one reported to the compiler, with the given severity.
+    pub fn into_plugin_diagnostic(
+        self,
+        severity: Severity,
+    ) -> (PluginDiagnostic, LintDiagnosticDetails) {
+        let diagnostic = PluginDiagnostic {
+            stable_ptr: self.stable_ptr,
+            message: self.message,
+            severity,
+            relative_span: None,
+        };
+        (diagnostic, self.details)
+    }
+}
+
+/// Kind of the note attached to the lint diagnostic.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LintNoteKind {
+    /// Additional context, e.g. another relevant piece of code.
+    Note,
+    /// Description of how to fix the code.
+    Help,
+}
+
+impl Display for LintNoteKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Note => write!(f, "note"),
+            Self::Help => write!(f, "help"),
+        }
+    }
+}
+
+/// A note attached to the lint diagnostic, see [`LintDiagnostic`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LintNote {
+    pub kind: LintNoteKind,
+    pub text: String,
+    /// The labeled location of another relevant piece of code, in the file of the diagnostic.
+    pub span: Option<TextPositionSpan>,
+}
+
+impl LintNote {
+    fn new(kind: LintNoteKind, text: &str) -> Self {
+        Self {
+            kind,
+            text: text.to_string(),
+            span: None,
+        }
+    }
+}
+
+/// Machine-readable representation of a diagnostic.
+/// IMPORTANT: This one is a part of the JSON output schema, so watch out when modifying it,
+/// as it might break the tools consuming the output.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct JsonDiagnostic {
+    /// Id of the lint rule which emitted the diagnostic, e.g. `CL0003`, or `None` for the compiler diagnostics.
+    pub code: Option<String>,
+    /// Name of the lint rule which emitted the diagnostic, or `None` for the compiler diagnostics.
+    pub lint: Option<String>,
+    /// Category of the lint rule which emitted the diagnostic.
+    pub category: Option<String>,
+    /// Either `error` or `warning`.
+    pub severity: String,
+    /// The message of the diagnostic.
+    pub message: String,
+    /// The labeled locations of the other relevant pieces of code, and the `note:` and `help:` lines.
+    pub notes: Vec<JsonNote>,
+    /// Path of the file the diagnostic points to.
+    pub file: String,
+    pub span: JsonSpan,
+    /// The source code the diagnostic points to.
+    pub snippet: String,
+    pub suggestions: Vec<JsonSuggestion>,
+}
+
+/// Location of the diagnostic or the edit in the file.
+/// Lines and columns are 1-based, the byte offsets are 0-based and the end is exclusive.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct JsonSpan {
+    pub byte_start: usize,
+    pub byte_end: usize,
+    pub line_start: usize,
+    pub column_start: usize,
+    pub line_end: usize,
+    pub column_end: usize,
+}
+
+/// Note attached to the lint diagnostic, see [`LintNote`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct JsonNote {
+    pub kind: LintNoteKind,
+    pub message: String,
+    /// The labeled location of another relevant piece of code, in the file of the diagnostic.
+    pub span: Option<JsonSpan>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct JsonSuggestion {
+    pub title: String,
+    pub applicability: Applicability,
+    pub edits: Vec<JsonEdit>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct JsonEdit {
+    pub span: JsonSpan,
+    pub replacement: String,
+}
+
+impl JsonDiagnostic {
+    /// Creates the machine-readable representation of the diagnostic, with the fix suggested for it.
+    /// The lint rule which emitted the diagnostic is looked up in the registry, so the custom rules are recognized as well.
+    pub fn new(
+        diagnostic: &SemanticDiagnostic,
+        db: &(dyn SemanticGroup + 'static),
+        lint_registry: &LintRegistry,
+    ) -> Self {
+        let files_db: &dyn FilesGroup = db.upcast();
+        let location = diagnostic.location(db).user_location(files_db);
+        let lint = match &diagnostic.kind {
+            SemanticDiagnosticKind::PluginDiagnostic(plugin_diag) => {
+                lint_registry.find_lint_for_diagnostic(plugin_diag)
+            }
+            _ => None,
+        };
+        let notes = lint_notes(diagnostic, lint_registry)
+            .into_iter()
+            .map(|note| JsonNote::new(note, location.file_id, files_db))
+            .collect();
+        let suggestions = fix_semantic_diagnostic_with_lint_registry(db, diagnostic, lint_registry)
+            .map(|suggestion| JsonSuggestion::new(&suggestion, location.file_id, files_db))
+            .into_iter()
+            .collect();
+        let snippet = files_db
+            .file_content(location.file_id)
+            .and_then(|content| {
+                content
+                    .get(location.span.to_str_range())
+                    .map(ToString::to_string)
+            })
+            .unwrap_or_default();
+
+        Self {
+            code: lint.map(|lint| lint.id().to_string()),
+            lint: lint.map(|lint| lint.allowed_name().to_string()),
+            category: lint.map(|lint| lint.category().name().to_string()),
+            severity: diagnostic.severity().to_string(),
+            message: diagnostic.format(db),
+            notes,
+            file: location.file_id.full_path(files_db),
+            span: JsonSpan::new(location.span, location.file_id, files_db),
+            snippet,
+            suggestions,
+        }
+    }
+}
+
+impl JsonSpan {
+    fn new(span: TextSpan, file_id: FileId, db: &dyn FilesGroup) -> Self {
+        let range = span.to_str_range();
+        let position = span.position_in_file(db, file_id);
+        Self {
+            byte_start: range.start,
+            byte_end: range.end,
+            line_start: position.map_or(0, |position| position.start.line + 1),
+            column_start: position.map_or(0, |position| position.start.col + 1),
+            line_end: position.map_or(0, |position| position.end.line + 1),
+            column_end: position.map_or(0, |position| position.end.col + 1),
+        }
+    }
+}
+
+impl JsonNote {
+    fn new(note: LintNote, file_id: FileId, db: &dyn FilesGroup) -> Self {
+        Self {
+            kind: note.kind,
+            message: note.text,
+            span: note
+                .span
+                .and_then(|span| span.offset_in_file(db, file_id))
+                .map(|span| JsonSpan::new(span, file_id, db)),
+        }
+    }
+}
+
+impl JsonSuggestion {
+    fn new(suggestion: &Suggestion, file_id: FileId, db: &dyn FilesGroup) -> Self {
+        Self {
+            title: suggestion.title.clone(),
+            applicability: suggestion.applicability,
+            edits: suggestion
+                .edits
+                .iter()
+                .map(|edit| JsonEdit::new(edit, file_id, db))
+                .collect(),
+        }
+    }
+}
+
+impl JsonEdit {
+    fn new(edit: &Edit, file_id: FileId, db: &dyn FilesGroup) -> Self {
+        Self {
+            span: JsonSpan::new(edit.span, file_id, db),
+            replacement: edit.replacement.clone(),
+        }
+    }
+}
+
+/// Formats the diagnostics as JSON Lines, i.e. every diagnostic is a JSON object on a separate line.
+///
+/// See [`JsonDiagnostic`] for the schema of the objects.
+pub fn format_diagnostics_as_json_lines(
+    diagnostics: &[SemanticDiagnostic],
+    db: &(dyn SemanticGroup + 'static),
+) -> String {
+    format_diagnostics_as_json_lines_with_lint_registry(diagnostics, db, &LintRegistry::default())
+}
+
+/// Formats the diagnostics as JSON Lines, taking the custom lint rules from the registry into account.
+///
+/// See [`JsonDiagnostic`] for the schema of the objects.
+pub fn format_diagnostics_as_json_lines_with_lint_registry(
+    diagnostics: &[SemanticDiagnostic],
+    db: &(dyn SemanticGroup + 'static),
+    lint_registry: &LintRegistry,
+) -> String {
+    diagnostics
+        .iter()
+        .map(|diagnostic| {
+            let json_diagnostic = JsonDiagnostic::new(diagnostic, db, lint_registry);
+            // Serializing a struct with string keys cannot fail.
+            serde_json::to_string(&json_diagnostic).unwrap() + "\n"
+        })
+        .collect()
 }
@@ -0,0 +1,85 @@
+//! # Lint explanations for Cairo Lint
+//!
+//! This module renders the documentation of the lint rules, see [`crate::context::LintDocs`], so it can
+//! be shown without the documentation website, e.g. by the `--explain` option of the linter or on hover
+//! in the IDE. The pages of the documentation website are generated from the same data.
+
+use anyhow::{anyhow, Result};
+
+use crate::config::LintLevel;
+use crate::context::{Lint, LintCategory, LintDocs, LintRegistry};
+
+/// Everything that is known about the lint rule, in a form ready to be shown to the user.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LintExplanation {
+    pub name: &'static str,
+    pub id: &'static str,
+    pub category: LintCategory,
+    /// The level of the lint rule if it is not configured in the tool metadata.
+    pub default_level: LintLevel,
+    /// Whether the diagnostics of the lint rule can be fixed automatically.
+    pub fixable: bool,
+    pub docs: LintDocs,
+}
+
+impl LintExplanation {
+    /// Collects the explanation of the lint rule.
+    pub fn new(lint: &dyn Lint) -> Self {
+        Self {
+            name: lint.allowed_name(),
+            id: lint.id(),
+            category: lint.category(),
+            default_level: lint.default_level(),
+            fixable: lint.has_fixer(),
+            docs: lint.docs(),
+        }
+    }
+
+    /// Renders the full explanation as Markdown: the name and the properties of the lint rule,
+    /// followed by its documentation, see [`LintExplanation::docs_markdown`].
+    pub fn to_markdown(&self) -> String {
+        let fixable = if self.fixable { "yes" } else { "no" };
+        format!(
+            "# {} ({})\n\nCategory: **{}**\n\nDefault level: **{}**\n\nFixable: **{fixable}**\n\n{}",
+            self.name,
+            self.id,
+            self.category.name(),
+            self.default_level,
+            self.docs_markdown(),
+        )
+    }
+
+    /// Renders the documentation of the lint rule as Markdown, with the `What it does`,
+    /// `Why is this bad` and `Example` sections. The sections without the content are skipped.
+    pub fn docs_markdown(&self) -> String {
+        let mut sections = vec![format!("## What it does\n\n{}", self.docs.description)];
+        if let Some(rationale) = self.docs.rationale {
+            sections.push(format!("## Why is this bad\n\n{rationale}"));
+        }
+        if let Some(bad_example) = self.docs.bad_example {
+            let mut example = format!("## Example\n\n{}", code_block(bad_example));
+            if let Some(good_example) = self.docs.good_example {
+                example.push_str(&format!(
+                    "\n\nCan be rewritten as:\n\n{}",
+                    code_block(good_example)
+                ));
+            }
+            sections.push(example);
+        }
+        sections.join("\n\n") + "\n"
+    }
+}
+
+/// Explains the lint rule with the given name or id, e.g. `manual_ok_or` or `CL0020`.
+/// Fails if the lint rule is not known to the registry.
+pub fn explain_lint(lint_registry: &LintRegistry, name_or_id: &str) -> Result<LintExplanation> {
+    lint_registry
+        .lints()
+        .find(|lint| lint.allowed_name() == name_or_id || lint.id() == name_or_id)
+        .map(LintExplanation::new)
+        .ok_or_else(|| anyhow!("Unknown lint '{name_or_id}'."))
+}
+
+fn code_block(code: &str) -> String {
+    format!("```cairo\n{}\n```", code.trim_end())
+}
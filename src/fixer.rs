@@ -0,0 +1,414 @@
+//! # Fixer for Cairo Lint
+//!
+//! This module applies the suggested fixes to the files iteratively:
+//!
+//! 1. Collecting the diagnostics of the crates and the fixes suggested for them.
+//! 2. Applying the fixes that do not overlap with each other. The content of the fixed files
+//!    is overridden in the database, so it is parsed and analyzed again.
+//! 3. Repeating the process until there is nothing left to fix, or the iteration limit is reached.
+//!
+//! This way the nested fixes (e.g. a `collapsible_if` inside a `manual_unwrap_or_default`)
+//! are applied one after another, instead of being dropped.
+//!
+//! The fixes which would introduce new parser errors to the file are always rejected, which usually
+//! means that the fixer is broken. Optionally, every fix can be verified more thoroughly before being
+//! applied. The fixes which would introduce any new semantic errors to the crates are rejected as well.
+//!
+//! Nothing is written to the disk until [`FixerOutcome::write`] is called, so the outcome can be
+//! used as a dry run as well, e.g. by rendering the changes with [`FixerOutcome::diffs`].
+
+use std::collections::HashMap;
+
+use cairo_lang_compiler::db::RootDatabase;
+use cairo_lang_compiler::diagnostics::DiagnosticsReporter;
+use cairo_lang_defs::db::DefsGroup;
+use cairo_lang_diagnostics::{DiagnosticEntry, Severity};
+use cairo_lang_filesystem::db::{FilesGroup, FilesGroupEx};
+use cairo_lang_filesystem::ids::{CrateId, FileId, FileLongId};
+use cairo_lang_parser::utils::SimpleParserDatabase;
+use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_semantic::SemanticDiagnostic;
+use cairo_lang_utils::LookupIntern;
+use itertools::{Either, Itertools};
+use similar::TextDiff;
+
+use crate::context::LintRegistry;
+use crate::fixes::{apply_suggestions, Applicability, Suggestion};
+use crate::get_fixes_with_lint_registry;
+
+/// Default number of the fixing iterations, after which the fixer gives up.
+pub const DEFAULT_MAX_FIX_ITERATIONS: usize = 10;
+
+/// Configuration of the fixer.
+#[derive(Debug, Clone)]
+pub struct FixerConfig {
+    /// Whether the suggestions which may be incorrect should be applied as well.
+    /// Suggestions with placeholders are never applied.
+    pub unsafe_fixes: bool,
+    /// Maximal number of the fixing iterations.
+    pub max_iterations: usize,
+    /// Whether every fix should be verified not to introduce any new errors before being applied.
+    pub verify_fixes: bool,
+    /// The registry of the lint rules used by the Cairo lint plugin, to find the fixes of the custom lint rules.
+    pub lint_registry: LintRegistry,
+}
+
+impl Default for FixerConfig {
+    fn default() -> Self {
+        Self {
+            unsafe_fixes: false,
+            max_iterations: DEFAULT_MAX_FIX_ITERATIONS,
+            verify_fixes: false,
+            lint_registry: LintRegistry::default(),
+        }
+    }
+}
+
+/// Result of fixing the crates.
+#[derive(Debug, Default)]
+pub struct FixerOutcome {
+    /// The new content of every file that was changed.
+    pub files: HashMap<FileId, String>,
+    /// The original content of every file that was changed.
+    pub original_files: HashMap<FileId, String>,
+    /// Number of the iterations in which any fix was applied.
+    pub iterations: usize,
+    /// Total number of the applied fixes. The fixes which would not change anything are not applied.
+    pub applied_fixes: usize,
+    /// Whether there were still some fixes left when the iteration limit was reached.
+    pub reached_iteration_limit: bool,
+    /// The fixes which were not applied, because they would break the code.
+    pub rejected_fixes: Vec<RejectedFix>,
+}
+
+/// A fix which was not applied, because the fixed code does not compile.
+#[derive(Debug, Clone)]
+pub struct RejectedFix {
+    /// The file which the fix was suggested for.
+    pub file_id: FileId,
+    /// The rejected fix.
+    pub suggestion: Suggestion,
+    /// The new errors which the fix would introduce, one per line.
+    pub reason: String,
+}
+
+impl FixerOutcome {
+    /// Writes the new content of all the changed files to the disk.
+    pub fn write(&self, db: &dyn FilesGroup) -> anyhow::Result<()> {
+        for (file_id, content) in &self.files {
+            std::fs::write(file_id.full_path(db), content)?;
+        }
+        Ok(())
+    }
+
+    /// Returns the changes made to every changed file as a unified diff.
+    pub fn diffs(&self, db: &dyn FilesGroup) -> HashMap<FileId, String> {
+        self.files
+            .iter()
+            .map(|(file_id, content)| {
+                let path = file_id.full_path(db);
+                let original = &self.original_files[file_id];
+                (*file_id, unified_diff(&path, original, content))
+            })
+            .collect()
+    }
+
+    /// Adds the rejected fixes to the outcome.
+    /// The rejected fixes are suggested again in every iteration, so each of them is reported only once.
+    fn report_rejected_fixes(&mut self, rejected_fixes: Vec<RejectedFix>) {
+        for rejected_fix in rejected_fixes {
+            if !self.rejected_fixes.iter().any(|reported| {
+                reported.file_id == rejected_fix.file_id
+                    && reported.suggestion.title == rejected_fix.suggestion.title
+                    && reported.reason == rejected_fix.reason
+            }) {
+                self.rejected_fixes.push(rejected_fix);
+            }
+        }
+    }
+
+    /// Restores the original content of all the changed files in the database.
+    pub fn revert(&self, db: &mut RootDatabase) {
+        for (file_id, content) in &self.original_files {
+            db.override_file_content(*file_id, Some(content.clone().into()));
+        }
+    }
+}
+
+/// Fixes all the files of the crates iteratively.
+///
+/// The content of the fixed files is overridden in the database, but it is not written to the disk.
+/// Use [`FixerOutcome::write`] to do it, or [`FixerOutcome::revert`] to restore the original content
+/// in the database after a dry run.
+///
+/// # Arguments
+///
+/// * `db` - The database with the Cairo lint plugin, on which the crates are analyzed.
+/// * `crate_ids` - The crates which should be fixed.
+/// * `config` - The configuration of the fixer.
+pub fn fix_crates(
+    db: &mut RootDatabase,
+    crate_ids: &[CrateId],
+    config: &FixerConfig,
+) -> FixerOutcome {
+    let mut outcome = FixerOutcome::default();
+    loop {
+        let diagnostics = collect_crates_diagnostics(db, crate_ids);
+        let mut fixes = HashMap::new();
+        for (file_id, file_fixes) in
+            get_fixes_with_lint_registry(&*db, diagnostics, &config.lint_registry)
+        {
+            if !is_user_file(&*db, file_id) {
+                continue;
+            }
+            let Some(content) = db.file_content(file_id) else {
+                continue;
+            };
+            // The fixes which would not change anything are suggested again in every iteration.
+            let file_fixes = file_fixes
+                .into_iter()
+                .filter(|fix| fix.changes(&content))
+                .collect();
+            let file_fixes = select_fixes(file_fixes, config.unsafe_fixes);
+            let (file_fixes, rejected_fixes) = reject_syntax_errors(file_id, &content, file_fixes);
+            outcome.report_rejected_fixes(rejected_fixes);
+            if !file_fixes.is_empty() {
+                fixes.insert(file_id, file_fixes);
+            }
+        }
+
+        if config.verify_fixes {
+            let (verified_fixes, rejected_fixes) = verify_fixes(db, crate_ids, fixes);
+            fixes = verified_fixes;
+            outcome.report_rejected_fixes(rejected_fixes);
+        }
+
+        if fixes.is_empty() {
+            break;
+        }
+        if outcome.iterations == config.max_iterations {
+            outcome.reached_iteration_limit = true;
+            break;
+        }
+
+        let mut changed = false;
+        for (file_id, fixes) in fixes {
+            let Some(original_content) = db.file_content(file_id) else {
+                continue;
+            };
+            let mut content = original_content.to_string();
+            apply_suggestions(&mut content, &fixes);
+            if *content == *original_content {
+                continue;
+            }
+            outcome
+                .original_files
+                .entry(file_id)
+                .or_insert_with(|| original_content.to_string());
+            db.override_file_content(file_id, Some(content.clone().into()));
+            outcome.files.insert(file_id, content);
+            outcome.applied_fixes += fixes.len();
+            changed = true;
+        }
+        // Nothing is going to change in the next iterations either.
+        if !changed {
+            break;
+        }
+        outcome.iterations += 1;
+    }
+    outcome
+}
+
+/// Verifies that the fixes do not introduce any new parser or semantic errors to the crates.
+///
+/// All the fixes of a file are applied together, by overriding the content of the file in the database,
+/// and the errors of the crates are compared with the ones found before the fixes. If there are any new
+/// errors, the fixes of the file are verified one by one instead: each fix is applied together with
+/// the ones accepted so far, and it is rejected if it introduces any new errors.
+/// The file overrides of the database are restored afterwards.
+///
+/// Returns the fixes that passed the verification, and the rejected ones with the reason.
+///
+/// # Arguments
+///
+/// * `db` - The database with the Cairo lint plugin, on which the crates are analyzed.
+/// * `crate_ids` - The crates which should be checked for the errors.
+/// * `fixes` - The fixes to verify, grouped by the file they should be applied to.
+pub fn verify_fixes(
+    db: &mut RootDatabase,
+    crate_ids: &[CrateId],
+    fixes: HashMap<FileId, Vec<Suggestion>>,
+) -> (HashMap<FileId, Vec<Suggestion>>, Vec<RejectedFix>) {
+    let original_errors = collect_crates_errors(db, crate_ids);
+    // Restoring the overrides as a whole keeps the files which were not overridden so.
+    let original_overrides = db.file_overrides();
+    let mut verified_fixes = HashMap::new();
+    let mut rejected_fixes = Vec::new();
+
+    for (file_id, fixes) in fixes {
+        let Some(original_content) = db.file_content(file_id) else {
+            continue;
+        };
+        let mut errors_after_fixes = |fixes: &[Suggestion]| {
+            let mut content = original_content.to_string();
+            apply_suggestions(&mut content, fixes);
+            db.override_file_content(file_id, Some(content.into()));
+            let new_errors = new_errors(&original_errors, collect_crates_errors(db, crate_ids));
+            db.set_file_overrides(original_overrides.clone());
+            new_errors
+        };
+
+        if errors_after_fixes(&fixes).is_empty() {
+            verified_fixes.insert(file_id, fixes);
+            continue;
+        }
+        // Some of the fixes are at fault, so the good ones are kept.
+        let mut accepted_fixes: Vec<Suggestion> = Vec::new();
+        for fix in fixes {
+            accepted_fixes.push(fix);
+            let new_errors = errors_after_fixes(&accepted_fixes);
+            if !new_errors.is_empty() {
+                rejected_fixes.push(RejectedFix {
+                    file_id,
+                    suggestion: accepted_fixes.pop().unwrap(),
+                    reason: new_errors.join("\n"),
+                });
+            }
+        }
+        if !accepted_fixes.is_empty() {
+            verified_fixes.insert(file_id, accepted_fixes);
+        }
+    }
+    (verified_fixes, rejected_fixes)
+}
+
+/// Rejects the fixes which would introduce new parser errors to the file.
+///
+/// Every fix is applied separately to the content of the file, which is parsed on its own
+/// parser database. Unlike [`verify_fixes`], the database with the crates is not touched,
+/// so this check is cheap enough to be always done.
+///
+/// Returns the fixes that passed the check, and the rejected ones with the reason.
+fn reject_syntax_errors(
+    file_id: FileId,
+    content: &str,
+    fixes: Vec<Suggestion>,
+) -> (Vec<Suggestion>, Vec<RejectedFix>) {
+    let parser_db = SimpleParserDatabase::default();
+    let original_errors = syntax_errors(&parser_db, content);
+    fixes.into_iter().partition_map(|fix| {
+        let mut fixed_content = content.to_string();
+        fix.apply(&mut fixed_content);
+        let new_errors = new_errors(&original_errors, syntax_errors(&parser_db, &fixed_content));
+        if new_errors.is_empty() {
+            Either::Left(fix)
+        } else {
+            Either::Right(RejectedFix {
+                file_id,
+                suggestion: fix,
+                reason: new_errors.join("\n"),
+            })
+        }
+    })
+}
+
+/// Collects the messages of all the parser errors of the content.
+fn syntax_errors(parser_db: &SimpleParserDatabase, content: &str) -> Vec<String> {
+    let (_, diagnostics) = parser_db.parse_virtual_with_diagnostics(content);
+    diagnostics
+        .get_all()
+        .iter()
+        .map(|diagnostic| diagnostic.format(parser_db))
+        .collect()
+}
+
+/// Picks the fixes which can be applied together, based on their applicability.
+/// If some of the fixes overlap, only the first one (in the order of appearance in the file) is picked.
+pub(crate) fn select_fixes(fixes: Vec<Suggestion>, unsafe_fixes: bool) -> Vec<Suggestion> {
+    let mut fixes = fixes
+        .into_iter()
+        .filter(|fix| match fix.applicability {
+            Applicability::MachineApplicable => true,
+            Applicability::MaybeIncorrect => unsafe_fixes,
+            Applicability::HasPlaceholders => false,
+        })
+        .collect_vec();
+    fixes.sort_by_key(|fix| fix.edits.iter().map(|edit| edit.span.start).min());
+
+    let mut selected_fixes: Vec<Suggestion> = Vec::with_capacity(fixes.len());
+    for fix in fixes {
+        if !selected_fixes
+            .iter()
+            .any(|selected_fix| selected_fix.overlaps(&fix))
+        {
+            selected_fixes.push(fix);
+        }
+    }
+    selected_fixes
+}
+
+/// Renders the changes between the original and the fixed content of the file as a unified diff.
+/// The diff is empty if the contents are the same.
+pub fn unified_diff(path: &str, original: &str, fixed: &str) -> String {
+    if original == fixed {
+        return String::new();
+    }
+    TextDiff::from_lines(original, fixed)
+        .unified_diff()
+        .header(path, path)
+        .to_string()
+}
+
+/// Collects the diagnostics of all the modules of the crates.
+fn collect_crates_diagnostics(db: &RootDatabase, crate_ids: &[CrateId]) -> Vec<SemanticDiagnostic> {
+    crate_ids
+        .iter()
+        .flat_map(|crate_id| db.crate_modules(*crate_id).iter().copied().collect_vec())
+        .filter_map(|module_id| db.module_semantic_diagnostics(module_id).ok())
+        .flat_map(|diagnostics| diagnostics.get_all())
+        .collect()
+}
+
+/// Collects the messages of all the errors found in the crates, including the parser ones.
+fn collect_crates_errors(db: &RootDatabase, crate_ids: &[CrateId]) -> Vec<String> {
+    let mut errors = Vec::new();
+    DiagnosticsReporter::callback(|entry| {
+        if entry.severity() == Severity::Error {
+            // The location of the error changes with the fix, so only the message is compared.
+            let message = entry.message().lines().next().unwrap_or_default();
+            errors.push(message.to_string());
+        }
+    })
+    .with_crates(crate_ids)
+    .check(db);
+    errors
+}
+
+/// Returns the errors which are not among the original ones.
+fn new_errors(original_errors: &[String], errors: Vec<String>) -> Vec<String> {
+    let mut original_errors = original_errors.iter().collect_vec();
+    errors
+        .into_iter()
+        .filter(|error| {
+            match original_errors
+                .iter()
+                .position(|original_error| *original_error == error)
+            {
+                Some(index) => {
+                    original_errors.swap_remove(index);
+                    false
+                }
+                None => true,
+            }
+        })
+        .collect()
+}
+
+/// Checks if the file is written by the user, i.e. it is not generated by the compiler plugins.
+fn is_user_file(db: &dyn FilesGroup, file_id: FileId) -> bool {
+    match file_id.lookup_intern(db) {
+        FileLongId::OnDisk(_) => true,
+        FileLongId::Virtual(file) => file.parent.is_none(),
+        FileLongId::External(_) => false,
+    }
+}
@@ -1,6 +1,9 @@
-//! # Import Fixes for Cairo Lint
+//! # Fixes for Cairo Lint
 //!
-//! This module provides functionality to detect and fix unused imports in Cairo code.
+//! This module describes the fixes suggested for the diagnostics as [`Suggestion`]s, which consist of
+//! a title, an [`Applicability`] level and a list of file [`Edit`]s.
+//!
+//! It also provides functionality to detect and fix unused imports in Cairo code.
 //! The process involves three main steps:
 //!
 //! 1. Collecting unused imports: Analyze semantic diagnostics to identify unused imports.
@@ -10,30 +13,172 @@
 //! The module handles both single imports and multi-imports, ensuring that only unused
 //! items are removed while preserving the structure of the import statements.
 
+use std::cmp::Reverse;
 use std::collections::HashMap;
 
 use cairo_lang_defs::ids::UseId;
 use cairo_lang_defs::plugin::PluginDiagnostic;
 use cairo_lang_diagnostics::DiagnosticEntry;
 use cairo_lang_filesystem::ids::FileId;
-use cairo_lang_filesystem::span::TextSpan;
+use cairo_lang_filesystem::span::{TextOffset, TextSpan};
 use cairo_lang_semantic::diagnostic::SemanticDiagnosticKind;
 use cairo_lang_semantic::SemanticDiagnostic;
 use cairo_lang_syntax::node::kind::SyntaxKind;
 use cairo_lang_syntax::node::{SyntaxNode, TypedStablePtr, TypedSyntaxNode};
+use itertools::Itertools;
 use log::debug;
 
-use crate::context::get_fix_for_diagnostic_message;
+use crate::context::LintRegistry;
 use cairo_lang_defs::db::DefsGroup;
 use cairo_lang_semantic::db::SemanticGroup;
 use cairo_lang_syntax::node::db::SyntaxGroup;
+use serde::{Deserialize, Serialize};
 
-/// Represents a fix for a diagnostic, containing the span of code to be replaced
-/// and the suggested replacement.
-#[derive(Debug, Clone)]
-pub struct Fix {
+/// Describes how confident we are that a suggestion is correct, and whether it can be applied
+/// without the user reviewing it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Applicability {
+    /// The suggestion is definitely what the user intended, and it can be applied automatically.
+    MachineApplicable,
+    /// The suggestion may be what the user intended, but it is uncertain.
+    /// It should compile, but it can change the behaviour of the code.
+    MaybeIncorrect,
+    /// The suggestion contains placeholders, which have to be filled in by the user.
+    /// It should never be applied automatically.
+    HasPlaceholders,
+}
+
+impl Applicability {
+    /// Checks if the suggestion can be applied without the user reviewing it.
+    pub fn is_machine_applicable(&self) -> bool {
+        *self == Applicability::MachineApplicable
+    }
+}
+
+/// A single change of the file text. The text in the span is replaced with the replacement.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Edit {
     pub span: TextSpan,
-    pub suggestion: String,
+    pub replacement: String,
+}
+
+impl Edit {
+    /// Replaces the text in the span.
+    pub fn replace(span: TextSpan, replacement: impl Into<String>) -> Self {
+        Self {
+            span,
+            replacement: replacement.into(),
+        }
+    }
+
+    /// Replaces the whole text of the node.
+    pub fn replace_node(
+        db: &dyn SyntaxGroup,
+        node: SyntaxNode,
+        replacement: impl Into<String>,
+    ) -> Self {
+        Self::replace(node.span(db), replacement)
+    }
+
+    /// Inserts the text at the given offset.
+    pub fn insert(offset: TextOffset, text: impl Into<String>) -> Self {
+        Self::replace(
+            TextSpan {
+                start: offset,
+                end: offset,
+            },
+            text,
+        )
+    }
+
+    /// Deletes the text in the span.
+    pub fn delete(span: TextSpan) -> Self {
+        Self::replace(span, String::new())
+    }
+
+    /// Checks if the edits touch the same part of the file.
+    /// Two insertions at the same offset are considered overlapping, as their order would be ambiguous.
+    pub fn overlaps(&self, other: &Edit) -> bool {
+        if self.span.start == other.span.start {
+            return true;
+        }
+        self.span.start < other.span.end && other.span.start < self.span.end
+    }
+}
+
+/// A suggested fix of a diagnostic. It consists of one or more edits of a single file,
+/// which should be applied together.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Suggestion {
+    /// Human-readable description of the suggestion, e.g. "Remove the double parentheses".
+    pub title: String,
+    pub applicability: Applicability,
+    /// Non-overlapping edits of the file.
+    pub edits: Vec<Edit>,
+}
+
+impl Suggestion {
+    /// Creates a machine applicable suggestion.
+    pub fn new(title: impl Into<String>, edits: Vec<Edit>) -> Self {
+        Self {
+            title: title.into(),
+            applicability: Applicability::MachineApplicable,
+            edits,
+        }
+    }
+
+    /// Creates a machine applicable suggestion replacing the whole text of the node.
+    pub fn replace_node(
+        db: &dyn SyntaxGroup,
+        title: impl Into<String>,
+        node: SyntaxNode,
+        replacement: impl Into<String>,
+    ) -> Self {
+        Self::new(title, vec![Edit::replace_node(db, node, replacement)])
+    }
+
+    /// Changes the applicability of the suggestion.
+    pub fn with_applicability(mut self, applicability: Applicability) -> Self {
+        self.applicability = applicability;
+        self
+    }
+
+    /// Checks if any of the edits of this suggestion overlaps with any of the edits of the other one.
+    pub fn overlaps(&self, other: &Suggestion) -> bool {
+        self.edits.iter().any(|edit| {
+            other
+                .edits
+                .iter()
+                .any(|other_edit| edit.overlaps(other_edit))
+        })
+    }
+
+    /// Checks if applying the suggestion would change the text.
+    pub fn changes(&self, text: &str) -> bool {
+        self.edits
+            .iter()
+            .any(|edit| text.get(edit.span.to_str_range()) != Some(edit.replacement.as_str()))
+    }
+
+    /// Applies the edits of the suggestion to the text.
+    /// The edits cannot overlap, and their spans must point into the text.
+    pub fn apply(&self, text: &mut String) {
+        apply_suggestions(text, std::slice::from_ref(self));
+    }
+}
+
+/// Applies the edits of all the suggestions to the text at once.
+/// The suggestions cannot overlap, and their spans must point into the text.
+pub fn apply_suggestions(text: &mut String, suggestions: &[Suggestion]) {
+    let mut edits = suggestions
+        .iter()
+        .flat_map(|suggestion| &suggestion.edits)
+        .collect_vec();
+    edits.sort_by_key(|edit| Reverse(edit.span.start));
+    for edit in edits {
+        text.replace_range(edit.span.to_str_range(), &edit.replacement);
+    }
 }
 
 /// Attempts to fix a semantic diagnostic.
@@ -48,16 +193,35 @@ pub struct Fix {
 ///
 /// # Returns
 ///
-/// An `Option<(SyntaxNode, String)>` where the `SyntaxNode` represents the node to be
-/// replaced, and the `String` is the suggested replacement. Returns `None` if no fix
-/// is available for the given diagnostic.
+/// An `Option<Suggestion>` with the edits fixing the diagnostic.
+/// Returns `None` if no fix is available for the given diagnostic.
 pub fn fix_semantic_diagnostic(
     db: &dyn SemanticGroup,
     diag: &SemanticDiagnostic,
-) -> Option<(SyntaxNode, String)> {
+) -> Option<Suggestion> {
+    fix_semantic_diagnostic_with_lint_registry(db, diag, &LintRegistry::default())
+}
+
+/// Attempts to fix a semantic diagnostic, taking the custom lint rules from the registry into account.
+///
+/// # Arguments
+///
+/// * `db` - A reference to the `dyn SemanticGroup`
+/// * `diag` - A reference to the SemanticDiagnostic to be fixed
+/// * `lint_registry` - The registry of the lint rules used by the Cairo lint plugin
+///
+/// # Returns
+///
+/// An `Option<Suggestion>` with the edits fixing the diagnostic.
+/// Returns `None` if no fix is available for the given diagnostic.
+pub fn fix_semantic_diagnostic_with_lint_registry(
+    db: &dyn SemanticGroup,
+    diag: &SemanticDiagnostic,
+    lint_registry: &LintRegistry,
+) -> Option<Suggestion> {
     match diag.kind {
         SemanticDiagnosticKind::PluginDiagnostic(ref plugin_diag) => {
-            fix_plugin_diagnostic(db, plugin_diag)
+            fix_plugin_diagnostic(db, plugin_diag, lint_registry)
         }
         SemanticDiagnosticKind::UnusedImport(_) => {
             debug!("Unused imports should be handled in preemptively");
@@ -77,17 +241,18 @@ pub fn fix_semantic_diagnostic(
 /// * `db` - A reference to the `dyn SemanticGroup`
 /// * `diag` - A reference to the SemanticDiagnostic
 /// * `plugin_diag` - A reference to the PluginDiagnostic
+/// * `lint_registry` - The registry of the lint rules used by the Cairo lint plugin
 ///
 /// # Returns
 ///
-/// An `Option<(SyntaxNode, String)>` containing the node to be replaced and the
-/// suggested replacement.
+/// An `Option<Suggestion>` with the edits fixing the diagnostic.
 fn fix_plugin_diagnostic(
     db: &dyn SemanticGroup,
     plugin_diag: &PluginDiagnostic,
-) -> Option<(SyntaxNode, String)> {
+    lint_registry: &LintRegistry,
+) -> Option<Suggestion> {
     let node = plugin_diag.stable_ptr.lookup(db);
-    get_fix_for_diagnostic_message(db, node, &plugin_diag.message)
+    lint_registry.get_fix_for_diagnostic(db, node, plugin_diag)
 }
 
 /// Represents a fix for unused imports in a specific syntax node.
@@ -171,7 +336,7 @@ fn process_unused_import(
     }
 }
 
-/// Applies the collected import fixes to generate a list of Fix objects.
+/// Applies the collected import fixes to generate a list of suggestions.
 ///
 /// # Arguments
 ///
@@ -180,26 +345,22 @@ fn process_unused_import(
 ///
 /// # Returns
 ///
-/// A vector of Fix objects representing the applied fixes.
+/// A vector of suggestions, one for each of the import fixes.
 pub fn apply_import_fixes(
     db: &dyn SyntaxGroup,
     fixes: &HashMap<SyntaxNode, ImportFix>,
-) -> Vec<Fix> {
+) -> Vec<Suggestion> {
     fixes
         .iter()
-        .flat_map(|(_, import_fix)| {
-            let span = import_fix.node.span(db);
-
-            if import_fix.items_to_remove.is_empty() {
+        .map(|(_, import_fix)| {
+            let edits = if import_fix.items_to_remove.is_empty() {
                 // Single import case: remove entire import
-                vec![Fix {
-                    span,
-                    suggestion: String::new(),
-                }]
+                vec![Edit::delete(import_fix.node.span(db))]
             } else {
                 // Multi-import case
                 handle_multi_import(db, &import_fix.node, &import_fix.items_to_remove)
-            }
+            };
+            Suggestion::new("Remove the unused imports", edits)
         })
         .collect()
 }
@@ -214,12 +375,12 @@ pub fn apply_import_fixes(
 ///
 /// # Returns
 ///
-/// A vector of Fix objects for the multi-import case.
+/// A vector of edits for the multi-import case.
 fn handle_multi_import(
     db: &dyn SyntaxGroup,
     node: &SyntaxNode,
     items_to_remove: &[String],
-) -> Vec<Fix> {
+) -> Vec<Edit> {
     if all_descendants_removed(db, node, items_to_remove) {
         remove_entire_import(db, node)
     } else {
@@ -260,8 +421,8 @@ fn all_descendants_removed(
 ///
 /// # Returns
 ///
-/// A vector of Fix objects for removing the entire import.
-fn remove_entire_import(db: &dyn SyntaxGroup, node: &SyntaxNode) -> Vec<Fix> {
+/// A vector of edits removing the entire import.
+fn remove_entire_import(db: &dyn SyntaxGroup, node: &SyntaxNode) -> Vec<Edit> {
     let mut current_node = *node;
     while let Some(parent) = current_node.parent(db) {
         // Go up until we find a UsePathList on the path - then, we can remove the current node from that
@@ -281,10 +442,7 @@ fn remove_entire_import(db: &dyn SyntaxGroup, node: &SyntaxNode) -> Vec<Fix> {
         }
         current_node = parent;
     }
-    vec![Fix {
-        span: current_node.span(db),
-        suggestion: String::new(),
-    }]
+    vec![Edit::delete(current_node.span(db))]
 }
 
 /// Removes specific items from a multi-import statement.
@@ -297,12 +455,12 @@ fn remove_entire_import(db: &dyn SyntaxGroup, node: &SyntaxNode) -> Vec<Fix> {
 ///
 /// # Returns
 ///
-/// A vector of Fix objects for removing specific items from the import.
+/// A vector of edits removing specific items from the import.
 fn remove_specific_items(
     db: &dyn SyntaxGroup,
     node: &SyntaxNode,
     items_to_remove: &[String],
-) -> Vec<Fix> {
+) -> Vec<Edit> {
     let use_path_list = find_use_path_list(db, node);
     let children = use_path_list.get_children(db);
     let children: Vec<SyntaxNode> = children
@@ -325,10 +483,7 @@ fn remove_specific_items(
         format!("{{{}}}", items.join(", "))
     };
 
-    vec![Fix {
-        span: node.span(db),
-        suggestion: text,
-    }]
+    vec![Edit::replace_node(db, *node, text)]
 }
 
 /// Finds the UsePathList node within a given syntax node.
@@ -1,9 +1,14 @@
 use cairo_lang_defs::plugin::PluginDiagnostic;
-use fixes::{apply_import_fixes, collect_unused_imports, fix_semantic_diagnostic, Fix, ImportFix};
+use fixer::{select_fixes, unified_diff};
+use fixes::{
+    apply_import_fixes, apply_suggestions, collect_unused_imports,
+    fix_semantic_diagnostic_with_lint_registry, ImportFix, Suggestion,
+};
 
 use cairo_lang_syntax::node::SyntaxNode;
 
-use std::{cmp::Reverse, collections::HashMap};
+use std::collections::HashMap;
+use std::ops::{Deref, DerefMut};
 
 use anyhow::{anyhow, Result};
 use cairo_lang_diagnostics::DiagnosticEntry;
@@ -12,23 +17,80 @@ use cairo_lang_filesystem::ids::FileId;
 use cairo_lang_semantic::{
     db::SemanticGroup, diagnostic::SemanticDiagnosticKind, SemanticDiagnostic,
 };
+use itertools::Itertools;
+use serde::{Deserialize, Serialize};
 
 pub static CAIRO_LINT_TOOL_NAME: &str = "cairo-lint";
 
-/// Describes tool metadata for the Cairo lint.
+/// Describes tool metadata for the Cairo lint, in the original format: whether each of the lint rules is enabled.
+/// It can be converted into the [`CairoLintConfig`], which is accepted everywhere in its place.
 /// IMPORTANT: This one is a public type, so watch out when modifying it,
 /// as it might break the backwards compatibility.
 pub type CairoLintToolMetadata = HashMap<String, bool>;
 
+/// Configuration of the Cairo lint from the tool metadata, with the levels and the options
+/// of the lint rules and the call pattern rules, see [`LintConfig`].
+/// IMPORTANT: This one is a public type, so watch out when modifying it,
+/// as it might break the backwards compatibility.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct CairoLintConfig(HashMap<String, LintConfig>);
+
+impl Deref for CairoLintConfig {
+    type Target = HashMap<String, LintConfig>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for CairoLintConfig {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl From<HashMap<String, LintConfig>> for CairoLintConfig {
+    fn from(configs: HashMap<String, LintConfig>) -> Self {
+        Self(configs)
+    }
+}
+
+impl From<CairoLintToolMetadata> for CairoLintConfig {
+    fn from(tool_metadata: CairoLintToolMetadata) -> Self {
+        tool_metadata
+            .into_iter()
+            .map(|(name, enabled)| (name, LintConfig::Enabled(enabled)))
+            .collect()
+    }
+}
+
+impl FromIterator<(String, LintConfig)> for CairoLintConfig {
+    fn from_iter<T: IntoIterator<Item = (String, LintConfig)>>(iter: T) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+pub mod baseline;
+pub mod changed_lines;
+pub mod code_actions;
+pub mod config;
 pub mod context;
 pub mod diagnostics;
+pub mod explain;
+pub mod fixer;
 pub mod fixes;
 mod helper;
 pub mod lints;
+pub mod listing;
+pub mod parallel;
 pub mod plugin;
 mod queries;
+pub mod sarif;
 
-use context::{get_lint_type_from_diagnostic_message, CairoLintKind};
+use config::LintConfig;
+use context::{get_lint_type_from_diagnostic_message, CairoLintKind, LintRegistry};
+pub use queries::{FunctionBodyVisitor, VisitorCallback};
 
 /// Gets the fixes for a set of a compiler diagnostics (that uses Cairo lint analyzer plugin).
 /// # Arguments
@@ -40,18 +102,35 @@ use context::{get_lint_type_from_diagnostic_message, CairoLintKind};
 ///
 /// A HashMap where:
 /// * keys are FileIds (that points to a file that the fixes might be applied to)
-/// * values are vectors of proposed Fixes.
+/// * values are vectors of proposed Suggestions.
 pub fn get_fixes(
     db: &(dyn SemanticGroup + 'static),
     diagnostics: Vec<SemanticDiagnostic>,
-) -> HashMap<FileId, Vec<Fix>> {
+) -> HashMap<FileId, Vec<Suggestion>> {
+    get_fixes_with_lint_registry(db, diagnostics, &LintRegistry::default())
+}
+
+/// Gets the fixes for a set of a compiler diagnostics, taking the custom lint rules from the registry into account.
+/// See [`get_fixes`] for the details.
+///
+/// # Arguments
+///
+/// * `db` - The reference to the `dyn SemanticGroup` that the diagnostics were based upon.
+/// * `diagnostics` - The list of compiler diagnostics. Make sure that the diagnostics from the Cairo lint analyzer plugin are also included.
+/// * `lint_registry` - The registry of the lint rules used by the Cairo lint analyzer plugin.
+pub fn get_fixes_with_lint_registry(
+    db: &(dyn SemanticGroup + 'static),
+    diagnostics: Vec<SemanticDiagnostic>,
+    lint_registry: &LintRegistry,
+) -> HashMap<FileId, Vec<Suggestion>> {
     // Handling unused imports separately as we need to run pre-analysis on the diagnostics.
     // to handle complex cases.
     let unused_imports: HashMap<FileId, HashMap<SyntaxNode, ImportFix>> =
         collect_unused_imports(db, &diagnostics);
     let mut fixes = HashMap::new();
     unused_imports.keys().for_each(|file_id| {
-        let file_fixes: Vec<Fix> = apply_import_fixes(db, unused_imports.get(file_id).unwrap());
+        let file_fixes: Vec<Suggestion> =
+            apply_import_fixes(db, unused_imports.get(file_id).unwrap());
         fixes.insert(*file_id, file_fixes);
     });
 
@@ -61,15 +140,14 @@ pub fn get_fixes(
         .collect::<Vec<_>>();
 
     for diag in diags_without_imports {
-        if let Some((fix_node, fix)) = fix_semantic_diagnostic(db, diag) {
+        if let Some(suggestion) =
+            fix_semantic_diagnostic_with_lint_registry(db, diag, lint_registry)
+        {
             let location = diag.location(db);
             fixes
                 .entry(location.file_id)
                 .or_insert_with(Vec::new)
-                .push(Fix {
-                    span: fix_node.span(db),
-                    suggestion: fix,
-                });
+                .push(suggestion);
         }
     }
     fixes
@@ -77,49 +155,73 @@ pub fn get_fixes(
 
 /// Applies the fixes to the file.
 ///
+/// By default only the machine applicable suggestions are applied. Suggestions with placeholders are never applied.
+/// If some of the suggestions overlap, only the first one (in the order of appearance in the file) is applied.
+///
 /// # Arguments
 ///
 /// * `file_id` - The FileId of the file that the fixes should be applied to.
 /// * `fixes` - The list of fixes that should be applied to the file.
-pub fn apply_file_fixes(file_id: FileId, fixes: Vec<Fix>, db: &dyn FilesGroup) -> Result<()> {
-    let mut fixes = fixes;
-    fixes.sort_by_key(|fix| Reverse(fix.span.start));
-    let mut fixable_diagnostics = Vec::with_capacity(fixes.len());
-    if fixes.len() <= 1 {
-        fixable_diagnostics = fixes;
-    } else {
-        // Check if we have nested diagnostics. If so it's a nightmare to fix hence just ignore it
-        for i in 0..fixes.len() - 1 {
-            let first = fixes[i].span;
-            let second = fixes[i + 1].span;
-            if first.start >= second.end {
-                fixable_diagnostics.push(fixes[i].clone());
-                if i == fixes.len() - 1 {
-                    fixable_diagnostics.push(fixes[i + 1].clone());
-                }
-            }
-        }
-    }
-    // Get all the files that need to be fixed
-    let mut files: HashMap<FileId, String> = HashMap::default();
-    files.insert(
-        file_id,
-        db.file_content(file_id)
-            .ok_or(anyhow!("{} not found", file_id.file_name(db)))?
-            .to_string(),
-    );
-    // Fix the files
-    for fix in fixable_diagnostics {
-        // Can't fail we just set the file value.
-        files
-            .entry(file_id)
-            .and_modify(|file| file.replace_range(fix.span.to_str_range(), &fix.suggestion));
-    }
-    // Dump them in place
-    std::fs::write(file_id.full_path(db), files.get(&file_id).unwrap())?;
+/// * `unsafe_fixes` - Whether the suggestions which may be incorrect should be applied as well.
+pub fn apply_file_fixes(
+    file_id: FileId,
+    fixes: Vec<Suggestion>,
+    db: &dyn FilesGroup,
+    unsafe_fixes: bool,
+) -> Result<()> {
+    let file = get_fixed_file_content(file_id, fixes, db, unsafe_fixes)?;
+    // Dump it in place
+    std::fs::write(file_id.full_path(db), file)?;
     Ok(())
 }
 
+/// Returns the content of the file with the fixes applied, without touching the filesystem.
+///
+/// The fixes are picked the same way as in [`apply_file_fixes`].
+///
+/// # Arguments
+///
+/// * `file_id` - The FileId of the file that the fixes should be applied to.
+/// * `fixes` - The list of fixes that should be applied to the file.
+/// * `unsafe_fixes` - Whether the suggestions which may be incorrect should be applied as well.
+pub fn get_fixed_file_content(
+    file_id: FileId,
+    fixes: Vec<Suggestion>,
+    db: &dyn FilesGroup,
+    unsafe_fixes: bool,
+) -> Result<String> {
+    let fixes = select_fixes(fixes, unsafe_fixes);
+    let mut file = db
+        .file_content(file_id)
+        .ok_or(anyhow!("{} not found", file_id.file_name(db)))?
+        .to_string();
+    apply_suggestions(&mut file, &fixes);
+    Ok(file)
+}
+
+/// Returns the changes which the fixes would make to the file as a unified diff,
+/// without touching the filesystem. The diff is empty if nothing would be changed.
+///
+/// The fixes are picked the same way as in [`apply_file_fixes`].
+///
+/// # Arguments
+///
+/// * `file_id` - The FileId of the file that the fixes should be applied to.
+/// * `fixes` - The list of fixes that should be applied to the file.
+/// * `unsafe_fixes` - Whether the suggestions which may be incorrect should be applied as well.
+pub fn get_file_fixes_diff(
+    file_id: FileId,
+    fixes: Vec<Suggestion>,
+    db: &dyn FilesGroup,
+    unsafe_fixes: bool,
+) -> Result<String> {
+    let fixed = get_fixed_file_content(file_id, fixes, db, unsafe_fixes)?;
+    let original = db
+        .file_content(file_id)
+        .ok_or(anyhow!("{} not found", file_id.file_name(db)))?;
+    Ok(unified_diff(&file_id.full_path(db), &original, &fixed))
+}
+
 /// Checks if the diagnostic is a panic diagnostic.
 pub fn is_panic_diagnostic(diag: &PluginDiagnostic) -> bool {
     get_lint_type_from_diagnostic_message(&diag.message) == CairoLintKind::Panic
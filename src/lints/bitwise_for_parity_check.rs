@@ -1,35 +1,27 @@
-use cairo_lang_defs::ids::{FunctionWithBodyId, ModuleItemId, TopLevelLanguageElementId};
-use cairo_lang_defs::plugin::PluginDiagnostic;
-use cairo_lang_diagnostics::Severity;
+use cairo_lang_defs::ids::{FunctionWithBodyId, TopLevelLanguageElementId};
 use cairo_lang_semantic::db::SemanticGroup;
 use cairo_lang_semantic::{Arenas, Expr, ExprFunctionCall, ExprFunctionCallArg};
 use cairo_lang_syntax::node::TypedStablePtr;
 use if_chain::if_chain;
+use indoc::indoc;
 use num_bigint::BigInt;
 
-use crate::context::{CairoLintKind, Lint};
-use crate::queries::{get_all_function_bodies, get_all_function_calls};
+use crate::context::{CairoLintKind, CheckContext, Lint, LintCategory, LintDocs};
+use crate::diagnostics::LintDiagnostic;
 
 use super::AND;
 
 pub struct BitwiseForParity;
 
-/// ## What it does
-///
-/// Checks for `x & 1` which is unoptimized in cairo and could be replaced by `x % 1`.
-///
-/// ## Example
-///
-/// ```cairo
-/// fn main() {
-///     let _a = 200_u32 & 1;
-/// }
-/// ```
 impl Lint for BitwiseForParity {
     fn allowed_name(&self) -> &'static str {
         "bitwise_for_parity_check"
     }
 
+    fn id(&self) -> &'static str {
+        "CL0016"
+    }
+
     fn diagnostic_message(&self) -> &'static str {
         "You seem to be trying to use `&` for parity check. Consider using `DivRem::div_rem()` instead."
     }
@@ -37,29 +29,32 @@ impl Lint for BitwiseForParity {
     fn kind(&self) -> CairoLintKind {
         CairoLintKind::BitwiseForParityCheck
     }
-}
 
-/// Checks for `x & 1` which is unoptimized in cairo and can be replaced by `x % 1`
-pub fn check_bitwise_for_parity(
-    db: &dyn SemanticGroup,
-    item: &ModuleItemId,
-    diagnostics: &mut Vec<PluginDiagnostic>,
-) {
-    let function_bodies = get_all_function_bodies(db, item);
-    for function_body in function_bodies.iter() {
-        let function_call_exprs = get_all_function_calls(function_body);
-        let arenas = &function_body.arenas;
-        for function_call_expr in function_call_exprs {
-            check_single_bitwise_for_parity(db, &function_call_expr, arenas, diagnostics);
+    fn category(&self) -> LintCategory {
+        LintCategory::Perf
+    }
+
+    fn docs(&self) -> LintDocs {
+        LintDocs {
+            description:
+                "Checks for `x & 1` which is unoptimized in cairo and could be replaced by `x % 1`.",
+            bad_example: Some(indoc! {r#"
+                fn main() {
+                    let _a = 200_u32 & 1;
+                }
+            "#}),
+            ..Default::default()
         }
     }
 }
 
-fn check_single_bitwise_for_parity(
+/// Checks for `x & 1` which is unoptimized in cairo and can be replaced by `x % 1`
+pub fn check_bitwise_for_parity(
     db: &dyn SemanticGroup,
     function_call_expr: &ExprFunctionCall,
     arenas: &Arenas,
-    diagnostics: &mut Vec<PluginDiagnostic>,
+    _context: &CheckContext,
+    diagnostics: &mut Vec<LintDiagnostic>,
 ) {
     let Ok(Some(func_id)) = function_call_expr.function.get_concrete(db).body(db) else {
         return;
@@ -80,12 +75,7 @@ fn check_single_bitwise_for_parity(
         if let Expr::Literal(lit) = &arenas.exprs[val];
         if lit.value == BigInt::from(1u8);
         then {
-            diagnostics.push(PluginDiagnostic {
-                stable_ptr: function_call_expr.stable_ptr.untyped(),
-                message: BitwiseForParity.diagnostic_message().to_string(),
-                severity: Severity::Warning,
-                relative_span: None
-            });
+            diagnostics.push(LintDiagnostic::new(&BitwiseForParity, function_call_expr.stable_ptr.untyped()));
         }
     }
 }
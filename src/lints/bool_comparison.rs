@@ -1,6 +1,4 @@
-use cairo_lang_defs::ids::{ModuleItemId, TopLevelLanguageElementId};
-use cairo_lang_defs::plugin::PluginDiagnostic;
-use cairo_lang_diagnostics::Severity;
+use cairo_lang_defs::ids::TopLevelLanguageElementId;
 use cairo_lang_semantic::db::SemanticGroup;
 use cairo_lang_semantic::{Arenas, Expr, ExprFunctionCall, ExprFunctionCallArg};
 use cairo_lang_syntax::node::db::SyntaxGroup;
@@ -8,42 +6,23 @@ use cairo_lang_syntax::node::kind::SyntaxKind;
 use cairo_lang_syntax::node::SyntaxNode;
 use cairo_lang_syntax::node::{ast::ExprBinary, TypedStablePtr, TypedSyntaxNode};
 use if_chain::if_chain;
+use indoc::indoc;
 
-use crate::context::{CairoLintKind, Lint};
-use crate::queries::{get_all_function_bodies, get_all_function_calls};
+use crate::context::{CairoLintKind, CheckContext, Lint, LintCategory, LintDocs};
+use crate::diagnostics::LintDiagnostic;
+use crate::fixes::Suggestion;
 
 pub struct BoolComparison;
 
-/// ## What it does
-///
-/// Checks for direct variable with boolean literal like `a == true` or `a == false`.
-///
-/// ## Example
-///
-/// ```cairo
-/// fn main() {
-///     let x = true;
-///     if x == true {
-///         println!("x is true");
-///     }
-/// }
-/// ```
-///
-/// Can be rewritten as:
-///
-/// ```cairo
-/// fn main() {
-///    let x = true;
-///    if x {
-///        println!("x is true");
-///    }
-/// }
-/// ```
 impl Lint for BoolComparison {
     fn allowed_name(&self) -> &'static str {
         "bool_comparison"
     }
 
+    fn id(&self) -> &'static str {
+        "CL0010"
+    }
+
     fn diagnostic_message(&self) -> &'static str {
         "Unnecessary comparison with a boolean value. Use the variable directly."
     }
@@ -52,36 +31,56 @@ impl Lint for BoolComparison {
         CairoLintKind::BoolComparison
     }
 
+    fn category(&self) -> LintCategory {
+        LintCategory::Complexity
+    }
+
+    fn docs(&self) -> LintDocs {
+        LintDocs {
+            description:
+                "Checks for direct variable with boolean literal like `a == true` or `a == false`.",
+            bad_example: Some(indoc! {r#"
+                fn main() {
+                    let x = true;
+                    if x == true {
+                        println!("x is true");
+                    }
+                }
+            "#}),
+            good_example: Some(indoc! {r#"
+                fn main() {
+                   let x = true;
+                   if x {
+                       println!("x is true");
+                   }
+                }
+            "#}),
+            ..Default::default()
+        }
+    }
+
     fn has_fixer(&self) -> bool {
         true
     }
 
-    fn fix(&self, db: &dyn SemanticGroup, node: SyntaxNode) -> Option<(SyntaxNode, String)> {
-        fix_bool_comparison(db.upcast(), node)
+    fn fix(&self, db: &dyn SemanticGroup, node: SyntaxNode) -> Option<Suggestion> {
+        let (node, replacement) = fix_bool_comparison(db.upcast(), node)?;
+        Some(Suggestion::replace_node(
+            db.upcast(),
+            "Simplify the boolean comparison",
+            node,
+            replacement,
+        ))
     }
 }
 
 /// Checks for ` a == true`. Bool comparisons are useless and can be rewritten more clearly.
 pub fn check_bool_comparison(
-    db: &dyn SemanticGroup,
-    item: &ModuleItemId,
-    diagnostics: &mut Vec<PluginDiagnostic>,
-) {
-    let function_bodies = get_all_function_bodies(db, item);
-    for function_body in function_bodies.iter() {
-        let function_call_exprs = get_all_function_calls(function_body);
-        let arenas = &function_body.arenas;
-        for function_call_expr in function_call_exprs {
-            check_single_bool_comparison(db, &function_call_expr, arenas, diagnostics);
-        }
-    }
-}
-
-fn check_single_bool_comparison(
     db: &dyn SemanticGroup,
     function_call_expr: &ExprFunctionCall,
     arenas: &Arenas,
-    diagnostics: &mut Vec<PluginDiagnostic>,
+    _context: &CheckContext,
+    diagnostics: &mut Vec<LintDiagnostic>,
 ) {
     // Check if the function call is the bool partial eq function (==).
     if !function_call_expr
@@ -100,12 +99,7 @@ fn check_single_bool_comparison(
             if let Expr::EnumVariantCtor(enum_var) = &arenas.exprs[snap.inner];
             if enum_var.variant.concrete_enum_id.enum_id(db).full_path(db.upcast()) == "core::bool";
             then {
-                diagnostics.push(PluginDiagnostic {
-                    stable_ptr: function_call_expr.stable_ptr.untyped(),
-                    message: BoolComparison.diagnostic_message().to_string(),
-                    severity: Severity::Warning,
-                    relative_span: None
-                });
+                diagnostics.push(LintDiagnostic::new(&BoolComparison, function_call_expr.stable_ptr.untyped()));
             }
         }
     }
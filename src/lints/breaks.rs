@@ -1,45 +1,25 @@
-use cairo_lang_defs::ids::ModuleItemId;
-use cairo_lang_defs::plugin::PluginDiagnostic;
-use cairo_lang_diagnostics::Severity;
 use cairo_lang_semantic::db::SemanticGroup;
 use cairo_lang_semantic::{Arenas, StatementBreak};
 use cairo_lang_syntax::node::db::SyntaxGroup;
 use cairo_lang_syntax::node::{SyntaxNode, TypedStablePtr};
 use if_chain::if_chain;
+use indoc::indoc;
 
-use crate::context::{CairoLintKind, Lint};
-use crate::queries::{get_all_break_statements, get_all_function_bodies};
+use crate::context::{CairoLintKind, CheckContext, Lint, LintCategory, LintDocs};
+use crate::diagnostics::LintDiagnostic;
+use crate::fixes::Suggestion;
 
 pub struct BreakUnit;
 
-/// ## What it does
-///
-/// Just a simple test :P.
-///
-/// ## Example
-///
-/// ```cairo
-/// fn main() {
-///     loop {
-///         break ();
-///     }
-/// }
-/// ```
-///
-/// Can be fixed by removing the parentheses:
-///
-/// ```cairo
-/// fn main() {
-///     loop {
-///         break;
-///     }
-/// }
-/// ```
 impl Lint for BreakUnit {
     fn allowed_name(&self) -> &'static str {
         "break_unit"
     }
 
+    fn id(&self) -> &'static str {
+        "CL0009"
+    }
+
     fn diagnostic_message(&self) -> &'static str {
         "unnecessary double parentheses found after break. Consider removing them."
     }
@@ -48,45 +28,58 @@ impl Lint for BreakUnit {
         CairoLintKind::BreakUnit
     }
 
+    fn category(&self) -> LintCategory {
+        LintCategory::Style
+    }
+
+    fn docs(&self) -> LintDocs {
+        LintDocs {
+            description: "Checks for `break ();` statements, where the unit value can be omitted.",
+            bad_example: Some(indoc! {r#"
+                fn main() {
+                    loop {
+                        break ();
+                    }
+                }
+            "#}),
+            good_example: Some(indoc! {r#"
+                fn main() {
+                    loop {
+                        break;
+                    }
+                }
+            "#}),
+            ..Default::default()
+        }
+    }
+
     fn has_fixer(&self) -> bool {
         true
     }
 
-    fn fix(&self, db: &dyn SemanticGroup, node: SyntaxNode) -> Option<(SyntaxNode, String)> {
-        fix_break_unit(db.upcast(), node)
+    fn fix(&self, db: &dyn SemanticGroup, node: SyntaxNode) -> Option<Suggestion> {
+        let (node, replacement) = fix_break_unit(db.upcast(), node)?;
+        Some(Suggestion::replace_node(
+            db.upcast(),
+            "Remove the `()` after `break`",
+            node,
+            replacement,
+        ))
     }
 }
 
 pub fn check_break(
-    db: &dyn SemanticGroup,
-    item: &ModuleItemId,
-    diagnostics: &mut Vec<PluginDiagnostic>,
-) {
-    let function_bodies = get_all_function_bodies(db, item);
-    for function_body in function_bodies.iter() {
-        let break_exprs = get_all_break_statements(function_body);
-        for break_expr in break_exprs.iter() {
-            check_single_break(db, break_expr, &function_body.arenas, diagnostics)
-        }
-    }
-}
-
-fn check_single_break(
     db: &dyn SemanticGroup,
     break_expr: &StatementBreak,
     arenas: &Arenas,
-    diagnostics: &mut Vec<PluginDiagnostic>,
+    _context: &CheckContext,
+    diagnostics: &mut Vec<LintDiagnostic>,
 ) {
     if_chain! {
         if let Some(expr) = break_expr.expr_option;
         if arenas.exprs[expr].ty().is_unit(db);
         then {
-            diagnostics.push(PluginDiagnostic {
-                stable_ptr: break_expr.stable_ptr.untyped(),
-                message: BreakUnit.diagnostic_message().to_string(),
-                severity: Severity::Warning,
-                relative_span: None
-            });
+            diagnostics.push(LintDiagnostic::new(&BreakUnit, break_expr.stable_ptr.untyped()));
         }
     }
 }
@@ -0,0 +1,234 @@
+use anyhow::{anyhow, ensure, Result};
+use cairo_lang_defs::ids::{LanguageElementId, ModuleItemId};
+use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_semantic::{Arenas, ExprFunctionCall};
+use cairo_lang_syntax::node::TypedStablePtr;
+use std::collections::HashSet;
+use std::sync::{Arc, LazyLock, Mutex};
+
+use crate::config::{CallPatternConfig, LintLevel};
+use crate::context::{CairoLintKind, CheckContext, Lint, LintCategory, LintDocs};
+use crate::diagnostics::LintDiagnostic;
+
+/// Lint rule created from the [`CallPatternConfig`].
+///
+/// The name and the message of the rule are interned to get the `'static` lifetime required by the
+/// [`Lint`] trait, so recreating the rules from the same configuration does not allocate them again.
+#[derive(Debug)]
+pub struct CallPatternRule {
+    name: &'static str,
+    message: &'static str,
+    function: String,
+    level: LintLevel,
+    category: LintCategory,
+    modules: Vec<String>,
+    excluded_modules: Vec<String>,
+}
+
+impl CallPatternRule {
+    /// Creates the rule from the configuration. Fails if the configuration is invalid.
+    pub fn new(config: CallPatternConfig) -> Result<Self> {
+        ensure!(
+            is_valid_rule_name(&config.name),
+            "Invalid name of the call pattern rule '{}'. Expected a snake_case identifier.",
+            config.name
+        );
+        ensure!(
+            !config.function.is_empty(),
+            "The function path of the call pattern rule '{}' is empty.",
+            config.name
+        );
+        ensure!(
+            !config.message.is_empty(),
+            "The message of the call pattern rule '{}' is empty.",
+            config.name
+        );
+        let level = config
+            .level
+            .map_or(Ok(None), |level| level.level())
+            .map_err(|err| {
+                anyhow!(
+                    "Invalid level of the call pattern rule '{}': {}",
+                    config.name,
+                    err
+                )
+            })?
+            .unwrap_or(LintLevel::Warn);
+        let category = match &config.category {
+            Some(category) => LintCategory::from_name(category).ok_or_else(|| {
+                anyhow!(
+                    "Unknown category '{}' of the call pattern rule '{}'.",
+                    category,
+                    config.name
+                )
+            })?,
+            None => LintCategory::Correctness,
+        };
+
+        Ok(Self {
+            name: intern(config.name),
+            message: intern(config.message),
+            function: config.function,
+            level,
+            category,
+            modules: config.modules,
+            excluded_modules: config.excluded_modules,
+        })
+    }
+
+    /// Checks if the rule matches the full path of the called function.
+    pub fn matches_function(&self, function_path: &str) -> bool {
+        let function_path = strip_generic_args(function_path);
+        match self.function.strip_suffix("::*") {
+            Some(parent_path) => {
+                function_path
+                    .rsplit_once("::")
+                    .is_some_and(|(function_parent_path, _)| {
+                        is_path_inside(function_parent_path, parent_path)
+                    })
+            }
+            None => function_path == self.function,
+        }
+    }
+
+    /// Checks if the rule is checked inside the module with the given full path.
+    pub fn applies_to_module(&self, module_path: &str) -> bool {
+        (self.modules.is_empty()
+            || self
+                .modules
+                .iter()
+                .any(|module| is_path_inside(module_path, module)))
+            && !self
+                .excluded_modules
+                .iter()
+                .any(|module| is_path_inside(module_path, module))
+    }
+}
+
+impl Lint for CallPatternRule {
+    fn allowed_name(&self) -> &'static str {
+        self.name
+    }
+
+    fn id(&self) -> &'static str {
+        self.name
+    }
+
+    fn diagnostic_message(&self) -> &'static str {
+        self.message
+    }
+
+    fn kind(&self) -> CairoLintKind {
+        CairoLintKind::CallPattern
+    }
+
+    fn category(&self) -> LintCategory {
+        self.category
+    }
+
+    fn docs(&self) -> LintDocs {
+        LintDocs {
+            description: self.message,
+            ..Default::default()
+        }
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.level != LintLevel::Allow
+    }
+
+    fn default_level(&self) -> LintLevel {
+        self.level
+    }
+}
+
+/// Selects the call pattern rules which are checked inside the module of the item.
+pub fn call_pattern_rules_for_item(
+    db: &dyn SemanticGroup,
+    item: &ModuleItemId,
+    rules: &[Arc<CallPatternRule>],
+) -> Vec<Arc<CallPatternRule>> {
+    if rules.is_empty() {
+        return Vec::new();
+    }
+    let module_path = item.parent_module(db.upcast()).full_path(db.upcast());
+    rules
+        .iter()
+        .filter(|rule| rule.applies_to_module(&module_path))
+        .cloned()
+        .collect()
+}
+
+/// Checks if the call matches any of the call pattern rules of the context,
+/// see [`CheckContext::call_pattern_rules`].
+pub fn check_call_patterns(
+    db: &dyn SemanticGroup,
+    function_call_expr: &ExprFunctionCall,
+    _arenas: &Arenas,
+    context: &CheckContext,
+    diagnostics: &mut Vec<LintDiagnostic>,
+) {
+    let function_path = function_call_expr.function.full_path(db);
+    for rule in context
+        .call_pattern_rules()
+        .iter()
+        .filter(|rule| rule.matches_function(&function_path))
+    {
+        diagnostics.push(LintDiagnostic::new(
+            &**rule,
+            function_call_expr.stable_ptr.untyped(),
+        ));
+    }
+}
+
+/// Checks if the path is equal to the parent path or starts with it, e.g. `a::b::c` is inside `a::b`.
+fn is_path_inside(path: &str, parent_path: &str) -> bool {
+    path.strip_prefix(parent_path)
+        .is_some_and(|rest| rest.is_empty() || rest.starts_with("::"))
+}
+
+/// Removes the generic arguments from the path, e.g. `core::array::ArrayImpl::<felt252>::append`
+/// becomes `core::array::ArrayImpl::append`.
+fn strip_generic_args(path: &str) -> String {
+    let mut result = String::with_capacity(path.len());
+    let mut depth = 0_usize;
+    for c in path.chars() {
+        match c {
+            '<' => {
+                depth += 1;
+                // Skip the `::` preceding the generic arguments.
+                if depth == 1 && result.ends_with("::") {
+                    result.truncate(result.len() - 2);
+                }
+            }
+            '>' => depth = depth.saturating_sub(1),
+            _ if depth == 0 => result.push(c),
+            _ => {}
+        }
+    }
+    result
+}
+
+fn is_valid_rule_name(name: &str) -> bool {
+    name.starts_with(|c: char| c.is_ascii_lowercase() || c == '_')
+        && name
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+}
+
+/// The strings of all of the call pattern rules created so far.
+static INTERNED_STRINGS: LazyLock<Mutex<HashSet<&'static str>>> = LazyLock::new(Default::default);
+
+/// Gets the `'static` copy of the string. Each distinct string is allocated only once,
+/// so the memory used does not grow when the configuration is reloaded.
+fn intern(value: String) -> &'static str {
+    let mut interned_strings = INTERNED_STRINGS
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    if let Some(interned) = interned_strings.get(value.as_str()) {
+        return interned;
+    }
+    let interned: &'static str = Box::leak(value.into_boxed_str());
+    interned_strings.insert(interned);
+    interned
+}
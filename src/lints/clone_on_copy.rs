@@ -1,68 +1,98 @@
-use crate::context::{CairoLintKind, Lint};
-use crate::queries::{get_all_function_bodies, get_all_function_calls};
-use cairo_lang_defs::ids::ModuleItemId;
-use cairo_lang_defs::plugin::PluginDiagnostic;
-use cairo_lang_diagnostics::Severity;
+use crate::context::{CairoLintKind, CheckContext, Lint, LintCategory, LintDocs};
+use crate::diagnostics::LintDiagnostic;
 use cairo_lang_semantic::db::SemanticGroup;
-use cairo_lang_semantic::ExprFunctionCall;
-use cairo_lang_syntax::node::TypedStablePtr;
+use cairo_lang_semantic::{Arenas, Expr, ExprFunctionCall, ExprFunctionCallArg};
+use cairo_lang_syntax::node::{TypedStablePtr, TypedSyntaxNode};
+use indoc::indoc;
 use itertools::Itertools;
 
 const T_COPY_CLONE: &str = "core::clone::TCopyClone";
 
 pub struct CloneOnCopy;
 
-/// ## What it does
-///
-/// Checks for usage of `.clone()` on a `Copy` type.
-///
-/// ## Example
-///
-/// ```cairo
-///     let a: felt252 = 'Hello';
-///     let b = a.clone()
-/// ```
 impl Lint for CloneOnCopy {
     fn allowed_name(&self) -> &'static str {
         "clone_on_copy"
     }
 
+    fn id(&self) -> &'static str {
+        "CL0043"
+    }
+
     fn diagnostic_message(&self) -> &'static str {
         "using `clone` on type which implements `Copy` trait"
     }
 
+    fn message_template(&self) -> &'static str {
+        "using `clone` on type `{type}` which implements `Copy` trait; use `{replacement}` instead"
+    }
+
     fn kind(&self) -> CairoLintKind {
         CairoLintKind::CloneOnCopy
     }
-}
 
-pub fn check_clone_on_copy(
-    db: &dyn SemanticGroup,
-    item: &ModuleItemId,
-    diagnostics: &mut Vec<PluginDiagnostic>,
-) {
-    let function_bodies = get_all_function_bodies(db, item);
-    for function_body in function_bodies.iter() {
-        let function_call_exprs = get_all_function_calls(function_body);
-        for function_call_expr in function_call_exprs {
-            check_clone_usage(db, &function_call_expr, diagnostics);
+    fn category(&self) -> LintCategory {
+        LintCategory::Complexity
+    }
+
+    fn docs(&self) -> LintDocs {
+        LintDocs {
+            description: "Checks for usage of `.clone()` on a `Copy` type.",
+            bad_example: Some(indoc! {r#"
+                    let a: felt252 = 'Hello';
+                    let b = a.clone()
+            "#}),
+            ..Default::default()
         }
     }
 }
 
-fn check_clone_usage(
+pub fn check_clone_on_copy(
     db: &dyn SemanticGroup,
     expr: &ExprFunctionCall,
-    diagnostics: &mut Vec<PluginDiagnostic>,
+    arenas: &Arenas,
+    _context: &CheckContext,
+    diagnostics: &mut Vec<LintDiagnostic>,
 ) {
     let function_name = expr.function.full_path(db).split("::").take(3).join("::");
 
     if function_name == T_COPY_CLONE {
-        diagnostics.push(PluginDiagnostic {
-            stable_ptr: expr.stable_ptr.untyped(),
-            message: CloneOnCopy.diagnostic_message().to_string(),
-            severity: Severity::Warning,
-            relative_span: None,
-        });
+        let ty = expr.ty.format(db);
+        let replacement = get_clone_replacement(db, expr, arenas).unwrap_or_default();
+        diagnostics.push(LintDiagnostic::with_args(
+            &CloneOnCopy,
+            expr.stable_ptr.untyped(),
+            &[("type", ty.as_str()), ("replacement", replacement.as_str())],
+        ));
     }
 }
+
+/// Returns the expression which can be used instead of the clone, e.g. `a` for `a.clone()`
+/// or `*a` for `a.clone()` where `a` is a snapshot.
+fn get_clone_replacement(
+    db: &dyn SemanticGroup,
+    expr: &ExprFunctionCall,
+    arenas: &Arenas,
+) -> Option<String> {
+    let ExprFunctionCallArg::Value(arg) = expr.args.first()? else {
+        return None;
+    };
+    // The snapshot is either taken explicitly, as in `Clone::clone(@a)`, or added implicitly
+    // by the method call on a value which is not a snapshot.
+    let (receiver, is_snapshot) = match &arenas.exprs[*arg] {
+        Expr::Snapshot(snapshot) => (&arenas.exprs[snapshot.inner], false),
+        receiver => (receiver, true),
+    };
+    let receiver_text = receiver
+        .stable_ptr()
+        .lookup(db.upcast())
+        .as_syntax_node()
+        .get_text_without_trivia(db.upcast())
+        .split_whitespace()
+        .join(" ");
+    Some(if is_snapshot {
+        format!("*{receiver_text}")
+    } else {
+        receiver_text
+    })
+}
@@ -1,8 +1,6 @@
+use indoc::indoc;
 use std::collections::HashSet;
 
-use cairo_lang_defs::ids::ModuleItemId;
-use cairo_lang_defs::plugin::PluginDiagnostic;
-use cairo_lang_diagnostics::Severity;
 use cairo_lang_semantic::db::SemanticGroup;
 use cairo_lang_semantic::{
     Arenas, Expr, ExprFunctionCall, ExprFunctionCallArg, ExprLogicalOperator, LogicalOperator,
@@ -12,33 +10,22 @@ use cairo_lang_syntax::node::db::SyntaxGroup;
 use cairo_lang_syntax::node::{SyntaxNode, TypedStablePtr, TypedSyntaxNode};
 
 use super::function_trait_name_from_fn_id;
-use crate::context::{CairoLintKind, Lint};
+use crate::context::{CairoLintKind, CheckContext, Lint, LintCategory, LintDocs};
+use crate::diagnostics::LintDiagnostic;
+use crate::fixes::{Applicability, Suggestion};
 use crate::lints::{EQ, GE, GT, LE, LT};
-use crate::queries::{get_all_function_bodies, get_all_logical_operator_expressions};
 
 pub struct ImpossibleComparison;
 
-/// ## What it does
-///
-/// Checks for impossible comparisons. Those ones always return false.
-///
-/// ## Example
-///
-/// Here is an example of impossible comparison:
-///
-/// ```cairo
-/// fn main() {
-///     let x: u32 = 1;
-///     if x > 200 && x < 100 {
-///         //impossible to reach
-///     }
-/// }
-/// ```
 impl Lint for ImpossibleComparison {
     fn allowed_name(&self) -> &'static str {
         "impossible_comparison"
     }
 
+    fn id(&self) -> &'static str {
+        "CL0004"
+    }
+
     fn diagnostic_message(&self) -> &'static str {
         "Impossible condition, always false"
     }
@@ -46,47 +33,38 @@ impl Lint for ImpossibleComparison {
     fn kind(&self) -> CairoLintKind {
         CairoLintKind::ImpossibleComparison
     }
+
+    fn category(&self) -> LintCategory {
+        LintCategory::Correctness
+    }
+
+    fn docs(&self) -> LintDocs {
+        LintDocs {
+            description: "Checks for impossible comparisons. Those ones always return false.",
+            bad_example: Some(indoc! {r#"
+                fn main() {
+                    let x: u32 = 1;
+                    if x > 200 && x < 100 {
+                        //impossible to reach
+                    }
+                }
+            "#}),
+            ..Default::default()
+        }
+    }
 }
 
 pub struct SimplifiableComparison;
 
-/// ## What it does
-///
-/// Checks for double comparisons that can be simplified.
-/// Those are comparisons that can be simplified to a single comparison.
-///
-/// ## Example
-///
-/// ```cairo
-/// fn main() -> bool {
-///     let x = 5_u32;
-///     let y = 10_u32;
-///     if x == y || x > y {
-///         true
-///     } else {
-///         false
-///     }
-/// }
-/// ```
-///
-/// The above code can be simplified to:
-///
-/// ```cairo
-/// fn main() -> bool {
-///     let x = 5_u32;
-///     let y = 10_u32;
-///     if x >= y {
-///         true
-///     } else {
-///         false
-///     }
-/// }
-/// ```
 impl Lint for SimplifiableComparison {
     fn allowed_name(&self) -> &'static str {
         "simplifiable_comparison"
     }
 
+    fn id(&self) -> &'static str {
+        "CL0005"
+    }
+
     fn diagnostic_message(&self) -> &'static str {
         "This double comparison can be simplified."
     }
@@ -95,49 +73,65 @@ impl Lint for SimplifiableComparison {
         CairoLintKind::DoubleComparison
     }
 
+    fn category(&self) -> LintCategory {
+        LintCategory::Complexity
+    }
+
+    fn docs(&self) -> LintDocs {
+        LintDocs {
+            description: "Checks for double comparisons that can be simplified. Those are comparisons that can be simplified to a single comparison.",
+            bad_example: Some(indoc! {r#"
+                fn main() -> bool {
+                    let x = 5_u32;
+                    let y = 10_u32;
+                    if x == y || x > y {
+                        true
+                    } else {
+                        false
+                    }
+                }
+            "#}),
+            good_example: Some(indoc! {r#"
+                fn main() -> bool {
+                    let x = 5_u32;
+                    let y = 10_u32;
+                    if x >= y {
+                        true
+                    } else {
+                        false
+                    }
+                }
+            "#}),
+            ..Default::default()
+        }
+    }
+
     fn has_fixer(&self) -> bool {
         true
     }
 
-    fn fix(&self, db: &dyn SemanticGroup, node: SyntaxNode) -> Option<(SyntaxNode, String)> {
-        fix_double_comparison(db.upcast(), node)
+    fn fix(&self, db: &dyn SemanticGroup, node: SyntaxNode) -> Option<Suggestion> {
+        let (node, replacement) = fix_double_comparison(db.upcast(), node)?;
+        Some(Suggestion::replace_node(
+            db.upcast(),
+            "Simplify the double comparison",
+            node,
+            replacement,
+        ))
     }
 }
 
 pub struct RedundantComparison;
 
-/// ## What it does
-///
-/// Checks for double comparisons that are redundant. Those are comparisons that can be simplified to a single comparison.
-///
-/// ## Example
-///
-/// ```cairo
-/// fn main() -> bool {
-///     let x = 5_u32;
-///     let y = 10_u32;
-///     if x >= y || x <= y {
-///         true
-///     } else {
-///         false
-///     }
-/// }
-/// ```
-///
-/// Could be simplified to just:
-///
-/// ```cairo
-/// fn main() -> bool {
-///     let x = 5_u32;
-///     let y = 10_u32;
-///     true
-/// }
-/// ```
 impl Lint for RedundantComparison {
     fn allowed_name(&self) -> &'static str {
         "redundant_comparison"
     }
 
+    fn id(&self) -> &'static str {
+        "CL0006"
+    }
+
     fn diagnostic_message(&self) -> &'static str {
         "Redundant double comparison found. Consider simplifying to a single comparison."
     }
@@ -146,49 +140,61 @@ impl Lint for RedundantComparison {
         CairoLintKind::DoubleComparison
     }
 
+    fn category(&self) -> LintCategory {
+        LintCategory::Complexity
+    }
+
+    fn docs(&self) -> LintDocs {
+        LintDocs {
+            description: "Checks for double comparisons that are redundant. Those are comparisons that can be simplified to a single comparison.",
+            bad_example: Some(indoc! {r#"
+                fn main() -> bool {
+                    let x = 5_u32;
+                    let y = 10_u32;
+                    if x >= y || x <= y {
+                        true
+                    } else {
+                        false
+                    }
+                }
+            "#}),
+            good_example: Some(indoc! {r#"
+                fn main() -> bool {
+                    let x = 5_u32;
+                    let y = 10_u32;
+                    true
+                }
+            "#}),
+            ..Default::default()
+        }
+    }
+
     fn has_fixer(&self) -> bool {
         true
     }
 
-    fn fix(&self, db: &dyn SemanticGroup, node: SyntaxNode) -> Option<(SyntaxNode, String)> {
-        fix_double_comparison(db.upcast(), node)
+    fn fix(&self, db: &dyn SemanticGroup, node: SyntaxNode) -> Option<Suggestion> {
+        let (node, replacement) = fix_double_comparison(db.upcast(), node)?;
+        Some(Suggestion::replace_node(
+            db.upcast(),
+            "Simplify the double comparison",
+            node,
+            replacement,
+        ))
     }
 }
 
 pub struct ContradictoryComparison;
 
-/// ## What it does
-///
-/// Checks for double comparisons that are contradictory. Those are comparisons that are always false.
-///
-/// ## Example
-///
-/// ```cairo
-/// fn main() -> bool {
-///     let x = 5_u32;
-///     let y = 10_u32;
-///     if x < y && x > y {
-///         true
-///     } else {
-///         false
-///     }
-/// }
-/// ```
-///
-/// Could be simplified to just:
-///
-/// ```cairo
-/// fn main() -> bool {
-///     let x = 5_u32;
-///     let y = 10_u32;
-///     false
-/// }
-/// ```
 impl Lint for ContradictoryComparison {
     fn allowed_name(&self) -> &'static str {
         "contradictory_comparison"
     }
 
+    fn id(&self) -> &'static str {
+        "CL0007"
+    }
+
     fn diagnostic_message(&self) -> &'static str {
         "This double comparison is contradictory and always false."
     }
@@ -197,35 +203,59 @@ impl Lint for ContradictoryComparison {
         CairoLintKind::DoubleComparison
     }
 
+    fn category(&self) -> LintCategory {
+        LintCategory::Correctness
+    }
+
+    fn docs(&self) -> LintDocs {
+        LintDocs {
+            description: "Checks for double comparisons that are contradictory. Those are comparisons that are always false.",
+            bad_example: Some(indoc! {r#"
+                fn main() -> bool {
+                    let x = 5_u32;
+                    let y = 10_u32;
+                    if x < y && x > y {
+                        true
+                    } else {
+                        false
+                    }
+                }
+            "#}),
+            good_example: Some(indoc! {r#"
+                fn main() -> bool {
+                    let x = 5_u32;
+                    let y = 10_u32;
+                    false
+                }
+            "#}),
+            ..Default::default()
+        }
+    }
+
     fn has_fixer(&self) -> bool {
         true
     }
 
-    fn fix(&self, db: &dyn SemanticGroup, node: SyntaxNode) -> Option<(SyntaxNode, String)> {
-        fix_double_comparison(db.upcast(), node)
+    fn fix(&self, db: &dyn SemanticGroup, node: SyntaxNode) -> Option<Suggestion> {
+        let (node, replacement) = fix_double_comparison(db.upcast(), node)?;
+        Some(
+            Suggestion::replace_node(
+                db.upcast(),
+                "Simplify the double comparison",
+                node,
+                replacement,
+            )
+            .with_applicability(Applicability::MaybeIncorrect),
+        )
     }
 }
 
 pub fn check_double_comparison(
-    db: &dyn SemanticGroup,
-    item: &ModuleItemId,
-    diagnostics: &mut Vec<PluginDiagnostic>,
-) {
-    let function_bodies = get_all_function_bodies(db, item);
-    for function_body in function_bodies.iter() {
-        let logical_operator_exprs = get_all_logical_operator_expressions(function_body);
-        let arenas = &function_body.arenas;
-        for logical_operator_expr in logical_operator_exprs.iter() {
-            check_single_double_comparison(db, logical_operator_expr, arenas, diagnostics);
-        }
-    }
-}
-
-fn check_single_double_comparison(
     db: &dyn SemanticGroup,
     logical_operator_exprs: &ExprLogicalOperator,
     arenas: &Arenas,
-    diagnostics: &mut Vec<PluginDiagnostic>,
+    _context: &CheckContext,
+    diagnostics: &mut Vec<LintDiagnostic>,
 ) {
     let Expr::FunctionCall(lhs_comparison) = &arenas.exprs[logical_operator_exprs.lhs] else {
         return;
@@ -258,12 +288,10 @@ fn check_single_double_comparison(
         db,
         arenas,
     ) {
-        diagnostics.push(PluginDiagnostic {
-            message: ImpossibleComparison.diagnostic_message().to_string(),
-            stable_ptr: logical_operator_exprs.stable_ptr.untyped(),
-            severity: Severity::Error,
-            relative_span: None,
-        })
+        diagnostics.push(LintDiagnostic::new(
+            &ImpossibleComparison,
+            logical_operator_exprs.stable_ptr.untyped(),
+        ))
     }
 
     // The comparison functions don't work with refs so should only be value
@@ -333,34 +361,28 @@ fn check_single_double_comparison(
         &rhs_fn_trait_name,
         &logical_operator_exprs.op,
     ) {
-        diagnostics.push(PluginDiagnostic {
-            message: SimplifiableComparison.diagnostic_message().to_string(),
-            stable_ptr: logical_operator_exprs.stable_ptr.untyped(),
-            severity: Severity::Warning,
-            relative_span: None,
-        });
+        diagnostics.push(LintDiagnostic::new(
+            &SimplifiableComparison,
+            logical_operator_exprs.stable_ptr.untyped(),
+        ));
     } else if is_redundant_double_comparison(
         &lhs_fn_trait_name,
         &rhs_fn_trait_name,
         &logical_operator_exprs.op,
     ) {
-        diagnostics.push(PluginDiagnostic {
-            message: RedundantComparison.diagnostic_message().to_string(),
-            stable_ptr: logical_operator_exprs.stable_ptr.untyped(),
-            severity: Severity::Warning,
-            relative_span: None,
-        });
+        diagnostics.push(LintDiagnostic::new(
+            &RedundantComparison,
+            logical_operator_exprs.stable_ptr.untyped(),
+        ));
     } else if is_contradictory_double_comparison(
         &lhs_fn_trait_name,
         &rhs_fn_trait_name,
         &logical_operator_exprs.op,
     ) {
-        diagnostics.push(PluginDiagnostic {
-            message: ContradictoryComparison.diagnostic_message().to_string(),
-            stable_ptr: logical_operator_exprs.stable_ptr.untyped(),
-            severity: Severity::Error,
-            relative_span: None,
-        });
+        diagnostics.push(LintDiagnostic::new(
+            &ContradictoryComparison,
+            logical_operator_exprs.stable_ptr.untyped(),
+        ));
     }
 }
 
@@ -1,41 +1,27 @@
 use cairo_lang_defs::ids::ModuleItemId;
-use cairo_lang_defs::plugin::PluginDiagnostic;
-use cairo_lang_diagnostics::Severity;
 use cairo_lang_semantic::db::SemanticGroup;
 use cairo_lang_syntax::node::ast::Expr;
 use cairo_lang_syntax::node::db::SyntaxGroup;
 use cairo_lang_syntax::node::{SyntaxNode, TypedStablePtr, TypedSyntaxNode};
+use indoc::indoc;
 
-use crate::context::{CairoLintKind, Lint};
+use crate::context::{CairoLintKind, CheckContext, Lint, LintCategory, LintDocs};
+use crate::diagnostics::LintDiagnostic;
+use crate::fixes::Suggestion;
 use crate::helper::indent_snippet;
 use crate::queries::get_all_parenthesized_expressions;
 
 pub struct DoubleParens;
 
-/// ## What it does
-///
-/// Checks for unnecessary double parentheses in expressions.
-///
-/// ## Example
-///
-/// ```cairo
-/// fn main() -> u32 {
-///     ((0))
-/// }
-/// ```
-///
-/// Can be simplified to:
-///
-/// ```cairo
-/// fn main() -> u32 {
-///     0
-/// }
-/// ```
 impl Lint for DoubleParens {
     fn allowed_name(&self) -> &'static str {
         "double_parens"
     }
 
+    fn id(&self) -> &'static str {
+        "CL0003"
+    }
+
     fn diagnostic_message(&self) -> &'static str {
         "unnecessary double parentheses found. Consider removing them."
     }
@@ -44,19 +30,47 @@ impl Lint for DoubleParens {
         CairoLintKind::DoubleParens
     }
 
+    fn category(&self) -> LintCategory {
+        LintCategory::Style
+    }
+
+    fn docs(&self) -> LintDocs {
+        LintDocs {
+            description: "Checks for unnecessary double parentheses in expressions.",
+            bad_example: Some(indoc! {r#"
+                fn main() -> u32 {
+                    ((0))
+                }
+            "#}),
+            good_example: Some(indoc! {r#"
+                fn main() -> u32 {
+                    0
+                }
+            "#}),
+            ..Default::default()
+        }
+    }
+
     fn has_fixer(&self) -> bool {
         true
     }
 
-    fn fix(&self, db: &dyn SemanticGroup, node: SyntaxNode) -> Option<(SyntaxNode, String)> {
-        fix_double_parens(db.upcast(), node)
+    fn fix(&self, db: &dyn SemanticGroup, node: SyntaxNode) -> Option<Suggestion> {
+        let (node, replacement) = fix_double_parens(db.upcast(), node)?;
+        Some(Suggestion::replace_node(
+            db.upcast(),
+            "Remove the double parentheses",
+            node,
+            replacement,
+        ))
     }
 }
 
 pub fn check_double_parens(
     db: &dyn SemanticGroup,
     item: &ModuleItemId,
-    diagnostics: &mut Vec<PluginDiagnostic>,
+    _context: &CheckContext,
+    diagnostics: &mut Vec<LintDiagnostic>,
 ) {
     let parenthesized_exprs = get_all_parenthesized_expressions(db, item);
     for parens_expr in parenthesized_exprs.iter() {
@@ -67,7 +81,7 @@ pub fn check_double_parens(
 fn check_single_double_parens(
     db: &dyn SemanticGroup,
     parens_expr: &Expr,
-    diagnostics: &mut Vec<PluginDiagnostic>,
+    diagnostics: &mut Vec<LintDiagnostic>,
 ) {
     let is_double_parens = if let Expr::Parenthesized(parenthesized_expr) = parens_expr {
         matches!(
@@ -79,12 +93,10 @@ fn check_single_double_parens(
     };
 
     if is_double_parens {
-        diagnostics.push(PluginDiagnostic {
-            stable_ptr: parens_expr.stable_ptr(db.upcast()).untyped(),
-            message: DoubleParens.diagnostic_message().to_string(),
-            severity: Severity::Warning,
-            relative_span: None,
-        });
+        diagnostics.push(LintDiagnostic::new(
+            &DoubleParens,
+            parens_expr.stable_ptr(db.upcast()).untyped(),
+        ));
     }
 }
 
@@ -1,30 +1,24 @@
+use indoc::indoc;
 use std::collections::HashSet;
 
-use cairo_lang_defs::{ids::ModuleItemId, plugin::PluginDiagnostic};
-use cairo_lang_diagnostics::Severity;
+use cairo_lang_defs::ids::ModuleItemId;
 use cairo_lang_semantic::db::SemanticGroup;
 
-use crate::context::{CairoLintKind, Lint};
+use crate::context::{CairoLintKind, CheckContext, Lint, LintCategory, LintDocs};
+use crate::diagnostics::LintDiagnostic;
 use crate::queries::get_all_checkable_functions;
 
 pub struct DuplicateUnderscoreArgs;
 
-/// ## What it does
-///
-/// Checks for functions that have the same argument name but prefix with `_`.
-///
-/// ## Example
-///
-/// This code will raise a warning because it can be difficult to differentiate between `test` and `_test`.
-///
-/// ```cairo
-/// fn foo(test: u32, _test: u32) {}
-/// ```
 impl Lint for DuplicateUnderscoreArgs {
     fn allowed_name(&self) -> &'static str {
         "duplicate_underscore_args"
     }
 
+    fn id(&self) -> &'static str {
+        "CL0013"
+    }
+
     fn diagnostic_message(&self) -> &'static str {
         "duplicate arguments, having another argument having almost the same name \
                                              makes code comprehension and documentation more difficult"
@@ -33,12 +27,28 @@ impl Lint for DuplicateUnderscoreArgs {
     fn kind(&self) -> CairoLintKind {
         CairoLintKind::DuplicateUnderscoreArgs
     }
+
+    fn category(&self) -> LintCategory {
+        LintCategory::Style
+    }
+
+    fn docs(&self) -> LintDocs {
+        LintDocs {
+            description: "Checks for functions that have the same argument name but prefix with `_`.",
+            rationale: Some("It can be difficult to differentiate between the arguments like `test` and `_test`."),
+            bad_example: Some(indoc! {r#"
+                fn foo(test: u32, _test: u32) {}
+            "#}),
+            ..Default::default()
+        }
+    }
 }
 
 pub fn check_duplicate_underscore_args(
     db: &dyn SemanticGroup,
     item: &ModuleItemId,
-    diagnostics: &mut Vec<PluginDiagnostic>,
+    _context: &CheckContext,
+    diagnostics: &mut Vec<LintDiagnostic>,
 ) {
     let functions = get_all_checkable_functions(db, item);
 
@@ -51,12 +61,10 @@ pub fn check_duplicate_underscore_args(
             let stripped_name = param_name.strip_prefix('_').unwrap_or(&param_name);
 
             if !registered_names.insert(stripped_name.to_string()) {
-                diagnostics.push(PluginDiagnostic {
-                    stable_ptr: param.stable_ptr.0,
-                    message: DuplicateUnderscoreArgs.diagnostic_message().to_string(),
-                    severity: Severity::Warning,
-                    relative_span: None,
-                });
+                diagnostics.push(LintDiagnostic::new(
+                    &DuplicateUnderscoreArgs,
+                    param.stable_ptr.0,
+                ));
             }
         }
     }
@@ -1,42 +1,27 @@
-use cairo_lang_defs::{ids::ModuleItemId, plugin::PluginDiagnostic};
-use cairo_lang_diagnostics::Severity;
+use cairo_lang_defs::ids::ModuleItemId;
 use cairo_lang_semantic::db::SemanticGroup;
 use cairo_lang_syntax::node::{
     ast::{self, OptionTypeClause},
     db::SyntaxGroup,
     SyntaxNode, TypedStablePtr, TypedSyntaxNode,
 };
+use indoc::indoc;
 
-use crate::context::{CairoLintKind, Lint};
+use crate::context::{CairoLintKind, CheckContext, Lint, LintCategory, LintDocs};
+use crate::diagnostics::LintDiagnostic;
+use crate::fixes::{Applicability, Suggestion};
 
 pub struct EmptyEnumBracketsVariant;
 
-/// ## What it does
-///
-/// Finds enum variants that are declared with empty brackets.
-///
-/// ## Example
-///
-/// ```cairo
-///  enum MyEnum {
-///     Data: u8,
-///     Empty: ()       // redundant parentheses
-///  }
-/// ```
-///
-/// Can be simplified to:
-///
-/// ```cairo
-///  enum MyEnum {
-///     Data(u8),
-///     Empty,
-///  }
-/// ```
 impl Lint for EmptyEnumBracketsVariant {
     fn allowed_name(&self) -> &'static str {
         "empty_enum_brackets_variant"
     }
 
+    fn id(&self) -> &'static str {
+        "CL0044"
+    }
+
     fn diagnostic_message(&self) -> &'static str {
         "redundant parentheses in enum variant definition"
     }
@@ -45,19 +30,52 @@ impl Lint for EmptyEnumBracketsVariant {
         CairoLintKind::EnumEmptyVariantBrackets
     }
 
+    fn category(&self) -> LintCategory {
+        LintCategory::Style
+    }
+
+    fn docs(&self) -> LintDocs {
+        LintDocs {
+            description: "Finds enum variants that are declared with empty brackets.",
+            bad_example: Some(indoc! {r#"
+                 enum MyEnum {
+                    Data: u8,
+                    Empty: ()       // redundant parentheses
+                 }
+            "#}),
+            good_example: Some(indoc! {r#"
+                 enum MyEnum {
+                    Data(u8),
+                    Empty,
+                 }
+            "#}),
+            ..Default::default()
+        }
+    }
+
     fn has_fixer(&self) -> bool {
         true
     }
 
-    fn fix(&self, db: &dyn SemanticGroup, node: SyntaxNode) -> Option<(SyntaxNode, String)> {
-        fix_empty_enum_brackets_variant(db.upcast(), node)
+    fn fix(&self, db: &dyn SemanticGroup, node: SyntaxNode) -> Option<Suggestion> {
+        let (node, replacement) = fix_empty_enum_brackets_variant(db.upcast(), node)?;
+        Some(
+            Suggestion::replace_node(
+                db.upcast(),
+                "Remove the empty parentheses from the enum variant",
+                node,
+                replacement,
+            )
+            .with_applicability(Applicability::MaybeIncorrect),
+        )
     }
 }
 
 pub fn check_empty_enum_brackets_variant(
     db: &dyn SemanticGroup,
     item: &ModuleItemId,
-    diagnostics: &mut Vec<PluginDiagnostic>,
+    _context: &CheckContext,
+    diagnostics: &mut Vec<LintDiagnostic>,
 ) {
     let ModuleItemId::Enum(enum_id) = item else {
         return;
@@ -78,12 +96,10 @@ pub fn check_empty_enum_brackets_variant(
 
             // Determine if the variant includes a type clause, or if the type clause is empty
             if let OptionTypeClause::TypeClause(_) = ast_variant.type_clause(db.upcast()) {
-                diagnostics.push(PluginDiagnostic {
-                    stable_ptr: variant.stable_ptr(db.upcast()).untyped(),
-                    message: EmptyEnumBracketsVariant.diagnostic_message().to_string(),
-                    severity: Severity::Warning,
-                    relative_span: None,
-                });
+                diagnostics.push(LintDiagnostic::new(
+                    &EmptyEnumBracketsVariant,
+                    variant.stable_ptr(db.upcast()).untyped(),
+                ));
             }
         }
     }
@@ -1,43 +1,32 @@
-use crate::context::{CairoLintKind, Lint};
+use crate::config::EnumVariantNamesOptions;
+use crate::context::{CairoLintKind, CheckContext, Lint, LintCategory, LintDocs};
+use crate::diagnostics::{LintDiagnostic, LintDiagnosticDetails};
+use crate::fixes::{Applicability, Edit, Suggestion};
 use cairo_lang_defs::ids::{LanguageElementId, ModuleItemId};
-use cairo_lang_defs::plugin::PluginDiagnostic;
-use cairo_lang_diagnostics::Severity;
 use cairo_lang_semantic::db::SemanticGroup;
 use cairo_lang_syntax::node::db::SyntaxGroup;
 use cairo_lang_syntax::node::{
     ast::ItemEnum as AstEnumItem, SyntaxNode, Terminal, TypedSyntaxNode,
 };
+use indoc::indoc;
+use itertools::Itertools;
+
+/// Names of the fix arguments carrying the shared words, which are removed by the fix.
+/// The fix removes only the words reported by the diagnostic, so it follows the options of the lint.
+const PREFIX_FIX_ARG: &str = "prefix";
+const SUFFIX_FIX_ARG: &str = "suffix";
 
 pub struct EnumVariantNames;
 
-/// ## What it does
-///
-/// Detects enumeration variants that are prefixed or suffixed by the same characters.
-///
-/// ## Example
-///
-/// ```cairo
-/// enum Cake {
-///     BlackForestCake,
-///     HummingbirdCake,
-///     BattenbergCake,
-/// }
-/// ```
-///
-/// Can be simplified to:
-///
-/// ```cairo
-/// enum Cake {
-///     BlackForest,
-///     Hummingbird,
-///     Battenberg,
-/// }
-/// ```
 impl Lint for EnumVariantNames {
     fn allowed_name(&self) -> &'static str {
         "enum_variant_names"
     }
 
+    fn id(&self) -> &'static str {
+        "CL0042"
+    }
+
     fn diagnostic_message(&self) -> &'static str {
         "All enum variants are prefixed or suffixed by the same characters."
     }
@@ -46,6 +35,32 @@ impl Lint for EnumVariantNames {
         CairoLintKind::EnumVariantNames
     }
 
+    fn category(&self) -> LintCategory {
+        LintCategory::Pedantic
+    }
+
+    fn docs(&self) -> LintDocs {
+        LintDocs {
+            description:
+                "Detects enumeration variants that are prefixed or suffixed by the same characters.",
+            bad_example: Some(indoc! {r#"
+                enum Cake {
+                    BlackForestCake,
+                    HummingbirdCake,
+                    BattenbergCake,
+                }
+            "#}),
+            good_example: Some(indoc! {r#"
+                enum Cake {
+                    BlackForest,
+                    Hummingbird,
+                    Battenberg,
+                }
+            "#}),
+            ..Default::default()
+        }
+    }
+
     fn is_enabled(&self) -> bool {
         false
     }
@@ -54,15 +69,39 @@ impl Lint for EnumVariantNames {
         true
     }
 
-    fn fix(&self, db: &dyn SemanticGroup, node: SyntaxNode) -> Option<(SyntaxNode, String)> {
-        fix_enum_variant_names(db.upcast(), node)
+    fn fix(&self, db: &dyn SemanticGroup, node: SyntaxNode) -> Option<Suggestion> {
+        // Without the diagnostic the shared words are found again, with the default options.
+        let variant_names = AstEnumItem::from_syntax_node(db.upcast(), node.clone())
+            .variants(db.upcast())
+            .elements(db.upcast())
+            .iter()
+            .map(|variant| variant.name(db.upcast()).text(db.upcast()).to_string())
+            .collect_vec();
+        let (prefix, suffix) =
+            get_shared_words(&variant_names, &EnumVariantNamesOptions::default());
+        fix_enum_variant_names(db.upcast(), node, &prefix, &suffix)
+    }
+
+    fn fix_diagnostic(
+        &self,
+        db: &dyn SemanticGroup,
+        node: SyntaxNode,
+        details: &LintDiagnosticDetails,
+    ) -> Option<Suggestion> {
+        fix_enum_variant_names(
+            db.upcast(),
+            node,
+            details.fix_arg(PREFIX_FIX_ARG).unwrap_or_default(),
+            details.fix_arg(SUFFIX_FIX_ARG).unwrap_or_default(),
+        )
     }
 }
 
 pub fn check_enum_variant_names(
     db: &dyn SemanticGroup,
     item: &ModuleItemId,
-    diagnostics: &mut Vec<PluginDiagnostic>,
+    context: &CheckContext,
+    diagnostics: &mut Vec<LintDiagnostic>,
 ) {
     let ModuleItemId::Enum(enum_id) = item else {
         return;
@@ -70,54 +109,100 @@ pub fn check_enum_variant_names(
     let Ok(variants) = db.enum_variants(*enum_id) else {
         return;
     };
+    let options = &context.options().enum_variant_names;
+    if variants.len() < options.min_variants {
+        return;
+    }
     let variant_names: Vec<String> = variants.iter().map(|v| v.0.to_string()).collect();
 
-    let (prefix, suffix) = get_prefix_and_suffix(&variant_names);
+    let (prefix, suffix) = get_shared_words(&variant_names, options);
+    if prefix.is_empty() && suffix.is_empty() {
+        return;
+    }
 
-    if !prefix.is_empty() || !suffix.is_empty() {
-        diagnostics.push(PluginDiagnostic {
-            stable_ptr: enum_id.untyped_stable_ptr(db.upcast()),
-            message: EnumVariantNames.diagnostic_message().to_string(),
-            severity: Severity::Warning,
-            relative_span: None,
-        });
+    let mut diagnostic =
+        LintDiagnostic::new(&EnumVariantNames, enum_id.untyped_stable_ptr(db.upcast()));
+    if !prefix.is_empty() {
+        diagnostic = diagnostic.with_note(&format!("all of the variants start with `{prefix}`"));
+    }
+    if !suffix.is_empty() {
+        diagnostic = diagnostic.with_note(&format!("all of the variants end with `{suffix}`"));
     }
+    diagnostics.push(
+        diagnostic
+            .with_help("remove the shared words from the names of the variants")
+            .with_fix_arg(PREFIX_FIX_ARG, prefix)
+            .with_fix_arg(SUFFIX_FIX_ARG, suffix),
+    );
 }
 
-fn fix_enum_variant_names(db: &dyn SyntaxGroup, node: SyntaxNode) -> Option<(SyntaxNode, String)> {
-    let enum_item = AstEnumItem::from_syntax_node(db, node);
-
-    let source = enum_item.as_syntax_node().get_text(db);
-    let variants = enum_item.variants(db).elements(db);
-
-    let variant_names: Vec<String> = variants
-        .iter()
-        .map(|v| v.name(db).text(db).to_string())
-        .collect();
-
-    let (prefixes, suffixes) = get_prefix_and_suffix(&variant_names);
+/// Finds the words shared by the beginnings and the ends of the variant names, which are checked
+/// according to the options. The words are empty if they are not shared or not checked.
+fn get_shared_words(
+    variant_names: &[String],
+    options: &EnumVariantNamesOptions,
+) -> (String, String) {
+    let (prefix, suffix) = get_prefix_and_suffix(variant_names);
+    let prefix = if options.check_prefix {
+        prefix.concat()
+    } else {
+        String::new()
+    };
+    // The words of the suffix are collected starting from the end of the names.
+    let suffix = if options.check_suffix {
+        suffix.iter().rev().join("")
+    } else {
+        String::new()
+    };
+    (prefix, suffix)
+}
 
-    let mut fixed_enum = source.clone();
+/// Removes the shared prefix and suffix from the names of the variants.
+/// Only the names are edited, so the rest of the enum, e.g. the comments or the types, is left intact.
+fn fix_enum_variant_names(
+    db: &dyn SyntaxGroup,
+    node: SyntaxNode,
+    prefix: &str,
+    suffix: &str,
+) -> Option<Suggestion> {
+    if prefix.is_empty() && suffix.is_empty() {
+        return None;
+    }
+    let enum_item = AstEnumItem::from_syntax_node(db, node);
 
-    for variant in &variant_names {
-        let mut fixed_name = variant.clone();
+    let mut edits = Vec::new();
+    for variant in enum_item.variants(db).elements(db) {
+        let name = variant.name(db);
+        let variant_name = name.text(db);
+        let mut fixed_name = variant_name.as_str();
 
-        for prefix in &prefixes {
-            if let Some(stripped) = fixed_name.strip_prefix(prefix) {
-                fixed_name = stripped.to_string();
-            }
+        if let Some(stripped) = fixed_name.strip_prefix(prefix) {
+            fixed_name = stripped;
         }
 
-        for suffix in &suffixes {
-            if let Some(stripped) = fixed_name.strip_suffix(suffix) {
-                fixed_name = stripped.to_string();
-            }
+        if let Some(stripped) = fixed_name.strip_suffix(suffix) {
+            fixed_name = stripped;
         }
 
-        fixed_enum = fixed_enum.replace(variant, &fixed_name);
+        // The prefix and the suffix can overlap, then there is no name left.
+        if fixed_name.is_empty() {
+            return None;
+        }
+        if fixed_name != variant_name.as_str() {
+            edits.push(Edit::replace(
+                name.as_syntax_node().span_without_trivia(db),
+                fixed_name,
+            ));
+        }
     }
 
-    Some((node, fixed_enum))
+    Some(
+        Suggestion::new(
+            "Remove the common prefix or suffix from the enum variants",
+            edits,
+        )
+        .with_applicability(Applicability::MaybeIncorrect),
+    )
 }
 
 fn get_prefix_and_suffix(variant_names: &[String]) -> (Vec<String>, Vec<String>) {
@@ -1,43 +1,26 @@
-use cairo_lang_defs::ids::ModuleItemId;
-use cairo_lang_defs::plugin::PluginDiagnostic;
-use cairo_lang_diagnostics::Severity;
 use cairo_lang_semantic::db::SemanticGroup;
 use cairo_lang_semantic::{Arenas, Expr, ExprFunctionCall, ExprFunctionCallArg};
 use cairo_lang_syntax::node::db::SyntaxGroup;
 use cairo_lang_syntax::node::{SyntaxNode, TypedStablePtr, TypedSyntaxNode};
 use if_chain::if_chain;
+use indoc::indoc;
 
-use crate::context::{CairoLintKind, Lint};
-use crate::queries::{get_all_function_bodies, get_all_function_calls};
+use crate::context::{CairoLintKind, CheckContext, Lint, LintCategory, LintDocs};
+use crate::diagnostics::LintDiagnostic;
 
 use super::{function_trait_name_from_fn_id, AND, DIV, EQ, GE, GT, LE, LT, NE, NOT, OR, SUB, XOR};
 
 pub struct DivisionEqualityOperation;
 
-/// ## What it does
-///
-/// Checks for division with identical operands.
-///
-/// ## Example
-///
-/// ```cairo
-/// fn foo(a: u256) -> u256 {
-///     a / a
-/// }
-/// ```
-///
-/// Could be simplified by replacing the entire expression with 1:
-///
-/// ```cairo
-/// fn foo(a: u256) -> u256 {
-///     1
-/// }
-/// ```
 impl Lint for DivisionEqualityOperation {
     fn allowed_name(&self) -> &'static str {
         "div_eq_op"
     }
 
+    fn id(&self) -> &'static str {
+        "CL0034"
+    }
+
     fn diagnostic_message(&self) -> &'static str {
         "Division with identical operands, this operation always results in one (except for zero) and \
                          may indicate a logic error"
@@ -46,34 +29,42 @@ impl Lint for DivisionEqualityOperation {
     fn kind(&self) -> CairoLintKind {
         CairoLintKind::EqualityOperation
     }
+
+    fn category(&self) -> LintCategory {
+        LintCategory::Correctness
+    }
+
+    fn docs(&self) -> LintDocs {
+        LintDocs {
+            description: "Checks for division with identical operands.",
+            rationale: Some(
+                "The result is always 1, so the entire expression can be replaced with it.",
+            ),
+            bad_example: Some(indoc! {r#"
+                fn foo(a: u256) -> u256 {
+                    a / a
+                }
+            "#}),
+            good_example: Some(indoc! {r#"
+                fn foo(a: u256) -> u256 {
+                    1
+                }
+            "#}),
+        }
+    }
 }
 
 pub struct EqualComparisonOperation;
 
-/// ## What it does
-///
-/// Checks for comparison with identical operands.
-///
-/// ## Example
-///
-/// ```cairo
-/// fn foo(a: u256) -> bool {
-///     a == a
-/// }
-/// ```
-///
-/// Could be simplified by replacing the entire expression with true:
-///
-/// ```cairo
-/// fn foo(a: u256) -> bool {
-///     true
-/// }
-/// ```
 impl Lint for EqualComparisonOperation {
     fn allowed_name(&self) -> &'static str {
         "eq_comp_op"
     }
 
+    fn id(&self) -> &'static str {
+        "CL0035"
+    }
+
     fn diagnostic_message(&self) -> &'static str {
         "Comparison with identical operands, this operation always results in true and may indicate a logic error"
     }
@@ -81,38 +72,42 @@ impl Lint for EqualComparisonOperation {
     fn kind(&self) -> CairoLintKind {
         CairoLintKind::EqualityOperation
     }
+
+    fn category(&self) -> LintCategory {
+        LintCategory::Correctness
+    }
+
+    fn docs(&self) -> LintDocs {
+        LintDocs {
+            description: "Checks for comparison with identical operands.",
+            rationale: Some(
+                "The result is always `true`, so the entire expression can be replaced with it.",
+            ),
+            bad_example: Some(indoc! {r#"
+                fn foo(a: u256) -> bool {
+                    a == a
+                }
+            "#}),
+            good_example: Some(indoc! {r#"
+                fn foo(a: u256) -> bool {
+                    true
+                }
+            "#}),
+        }
+    }
 }
 
 pub struct NotEqualComparisonOperation;
 
-/// ## What it does
-///
-/// Checks for arithmetical comparison with identical operands.
-///
-/// ## Example
-///
-/// ```cairo
-/// fn foo(a: u256) -> bool {
-///     let _z = a != a;
-///     let _y = a > a;
-///     a < a
-/// }
-/// ```
-///
-/// Could be simplified by replacing the entire expression with false:
-///
-/// ```cairo
-/// fn foo(a: u256) -> bool {
-///     let _z = false;
-///     let _y = false;
-///     false
-/// }
-/// ```
 impl Lint for NotEqualComparisonOperation {
     fn allowed_name(&self) -> &'static str {
         "neq_comp_op"
     }
 
+    fn id(&self) -> &'static str {
+        "CL0036"
+    }
+
     fn diagnostic_message(&self) -> &'static str {
         "Comparison with identical operands, this operation always results in false and may indicate a logic error"
     }
@@ -120,34 +115,46 @@ impl Lint for NotEqualComparisonOperation {
     fn kind(&self) -> CairoLintKind {
         CairoLintKind::EqualityOperation
     }
+
+    fn category(&self) -> LintCategory {
+        LintCategory::Correctness
+    }
+
+    fn docs(&self) -> LintDocs {
+        LintDocs {
+            description: "Checks for arithmetical comparison with identical operands.",
+            rationale: Some(
+                "The result is always `false`, so the entire expression can be replaced with it.",
+            ),
+            bad_example: Some(indoc! {r#"
+                fn foo(a: u256) -> bool {
+                    let _z = a != a;
+                    let _y = a > a;
+                    a < a
+                }
+            "#}),
+            good_example: Some(indoc! {r#"
+                fn foo(a: u256) -> bool {
+                    let _z = false;
+                    let _y = false;
+                    false
+                }
+            "#}),
+        }
+    }
 }
 
 pub struct DifferenceEqualityOperation;
 
-/// ## What it does
-///
-/// Checks for subtraction with identical operands.
-///
-/// ## Example
-///
-/// ```cairo
-/// fn foo(a: u256) -> u256 {
-///     a - a
-/// }
-/// ```
-///
-/// Could be simplified by replacing the entire expression with zero:
-///
-/// ```cairo
-/// fn foo(a: u256) -> u256 {
-///     0
-/// }
-/// ```
 impl Lint for DifferenceEqualityOperation {
     fn allowed_name(&self) -> &'static str {
         "eq_diff_op"
     }
 
+    fn id(&self) -> &'static str {
+        "CL0037"
+    }
+
     fn diagnostic_message(&self) -> &'static str {
         "Subtraction with identical operands, this operation always results in zero and may indicate a logic error"
     }
@@ -155,34 +162,42 @@ impl Lint for DifferenceEqualityOperation {
     fn kind(&self) -> CairoLintKind {
         CairoLintKind::EqualityOperation
     }
+
+    fn category(&self) -> LintCategory {
+        LintCategory::Correctness
+    }
+
+    fn docs(&self) -> LintDocs {
+        LintDocs {
+            description: "Checks for subtraction with identical operands.",
+            rationale: Some(
+                "The result is always zero, so the entire expression can be replaced with it.",
+            ),
+            bad_example: Some(indoc! {r#"
+                fn foo(a: u256) -> u256 {
+                    a - a
+                }
+            "#}),
+            good_example: Some(indoc! {r#"
+                fn foo(a: u256) -> u256 {
+                    0
+                }
+            "#}),
+        }
+    }
 }
 
 pub struct BitwiseEqualityOperation;
 
-/// ## What it does
-///
-/// Checks for bitwise operation with identical operands.
-///
-/// ## Example
-///
-/// ```cairo
-/// fn foo(a: u256) -> u256 {
-///     a & a
-/// }
-/// ```
-///
-/// Could be simplified by replacing the entire expression with the operand:
-///
-/// ```cairo
-/// fn foo(a: u256) -> u256 {
-///     a
-/// }
-/// ```
 impl Lint for BitwiseEqualityOperation {
     fn allowed_name(&self) -> &'static str {
         "eq_bitwise_op"
     }
 
+    fn id(&self) -> &'static str {
+        "CL0038"
+    }
+
     fn diagnostic_message(&self) -> &'static str {
         "Bitwise operation with identical operands, this operation always results in the same \
                              value and may indicate a logic error"
@@ -191,34 +206,40 @@ impl Lint for BitwiseEqualityOperation {
     fn kind(&self) -> CairoLintKind {
         CairoLintKind::EqualityOperation
     }
+
+    fn category(&self) -> LintCategory {
+        LintCategory::Correctness
+    }
+
+    fn docs(&self) -> LintDocs {
+        LintDocs {
+            description: "Checks for bitwise operation with identical operands.",
+            rationale: Some("The result is always equal to the operand, so the entire expression can be replaced with it."),
+            bad_example: Some(indoc! {r#"
+                fn foo(a: u256) -> u256 {
+                    a & a
+                }
+            "#}),
+            good_example: Some(indoc! {r#"
+                fn foo(a: u256) -> u256 {
+                    a
+                }
+            "#}),
+        }
+    }
 }
 
 pub struct LogicalEqualityOperation;
 
-/// ## What it does
-///
-/// Checks for logical operation with identical operands.
-///
-/// ## Example
-///
-/// ```cairo
-/// fn foo(a: u256) -> u256 {
-///     a & a
-/// }
-/// ```
-///
-/// Could be simplified by replacing the entire expression with the operand:
-///
-/// ```cairo
-/// fn foo(a: u256) -> u256 {
-///     a
-/// }
-/// ```
 impl Lint for LogicalEqualityOperation {
     fn allowed_name(&self) -> &'static str {
         "eq_logical_op"
     }
 
+    fn id(&self) -> &'static str {
+        "CL0039"
+    }
+
     fn diagnostic_message(&self) -> &'static str {
         "Logical operation with identical operands, this operation always results in the same \
                              value and may indicate a logic error"
@@ -227,28 +248,35 @@ impl Lint for LogicalEqualityOperation {
     fn kind(&self) -> CairoLintKind {
         CairoLintKind::EqualityOperation
     }
-}
 
-pub fn check_eq_op(
-    db: &dyn SemanticGroup,
-    item: &ModuleItemId,
-    diagnostics: &mut Vec<PluginDiagnostic>,
-) {
-    let function_bodies = get_all_function_bodies(db, item);
-    for function_body in function_bodies.iter() {
-        let function_call_exprs = get_all_function_calls(function_body);
-        let arenas = &function_body.arenas;
-        for function_call_expr in function_call_exprs {
-            check_single_eq_op(db, &function_call_expr, arenas, diagnostics);
+    fn category(&self) -> LintCategory {
+        LintCategory::Correctness
+    }
+
+    fn docs(&self) -> LintDocs {
+        LintDocs {
+            description: "Checks for logical operation with identical operands.",
+            rationale: Some("The result is always equal to the operand, so the entire expression can be replaced with it."),
+            bad_example: Some(indoc! {r#"
+                fn foo(a: u256) -> u256 {
+                    a & a
+                }
+            "#}),
+            good_example: Some(indoc! {r#"
+                fn foo(a: u256) -> u256 {
+                    a
+                }
+            "#}),
         }
     }
 }
 
-fn check_single_eq_op(
+pub fn check_eq_op(
     db: &dyn SemanticGroup,
     expr_func: &ExprFunctionCall,
     arenas: &Arenas,
-    diagnostics: &mut Vec<PluginDiagnostic>,
+    _context: &CheckContext,
+    diagnostics: &mut Vec<LintDiagnostic>,
 ) {
     // We're looking for binary operations
     if expr_func.args.len() != 2 {
@@ -305,13 +333,14 @@ fn check_single_eq_op(
     let op = function_trait_name_from_fn_id(db, &expr_func.function);
 
     if are_operands_equal(db.upcast(), lhs, rhs) {
-        if let Some(message) = get_diagnostic_message(&op) {
-            diagnostics.push(PluginDiagnostic {
-                stable_ptr: expr_func.stable_ptr.untyped(),
-                message: message.to_owned(),
-                severity: Severity::Warning,
-                relative_span: None,
-            });
+        if let Some(lint) = get_lint(&op) {
+            diagnostics.push(
+                LintDiagnostic::new(lint, expr_func.stable_ptr.untyped()).with_label(
+                    db,
+                    rhs.stable_ptr(db.upcast()),
+                    "this operand is identical to the left one",
+                ),
+            );
         }
     }
 }
@@ -320,14 +349,14 @@ fn are_operands_equal(db: &dyn SyntaxGroup, lhs: SyntaxNode, rhs: SyntaxNode) ->
     lhs.get_text_without_trivia(db) == rhs.get_text_without_trivia(db)
 }
 
-fn get_diagnostic_message(op: &str) -> Option<&'static str> {
+fn get_lint(op: &str) -> Option<&'static dyn Lint> {
     match op {
-        EQ | LE | GE => Some(EqualComparisonOperation.diagnostic_message()),
-        NE | LT | GT => Some(NotEqualComparisonOperation.diagnostic_message()),
-        AND | OR => Some(LogicalEqualityOperation.diagnostic_message()),
-        XOR | NOT => Some(BitwiseEqualityOperation.diagnostic_message()),
-        SUB => Some(DifferenceEqualityOperation.diagnostic_message()),
-        DIV => Some(DivisionEqualityOperation.diagnostic_message()),
+        EQ | LE | GE => Some(&EqualComparisonOperation),
+        NE | LT | GT => Some(&NotEqualComparisonOperation),
+        AND | OR => Some(&LogicalEqualityOperation),
+        XOR | NOT => Some(&BitwiseEqualityOperation),
+        SUB => Some(&DifferenceEqualityOperation),
+        DIV => Some(&DivisionEqualityOperation),
         _ => None,
     }
 }
@@ -1,6 +1,3 @@
-use cairo_lang_defs::ids::ModuleItemId;
-use cairo_lang_defs::plugin::PluginDiagnostic;
-use cairo_lang_diagnostics::Severity;
 use cairo_lang_semantic::db::SemanticGroup;
 use cairo_lang_semantic::{Arenas, Expr, ExprIf, Statement};
 use cairo_lang_syntax::node::db::SyntaxGroup;
@@ -9,50 +6,25 @@ use cairo_lang_syntax::node::{
     SyntaxNode, TypedStablePtr, TypedSyntaxNode,
 };
 use if_chain::if_chain;
+use indoc::indoc;
 
-use crate::context::{CairoLintKind, Lint};
+use crate::context::{CairoLintKind, CheckContext, Lint, LintCategory, LintDocs};
+use crate::diagnostics::LintDiagnostic;
+use crate::fixes::Suggestion;
 use crate::helper::indent_snippet;
-use crate::queries::{get_all_function_bodies, get_all_if_expressions, is_assert_macro_call};
+use crate::queries::is_assert_macro_call;
 
 pub struct CollapsibleIf;
 
-/// ## What it does
-///
-/// Checks for nested `if` statements that can be collapsed into a single `if` statement.
-///
-/// ## Example
-///
-/// ```cairo
-/// fn main() {
-///     let x = true;
-///     let y = true;
-///     let z = false;
-///
-///     if x || z {
-///         if y && z {
-///             println!("Hello");
-///         }
-///     }
-/// }
-/// ```
-///
-/// Can be collapsed to
-///
-/// ```cairo
-/// fn main() {
-///     let x = true;
-///     let y = true;
-///     let z = false;
-///     if (x || z) && (y && z) {
-///         println!("Hello");
-///     }
-/// }
-/// ```
 impl Lint for CollapsibleIf {
     fn allowed_name(&self) -> &'static str {
         "collapsible_if"
     }
 
+    fn id(&self) -> &'static str {
+        "CL0012"
+    }
+
     fn diagnostic_message(&self) -> &'static str {
         "Each `if`-statement adds one level of nesting, which makes code look more complex than it really is."
     }
@@ -61,35 +33,61 @@ impl Lint for CollapsibleIf {
         CairoLintKind::CollapsibleIf
     }
 
+    fn category(&self) -> LintCategory {
+        LintCategory::Style
+    }
+
+    fn docs(&self) -> LintDocs {
+        LintDocs {
+            description: "Checks for nested `if` statements that can be collapsed into a single `if` statement.",
+            bad_example: Some(indoc! {r#"
+                fn main() {
+                    let x = true;
+                    let y = true;
+                    let z = false;
+
+                    if x || z {
+                        if y && z {
+                            println!("Hello");
+                        }
+                    }
+                }
+            "#}),
+            good_example: Some(indoc! {r#"
+                fn main() {
+                    let x = true;
+                    let y = true;
+                    let z = false;
+                    if (x || z) && (y && z) {
+                        println!("Hello");
+                    }
+                }
+            "#}),
+            ..Default::default()
+        }
+    }
+
     fn has_fixer(&self) -> bool {
         true
     }
 
-    fn fix(&self, db: &dyn SemanticGroup, node: SyntaxNode) -> Option<(SyntaxNode, String)> {
-        fix_collapsible_if(db.upcast(), node)
+    fn fix(&self, db: &dyn SemanticGroup, node: SyntaxNode) -> Option<Suggestion> {
+        let (node, replacement) = fix_collapsible_if(db.upcast(), node)?;
+        Some(Suggestion::replace_node(
+            db.upcast(),
+            "Collapse the nested `if` statements",
+            node,
+            replacement,
+        ))
     }
 }
 
 pub fn check_collapsible_if(
-    db: &dyn SemanticGroup,
-    item: &ModuleItemId,
-    diagnostics: &mut Vec<PluginDiagnostic>,
-) {
-    let function_bodies = get_all_function_bodies(db, item);
-    for function_body in function_bodies.iter() {
-        let if_exprs = get_all_if_expressions(function_body);
-        let arenas = &function_body.arenas;
-        for if_expr in if_exprs.iter() {
-            check_single_collapsible_if(db, if_expr, arenas, diagnostics);
-        }
-    }
-}
-
-fn check_single_collapsible_if(
     db: &dyn SemanticGroup,
     if_expr: &ExprIf,
     arenas: &Arenas,
-    diagnostics: &mut Vec<PluginDiagnostic>,
+    _context: &CheckContext,
+    diagnostics: &mut Vec<LintDiagnostic>,
 ) {
     let Expr::Block(ref if_block) = arenas.exprs[if_expr.if_block] else {
         return;
@@ -116,12 +114,7 @@ fn check_single_collapsible_if(
                 return;
             }
 
-            diagnostics.push(PluginDiagnostic {
-                stable_ptr: if_expr.stable_ptr.untyped(),
-                message: CollapsibleIf.diagnostic_message().to_string(),
-                severity: Severity::Warning,
-                relative_span: None
-            });
+            diagnostics.push(LintDiagnostic::new(&CollapsibleIf, if_expr.stable_ptr.untyped()));
             return;
         }
     }
@@ -136,12 +129,10 @@ fn check_single_collapsible_if(
         if_expr.else_block.is_none() && inner_if_expr.else_block.is_none()
     }) && if_block.statements.is_empty()
     {
-        diagnostics.push(PluginDiagnostic {
-            stable_ptr: if_expr.stable_ptr.untyped(),
-            message: CollapsibleIf.diagnostic_message().to_string(),
-            severity: Severity::Warning,
-            relative_span: None,
-        });
+        diagnostics.push(LintDiagnostic::new(
+            &CollapsibleIf,
+            if_expr.stable_ptr.untyped(),
+        ));
     }
 }
 
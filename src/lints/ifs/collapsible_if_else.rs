@@ -1,6 +1,3 @@
-use cairo_lang_defs::ids::ModuleItemId;
-use cairo_lang_defs::plugin::PluginDiagnostic;
-use cairo_lang_diagnostics::Severity;
 use cairo_lang_semantic::db::SemanticGroup;
 use cairo_lang_semantic::{Arenas, Expr, ExprBlock, ExprIf, Statement};
 use cairo_lang_syntax::node::db::SyntaxGroup;
@@ -12,49 +9,23 @@ use cairo_lang_syntax::node::{
     SyntaxNode, TypedStablePtr, TypedSyntaxNode,
 };
 use if_chain::if_chain;
+use indoc::indoc;
 
-use crate::context::{CairoLintKind, Lint};
-use crate::queries::{get_all_function_bodies, get_all_if_expressions};
+use crate::context::{CairoLintKind, CheckContext, Lint, LintCategory, LintDocs};
+use crate::diagnostics::LintDiagnostic;
+use crate::fixes::Suggestion;
 
 pub struct CollapsibleIfElse;
 
-/// ## What it does
-///
-/// Checks for nested `if` statements inside the `else` statement
-/// that can be collapsed into a single `if-else` statement.
-///
-/// ## Example
-///
-/// ```cairo
-/// fn main() {
-///     let x = true;
-///     if x {
-///         println!("x is true");
-///     } else {
-///         if !x {
-///             println!("x is false");
-///         }
-///     }
-/// }
-/// ```
-///
-/// Can be refactored to:
-///
-/// ```cairo
-/// fn main() {
-///     let x = true;
-///     if x {
-///         println!("x is true");
-///     } else if !x {
-///         println!("x is false");
-///     }
-/// }
-/// ```
 impl Lint for CollapsibleIfElse {
     fn allowed_name(&self) -> &'static str {
         "collapsible_if_else"
     }
 
+    fn id(&self) -> &'static str {
+        "CL0011"
+    }
+
     fn diagnostic_message(&self) -> &'static str {
         "Consider using else if instead of else { if ... }"
     }
@@ -63,12 +34,51 @@ impl Lint for CollapsibleIfElse {
         CairoLintKind::CollapsibleIfElse
     }
 
+    fn category(&self) -> LintCategory {
+        LintCategory::Style
+    }
+
+    fn docs(&self) -> LintDocs {
+        LintDocs {
+            description: "Checks for nested `if` statements inside the `else` statement that can be collapsed into a single `if-else` statement.",
+            bad_example: Some(indoc! {r#"
+                fn main() {
+                    let x = true;
+                    if x {
+                        println!("x is true");
+                    } else {
+                        if !x {
+                            println!("x is false");
+                        }
+                    }
+                }
+            "#}),
+            good_example: Some(indoc! {r#"
+                fn main() {
+                    let x = true;
+                    if x {
+                        println!("x is true");
+                    } else if !x {
+                        println!("x is false");
+                    }
+                }
+            "#}),
+            ..Default::default()
+        }
+    }
+
     fn has_fixer(&self) -> bool {
         true
     }
 
-    fn fix(&self, db: &dyn SemanticGroup, node: SyntaxNode) -> Option<(SyntaxNode, String)> {
-        fix_collapsible_if_else(db.upcast(), node)
+    fn fix(&self, db: &dyn SemanticGroup, node: SyntaxNode) -> Option<Suggestion> {
+        let (node, replacement) = fix_collapsible_if_else(db.upcast(), node)?;
+        Some(Suggestion::replace_node(
+            db.upcast(),
+            "Replace the `else { if .. }` with `else if`",
+            node,
+            replacement,
+        ))
     }
 }
 
@@ -91,24 +101,11 @@ impl Lint for CollapsibleIfElse {
 /// }
 /// ```
 pub fn check_collapsible_if_else(
-    db: &dyn SemanticGroup,
-    item: &ModuleItemId,
-    diagnostics: &mut Vec<PluginDiagnostic>,
-) {
-    let function_bodies = get_all_function_bodies(db, item);
-    for function_body in function_bodies.iter() {
-        let if_exprs = get_all_if_expressions(function_body);
-        let arenas = &function_body.arenas;
-        for if_expr in if_exprs.iter() {
-            check_single_collapsible_if_else(if_expr, arenas, diagnostics);
-        }
-    }
-}
-
-fn check_single_collapsible_if_else(
+    _db: &dyn SemanticGroup,
     if_expr: &ExprIf,
     arenas: &Arenas,
-    diagnostics: &mut Vec<PluginDiagnostic>,
+    _context: &CheckContext,
+    diagnostics: &mut Vec<LintDiagnostic>,
 ) {
     // Extract the expression from the ElseClause
     let Some(else_block) = if_expr.else_block else {
@@ -122,12 +119,10 @@ fn check_single_collapsible_if_else(
     let is_if = is_only_statement_if(block_expr, arenas);
 
     if is_if {
-        diagnostics.push(PluginDiagnostic {
-            stable_ptr: if_expr.stable_ptr.untyped(),
-            message: CollapsibleIfElse.diagnostic_message().to_string(),
-            severity: Severity::Warning,
-            relative_span: None,
-        });
+        diagnostics.push(LintDiagnostic::new(
+            &CollapsibleIfElse,
+            if_expr.stable_ptr.untyped(),
+        ));
     }
 }
 
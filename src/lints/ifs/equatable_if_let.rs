@@ -1,6 +1,3 @@
-use cairo_lang_defs::ids::ModuleItemId;
-use cairo_lang_defs::plugin::PluginDiagnostic;
-use cairo_lang_diagnostics::Severity;
 use cairo_lang_semantic::db::SemanticGroup;
 use cairo_lang_semantic::{Arenas, Condition, Expr, ExprIf, Pattern, PatternId};
 use cairo_lang_syntax::node::db::SyntaxGroup;
@@ -8,36 +5,23 @@ use cairo_lang_syntax::node::{
     ast::{Condition as AstCondition, ExprIf as AstExprIf},
     SyntaxNode, TypedStablePtr, TypedSyntaxNode,
 };
+use indoc::indoc;
 
-use crate::context::{CairoLintKind, Lint};
-use crate::queries::{get_all_function_bodies, get_all_if_expressions};
+use crate::context::{CairoLintKind, CheckContext, Lint, LintCategory, LintDocs};
+use crate::diagnostics::LintDiagnostic;
+use crate::fixes::Suggestion;
 
 pub struct EquatableIfLet;
 
-/// ## What it does
-///
-/// Checks for `if let` pattern matching that can be replaced by a simple comparison.
-///
-/// ## Example
-///
-/// ```cairo
-/// if let Some(2) = a {
-///     // Code
-/// }
-/// ```
-///
-/// Could be replaced by
-///
-/// ```cairo
-/// if a == Some(2) {
-///     // Code
-/// }
-/// ```
 impl Lint for EquatableIfLet {
     fn allowed_name(&self) -> &'static str {
         "equatable_if_let"
     }
 
+    fn id(&self) -> &'static str {
+        "CL0008"
+    }
+
     fn diagnostic_message(&self) -> &'static str {
         "`if let` pattern used for equatable value. Consider using a simple comparison `==` instead"
     }
@@ -46,35 +30,49 @@ impl Lint for EquatableIfLet {
         CairoLintKind::EquatableIfLet
     }
 
+    fn category(&self) -> LintCategory {
+        LintCategory::Style
+    }
+
+    fn docs(&self) -> LintDocs {
+        LintDocs {
+            description:
+                "Checks for `if let` pattern matching that can be replaced by a simple comparison.",
+            bad_example: Some(indoc! {r#"
+                if let Some(2) = a {
+                    // Code
+                }
+            "#}),
+            good_example: Some(indoc! {r#"
+                if a == Some(2) {
+                    // Code
+                }
+            "#}),
+            ..Default::default()
+        }
+    }
+
     fn has_fixer(&self) -> bool {
         true
     }
 
-    fn fix(&self, db: &dyn SemanticGroup, node: SyntaxNode) -> Option<(SyntaxNode, String)> {
-        fix_equatable_if_let(db.upcast(), node)
+    fn fix(&self, db: &dyn SemanticGroup, node: SyntaxNode) -> Option<Suggestion> {
+        let (node, replacement) = fix_equatable_if_let(db.upcast(), node)?;
+        Some(Suggestion::replace_node(
+            db.upcast(),
+            "Replace the `if let` with a comparison",
+            node,
+            replacement,
+        ))
     }
 }
 
 pub fn check_equatable_if_let(
-    db: &dyn SemanticGroup,
-    item: &ModuleItemId,
-    diagnostics: &mut Vec<PluginDiagnostic>,
-) {
-    let function_bodies = get_all_function_bodies(db, item);
-    for function_body in function_bodies.iter() {
-        let if_exprs = get_all_if_expressions(function_body);
-        let arenas = &function_body.arenas;
-        for if_expr in if_exprs.iter() {
-            check_single_equatable_if_let(db, if_expr, arenas, diagnostics);
-        }
-    }
-}
-
-fn check_single_equatable_if_let(
     _db: &dyn SemanticGroup,
     if_expr: &ExprIf,
     arenas: &Arenas,
-    diagnostics: &mut Vec<PluginDiagnostic>,
+    _context: &CheckContext,
+    diagnostics: &mut Vec<LintDiagnostic>,
 ) {
     if let Condition::Let(condition_let, patterns) = &if_expr.condition {
         // Simple literals and variables
@@ -85,12 +83,10 @@ fn check_single_equatable_if_let(
         let condition_is_simple = is_simple_equality_condition(patterns, arenas);
 
         if expr_is_simple && condition_is_simple {
-            diagnostics.push(PluginDiagnostic {
-                stable_ptr: if_expr.stable_ptr.untyped(),
-                message: EquatableIfLet.diagnostic_message().to_string(),
-                severity: Severity::Warning,
-                relative_span: None,
-            });
+            diagnostics.push(LintDiagnostic::new(
+                &EquatableIfLet,
+                if_expr.stable_ptr.untyped(),
+            ));
         }
     }
 }
@@ -1,50 +1,23 @@
-use cairo_lang_defs::ids::ModuleItemId;
-use cairo_lang_defs::plugin::PluginDiagnostic;
-use cairo_lang_diagnostics::Severity;
 use cairo_lang_semantic::db::SemanticGroup;
 use cairo_lang_semantic::{Arenas, Condition, Expr, ExprFunctionCall, ExprFunctionCallArg, ExprIf};
 use cairo_lang_syntax::node::{TypedStablePtr, TypedSyntaxNode};
 use if_chain::if_chain;
+use indoc::indoc;
 
-use crate::context::{CairoLintKind, Lint};
-use crate::queries::{get_all_function_bodies, get_all_if_expressions};
+use crate::context::{CairoLintKind, CheckContext, Lint, LintCategory, LintDocs};
+use crate::diagnostics::LintDiagnostic;
 
 pub struct DuplicateIfCondition;
 
-/// ## What it does
-///
-/// Checks for consecutive `if` expressions with the same condition.
-///
-/// ## Example
-///
-/// ```cairo
-/// fn main() {
-///     let a = 1;
-///     let b = 1;
-///     if a == b {
-///         println!("a is equal to b");
-///     } else if a == b {
-///         println!("a is equal to b");
-///     }
-/// }
-/// ```
-///
-/// Could be rewritten as just:
-///
-/// ```cairo
-/// fn main() {
-///     let a = 1;
-///     let b = 1;
-///     if a == b {
-///         println!("a is equal to b");
-///     }
-/// }
-/// ```
 impl Lint for DuplicateIfCondition {
     fn allowed_name(&self) -> &'static str {
         "ifs_same_cond"
     }
 
+    fn id(&self) -> &'static str {
+        "CL0028"
+    }
+
     fn diagnostic_message(&self) -> &'static str {
         "Consecutive `if` with the same condition found."
     }
@@ -52,28 +25,45 @@ impl Lint for DuplicateIfCondition {
     fn kind(&self) -> CairoLintKind {
         CairoLintKind::DuplicateIfCondition
     }
-}
 
-pub fn check_duplicate_if_condition(
-    db: &dyn SemanticGroup,
-    item: &ModuleItemId,
-    diagnostics: &mut Vec<PluginDiagnostic>,
-) {
-    let function_bodies = get_all_function_bodies(db, item);
-    for function_body in function_bodies.iter() {
-        let if_exprs = get_all_if_expressions(function_body);
-        let arenas = &function_body.arenas;
-        for if_expr in if_exprs.iter() {
-            check_single_duplicate_if_condition(db, if_expr, arenas, diagnostics);
+    fn category(&self) -> LintCategory {
+        LintCategory::Correctness
+    }
+
+    fn docs(&self) -> LintDocs {
+        LintDocs {
+            description: "Checks for consecutive `if` expressions with the same condition.",
+            bad_example: Some(indoc! {r#"
+                fn main() {
+                    let a = 1;
+                    let b = 1;
+                    if a == b {
+                        println!("a is equal to b");
+                    } else if a == b {
+                        println!("a is equal to b");
+                    }
+                }
+            "#}),
+            good_example: Some(indoc! {r#"
+                fn main() {
+                    let a = 1;
+                    let b = 1;
+                    if a == b {
+                        println!("a is equal to b");
+                    }
+                }
+            "#}),
+            ..Default::default()
         }
     }
 }
 
-fn check_single_duplicate_if_condition(
+pub fn check_duplicate_if_condition(
     db: &dyn SemanticGroup,
     if_expr: &ExprIf,
     arenas: &Arenas,
-    diagnostics: &mut Vec<PluginDiagnostic>,
+    _context: &CheckContext,
+    diagnostics: &mut Vec<LintDiagnostic>,
 ) {
     let cond_expr = match &if_expr.condition {
         Condition::BoolExpr(expr_id) => &arenas.exprs[*expr_id],
@@ -118,12 +108,14 @@ fn check_single_duplicate_if_condition(
                 .get_text(db.upcast());
 
             if if_condition_text == else_if_condition_text {
-                diagnostics.push(PluginDiagnostic {
-                    stable_ptr: if_expr.stable_ptr.untyped(),
-                    message: DuplicateIfCondition.diagnostic_message().to_string(),
-                    severity: Severity::Warning,
-                    relative_span: None,
-                });
+                diagnostics.push(
+                    LintDiagnostic::new(&DuplicateIfCondition, if_expr.stable_ptr.untyped())
+                        .with_label(
+                            db,
+                            else_if_cond.stable_ptr().untyped(),
+                            "the same condition is checked here",
+                        ),
+                );
                 break;
             }
         } else {
@@ -1,46 +1,26 @@
-use cairo_lang_defs::ids::ModuleItemId;
-use cairo_lang_defs::plugin::PluginDiagnostic;
-use cairo_lang_diagnostics::Severity;
 use cairo_lang_semantic::db::SemanticGroup;
 use cairo_lang_semantic::{Arenas, Expr, ExprFunctionCall, ExprFunctionCallArg};
 use cairo_lang_syntax::node::ast::{Expr as AstExpr, ExprBinary};
 use cairo_lang_syntax::node::db::SyntaxGroup;
 use cairo_lang_syntax::node::{SyntaxNode, TypedStablePtr, TypedSyntaxNode};
 use if_chain::if_chain;
+use indoc::indoc;
 
-use crate::context::{CairoLintKind, Lint};
-use crate::queries::{get_all_function_bodies, get_all_function_calls};
+use crate::context::{CairoLintKind, CheckContext, Lint, LintCategory, LintDocs};
+use crate::diagnostics::LintDiagnostic;
+use crate::fixes::Suggestion;
 
 pub struct IntegerGreaterEqualPlusOne;
 
-/// ## What it does
-///
-/// Check for unnecessary add operation in integer >= comparison.
-///
-/// ## Example
-///
-/// ```cairo
-/// fn main() {
-///     let x: u32 = 1;
-///     let y: u32 = 1;
-///     if x >= y + 1 {}
-/// }
-/// ```
-///
-/// Can be simplified to:
-///
-/// ```cairo
-/// fn main() {
-///     let x: u32 = 1;
-///     let y: u32 = 1;
-///     if x > y {}
-/// }
-/// ```
 impl Lint for IntegerGreaterEqualPlusOne {
     fn allowed_name(&self) -> &'static str {
         "int_ge_plus_one"
     }
 
+    fn id(&self) -> &'static str {
+        "CL0030"
+    }
+
     fn diagnostic_message(&self) -> &'static str {
         "Unnecessary add operation in integer >= comparison. Use simplified comparison."
     }
@@ -49,45 +29,57 @@ impl Lint for IntegerGreaterEqualPlusOne {
         CairoLintKind::IntGePlusOne
     }
 
+    fn category(&self) -> LintCategory {
+        LintCategory::Complexity
+    }
+
+    fn docs(&self) -> LintDocs {
+        LintDocs {
+            description: "Check for unnecessary add operation in integer >= comparison.",
+            bad_example: Some(indoc! {r#"
+                fn main() {
+                    let x: u32 = 1;
+                    let y: u32 = 1;
+                    if x >= y + 1 {}
+                }
+            "#}),
+            good_example: Some(indoc! {r#"
+                fn main() {
+                    let x: u32 = 1;
+                    let y: u32 = 1;
+                    if x > y {}
+                }
+            "#}),
+            ..Default::default()
+        }
+    }
+
     fn has_fixer(&self) -> bool {
         true
     }
 
-    fn fix(&self, db: &dyn SemanticGroup, node: SyntaxNode) -> Option<(SyntaxNode, String)> {
-        fix_int_ge_plus_one(db.upcast(), node)
+    fn fix(&self, db: &dyn SemanticGroup, node: SyntaxNode) -> Option<Suggestion> {
+        let (node, replacement) = fix_int_ge_plus_one(db.upcast(), node)?;
+        Some(Suggestion::replace_node(
+            db.upcast(),
+            "Replace with `>`",
+            node,
+            replacement,
+        ))
     }
 }
 
 pub struct IntegerGreaterEqualMinusOne;
 
-/// ## What it does
-///
-/// Check for unnecessary sub operation in integer >= comparison.
-///
-/// ## Example
-///
-/// ```cairo
-/// fn main() {
-///     let x: u32 = 1;
-///     let y: u32 = 1;
-///     if x - 1 >= y {}
-/// }
-/// ```
-///
-/// Can be simplified to:
-///
-/// ```cairo
-/// fn main() {
-///     let x: u32 = 1;
-///     let y: u32 = 1;
-///     if x > y {}
-/// }
-/// ```
 impl Lint for IntegerGreaterEqualMinusOne {
     fn allowed_name(&self) -> &'static str {
         "int_ge_min_one"
     }
 
+    fn id(&self) -> &'static str {
+        "CL0031"
+    }
+
     fn diagnostic_message(&self) -> &'static str {
         "Unnecessary sub operation in integer >= comparison. Use simplified comparison."
     }
@@ -96,45 +88,57 @@ impl Lint for IntegerGreaterEqualMinusOne {
         CairoLintKind::IntGeMinOne
     }
 
+    fn category(&self) -> LintCategory {
+        LintCategory::Complexity
+    }
+
+    fn docs(&self) -> LintDocs {
+        LintDocs {
+            description: "Check for unnecessary sub operation in integer >= comparison.",
+            bad_example: Some(indoc! {r#"
+                fn main() {
+                    let x: u32 = 1;
+                    let y: u32 = 1;
+                    if x - 1 >= y {}
+                }
+            "#}),
+            good_example: Some(indoc! {r#"
+                fn main() {
+                    let x: u32 = 1;
+                    let y: u32 = 1;
+                    if x > y {}
+                }
+            "#}),
+            ..Default::default()
+        }
+    }
+
     fn has_fixer(&self) -> bool {
         true
     }
 
-    fn fix(&self, db: &dyn SemanticGroup, node: SyntaxNode) -> Option<(SyntaxNode, String)> {
-        fix_int_ge_min_one(db.upcast(), node)
+    fn fix(&self, db: &dyn SemanticGroup, node: SyntaxNode) -> Option<Suggestion> {
+        let (node, replacement) = fix_int_ge_min_one(db.upcast(), node)?;
+        Some(Suggestion::replace_node(
+            db.upcast(),
+            "Replace with `>`",
+            node,
+            replacement,
+        ))
     }
 }
 
 pub struct IntegerLessEqualPlusOne;
 
-/// ## What it does
-///
-/// Check for unnecessary add operation in integer <= comparison.
-///
-/// ## Example
-///
-/// ```cairo
-/// fn main() {
-///     let x: u32 = 1;
-///     let y: u32 = 1;
-///     if x + 1 <= y {}
-/// }
-/// ```
-///
-/// Can be simplified to:
-///
-/// ```cairo
-/// fn main() {
-///     let x: u32 = 1;
-///     let y: u32 = 1;
-///     if x < y {}
-/// }
-/// ```
 impl Lint for IntegerLessEqualPlusOne {
     fn allowed_name(&self) -> &'static str {
         "int_le_plus_one"
     }
 
+    fn id(&self) -> &'static str {
+        "CL0032"
+    }
+
     fn diagnostic_message(&self) -> &'static str {
         "Unnecessary add operation in integer <= comparison. Use simplified comparison."
     }
@@ -143,45 +147,57 @@ impl Lint for IntegerLessEqualPlusOne {
         CairoLintKind::IntLePlusOne
     }
 
+    fn category(&self) -> LintCategory {
+        LintCategory::Complexity
+    }
+
+    fn docs(&self) -> LintDocs {
+        LintDocs {
+            description: "Check for unnecessary add operation in integer <= comparison.",
+            bad_example: Some(indoc! {r#"
+                fn main() {
+                    let x: u32 = 1;
+                    let y: u32 = 1;
+                    if x + 1 <= y {}
+                }
+            "#}),
+            good_example: Some(indoc! {r#"
+                fn main() {
+                    let x: u32 = 1;
+                    let y: u32 = 1;
+                    if x < y {}
+                }
+            "#}),
+            ..Default::default()
+        }
+    }
+
     fn has_fixer(&self) -> bool {
         true
     }
 
-    fn fix(&self, db: &dyn SemanticGroup, node: SyntaxNode) -> Option<(SyntaxNode, String)> {
-        fix_int_le_plus_one(db.upcast(), node)
+    fn fix(&self, db: &dyn SemanticGroup, node: SyntaxNode) -> Option<Suggestion> {
+        let (node, replacement) = fix_int_le_plus_one(db.upcast(), node)?;
+        Some(Suggestion::replace_node(
+            db.upcast(),
+            "Replace with `<`",
+            node,
+            replacement,
+        ))
     }
 }
 
 pub struct IntegerLessEqualMinusOne;
 
-/// ## What it does
-///
-/// Check for unnecessary sub operation in integer <= comparison.
-///
-/// ## Example
-///
-/// ```cairo
-/// fn main() {
-///     let x: u32 = 1;
-///     let y: u32 = 1;
-///     if x <= y - 1 {}
-/// }
-/// ```
-///
-/// Can be simplified to:
-///
-/// ```cairo
-/// fn main() {
-///     let x: u32 = 1;
-///     let y: u32 = 1;
-///     if x < y {}
-/// }
-/// ```
 impl Lint for IntegerLessEqualMinusOne {
     fn allowed_name(&self) -> &'static str {
         "int_le_min_one"
     }
 
+    fn id(&self) -> &'static str {
+        "CL0033"
+    }
+
     fn diagnostic_message(&self) -> &'static str {
         "Unnecessary sub operation in integer <= comparison. Use simplified comparison."
     }
@@ -190,35 +206,52 @@ impl Lint for IntegerLessEqualMinusOne {
         CairoLintKind::IntLeMinOne
     }
 
+    fn category(&self) -> LintCategory {
+        LintCategory::Complexity
+    }
+
+    fn docs(&self) -> LintDocs {
+        LintDocs {
+            description: "Check for unnecessary sub operation in integer <= comparison.",
+            bad_example: Some(indoc! {r#"
+                fn main() {
+                    let x: u32 = 1;
+                    let y: u32 = 1;
+                    if x <= y - 1 {}
+                }
+            "#}),
+            good_example: Some(indoc! {r#"
+                fn main() {
+                    let x: u32 = 1;
+                    let y: u32 = 1;
+                    if x < y {}
+                }
+            "#}),
+            ..Default::default()
+        }
+    }
+
     fn has_fixer(&self) -> bool {
         true
     }
 
-    fn fix(&self, db: &dyn SemanticGroup, node: SyntaxNode) -> Option<(SyntaxNode, String)> {
-        fix_int_le_min_one(db.upcast(), node)
+    fn fix(&self, db: &dyn SemanticGroup, node: SyntaxNode) -> Option<Suggestion> {
+        let (node, replacement) = fix_int_le_min_one(db.upcast(), node)?;
+        Some(Suggestion::replace_node(
+            db.upcast(),
+            "Replace with `<`",
+            node,
+            replacement,
+        ))
     }
 }
 
 pub fn check_int_op_one(
-    db: &dyn SemanticGroup,
-    item: &ModuleItemId,
-    diagnostics: &mut Vec<PluginDiagnostic>,
-) {
-    let function_bodies = get_all_function_bodies(db, item);
-    for function_body in function_bodies.iter() {
-        let function_call_exprs = get_all_function_calls(function_body);
-        let arenas = &function_body.arenas;
-        for function_call_expr in function_call_exprs {
-            check_single_int_op_one(db, &function_call_expr, arenas, diagnostics);
-        }
-    }
-}
-
-fn check_single_int_op_one(
     db: &dyn SemanticGroup,
     function_call_expr: &ExprFunctionCall,
     arenas: &Arenas,
-    diagnostics: &mut Vec<PluginDiagnostic>,
+    _context: &CheckContext,
+    diagnostics: &mut Vec<LintDiagnostic>,
 ) {
     // Check if the function call is the bool greater or equal (>=) or lower or equal (<=).
     let full_name = function_call_expr.function.full_path(db);
@@ -236,12 +269,10 @@ fn check_single_int_op_one(
         && check_is_add_or_sub_one(db, rhs, arenas, "::add")
         && function_call_expr.function.full_path(db).contains("::ge")
     {
-        diagnostics.push(PluginDiagnostic {
-            stable_ptr: function_call_expr.stable_ptr.untyped(),
-            message: IntegerGreaterEqualPlusOne.diagnostic_message().to_string(),
-            severity: Severity::Warning,
-            relative_span: None,
-        })
+        diagnostics.push(LintDiagnostic::new(
+            &IntegerGreaterEqualPlusOne,
+            function_call_expr.stable_ptr.untyped(),
+        ))
     }
 
     // x - 1 >= y
@@ -249,12 +280,10 @@ fn check_single_int_op_one(
         && check_is_variable(rhs, arenas)
         && function_call_expr.function.full_path(db).contains("::ge")
     {
-        diagnostics.push(PluginDiagnostic {
-            stable_ptr: function_call_expr.stable_ptr.untyped(),
-            message: IntegerGreaterEqualMinusOne.diagnostic_message().to_string(),
-            severity: Severity::Warning,
-            relative_span: None,
-        })
+        diagnostics.push(LintDiagnostic::new(
+            &IntegerGreaterEqualMinusOne,
+            function_call_expr.stable_ptr.untyped(),
+        ))
     }
 
     // x + 1 <= y
@@ -262,12 +291,10 @@ fn check_single_int_op_one(
         && check_is_variable(rhs, arenas)
         && function_call_expr.function.full_path(db).contains("::le")
     {
-        diagnostics.push(PluginDiagnostic {
-            stable_ptr: function_call_expr.stable_ptr.untyped(),
-            message: IntegerLessEqualPlusOne.diagnostic_message().to_string(),
-            severity: Severity::Warning,
-            relative_span: None,
-        })
+        diagnostics.push(LintDiagnostic::new(
+            &IntegerLessEqualPlusOne,
+            function_call_expr.stable_ptr.untyped(),
+        ))
     }
 
     // x <= y - 1
@@ -275,12 +302,10 @@ fn check_single_int_op_one(
         && check_is_add_or_sub_one(db, rhs, arenas, "::sub")
         && function_call_expr.function.full_path(db).contains("::le")
     {
-        diagnostics.push(PluginDiagnostic {
-            stable_ptr: function_call_expr.stable_ptr.untyped(),
-            message: IntegerLessEqualMinusOne.diagnostic_message().to_string(),
-            severity: Severity::Warning,
-            relative_span: None,
-        })
+        diagnostics.push(LintDiagnostic::new(
+            &IntegerLessEqualMinusOne,
+            function_call_expr.stable_ptr.untyped(),
+        ))
     }
 }
 
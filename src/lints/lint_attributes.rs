@@ -0,0 +1,225 @@
+//! Lint rules checking the attributes that set the lint levels (`allow`, `warn`, `deny` and `expect`).
+//!
+//! These rules do not have their own checking functions. Their diagnostics are emitted by the
+//! [`crate::plugin::CairoLint`] plugin while it resolves the level of the other lint rules.
+
+use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_syntax::node::SyntaxNode;
+
+use crate::context::{CairoLintKind, Lint, LintCategory, LintDocs, LintRegistry};
+use crate::fixes::Suggestion;
+use indoc::indoc;
+
+pub struct UnfulfilledLintExpectation;
+
+impl Lint for UnfulfilledLintExpectation {
+    fn allowed_name(&self) -> &'static str {
+        "unfulfilled_lint_expectations"
+    }
+
+    fn id(&self) -> &'static str {
+        "CL0047"
+    }
+
+    fn diagnostic_message(&self) -> &'static str {
+        "This lint expectation is unfulfilled. Consider removing the `expect` attribute."
+    }
+
+    fn kind(&self) -> CairoLintKind {
+        CairoLintKind::UnfulfilledLintExpectation
+    }
+
+    fn category(&self) -> LintCategory {
+        LintCategory::Style
+    }
+
+    fn docs(&self) -> LintDocs {
+        LintDocs {
+            description: "Checks for `#[expect(...)]` attributes whose lint rule was not triggered in their scope.",
+            bad_example: Some(indoc! {r#"
+                #[expect(clone_on_copy)]
+                fn main() {
+                    let a: felt252 = 'Hello';
+                    let b = a;
+                }
+            "#}),
+            ..Default::default()
+        }
+    }
+}
+
+pub struct UnusedLintAllow;
+
+impl Lint for UnusedLintAllow {
+    fn allowed_name(&self) -> &'static str {
+        "unused_lint_allows"
+    }
+
+    fn id(&self) -> &'static str {
+        "CL0048"
+    }
+
+    fn diagnostic_message(&self) -> &'static str {
+        "This lint is not suppressed anywhere in the scope of the `allow` attribute. Consider removing it from the attribute."
+    }
+
+    fn kind(&self) -> CairoLintKind {
+        CairoLintKind::UnusedLintAllow
+    }
+
+    fn category(&self) -> LintCategory {
+        LintCategory::Pedantic
+    }
+
+    fn docs(&self) -> LintDocs {
+        LintDocs {
+            description: "Checks for lint names inside the `#[allow(...)]` attributes, which did not suppress any diagnostic in their scope.",
+            bad_example: Some(indoc! {r#"
+                #[allow(clone_on_copy)]
+                fn main() {
+                    let a: felt252 = 'Hello';
+                    let b = a;
+                }
+            "#}),
+            ..Default::default()
+        }
+    }
+
+    fn is_enabled(&self) -> bool {
+        false
+    }
+}
+
+pub struct UnknownLintName;
+
+impl Lint for UnknownLintName {
+    fn allowed_name(&self) -> &'static str {
+        "unknown_lints"
+    }
+
+    fn id(&self) -> &'static str {
+        "CL0049"
+    }
+
+    fn diagnostic_message(&self) -> &'static str {
+        "Unknown lint name."
+    }
+
+    fn kind(&self) -> CairoLintKind {
+        CairoLintKind::UnknownLintName
+    }
+
+    fn category(&self) -> LintCategory {
+        LintCategory::Correctness
+    }
+
+    fn docs(&self) -> LintDocs {
+        LintDocs {
+            description: "Checks for lint names inside the lint level attributes, which are not known to the Cairo lint, and suggests the closest known name. Inside the `#[allow(...)]` attribute only the names similar to one of the known names are reported, as the other ones may belong to the compiler.",
+            bad_example: Some(indoc! {r#"
+                #[allow(clone_on_cpy)]
+                fn main() {
+                    let a: felt252 = 'Hello';
+                    let b = a.clone();
+                }
+            "#}),
+            ..Default::default()
+        }
+    }
+
+    fn is_enabled(&self) -> bool {
+        false
+    }
+}
+
+pub struct RenamedLintName;
+
+impl Lint for RenamedLintName {
+    fn allowed_name(&self) -> &'static str {
+        "renamed_lints"
+    }
+
+    fn id(&self) -> &'static str {
+        "CL0050"
+    }
+
+    fn diagnostic_message(&self) -> &'static str {
+        "This lint has been renamed."
+    }
+
+    fn message_template(&self) -> &'static str {
+        "The lint `{old_name}` has been renamed to `{new_name}`."
+    }
+
+    fn kind(&self) -> CairoLintKind {
+        CairoLintKind::RenamedLintName
+    }
+
+    fn category(&self) -> LintCategory {
+        LintCategory::Style
+    }
+
+    fn docs(&self) -> LintDocs {
+        LintDocs {
+            description: "Checks for the old names of the renamed lint rules, used inside the lint level attributes. The old name still works as the new one.",
+            bad_example: Some(indoc! {r#"
+                // The `old_lint` lint rule was renamed to `new_lint`.
+                #[allow(old_lint)]
+                fn main() {}
+            "#}),
+            good_example: Some(indoc! {r#"
+                #[allow(new_lint)]
+                fn main() {}
+            "#}),
+            ..Default::default()
+        }
+    }
+
+    fn has_fixer(&self) -> bool {
+        true
+    }
+
+    fn fix(&self, db: &dyn SemanticGroup, node: SyntaxNode) -> Option<Suggestion> {
+        LintRegistry::default().fix_renamed_lint_name(db, node)
+    }
+}
+
+pub struct RemovedLintName;
+
+impl Lint for RemovedLintName {
+    fn allowed_name(&self) -> &'static str {
+        "removed_lints"
+    }
+
+    fn id(&self) -> &'static str {
+        "CL0051"
+    }
+
+    fn diagnostic_message(&self) -> &'static str {
+        "This lint has been removed."
+    }
+
+    fn message_template(&self) -> &'static str {
+        "The lint `{name}` has been removed: {reason}"
+    }
+
+    fn kind(&self) -> CairoLintKind {
+        CairoLintKind::RemovedLintName
+    }
+
+    fn category(&self) -> LintCategory {
+        LintCategory::Style
+    }
+
+    fn docs(&self) -> LintDocs {
+        LintDocs {
+            description: "Checks for the names of the removed lint rules, used inside the lint level attributes. They do not change the level of any lint rule.",
+            bad_example: Some(indoc! {r#"
+                // The `old_lint` lint rule was removed.
+                #[allow(old_lint)]
+                fn main() {}
+            "#}),
+            ..Default::default()
+        }
+    }
+}
@@ -1,6 +1,3 @@
-use cairo_lang_defs::ids::ModuleItemId;
-use cairo_lang_defs::plugin::PluginDiagnostic;
-use cairo_lang_diagnostics::Severity;
 use cairo_lang_semantic::db::SemanticGroup;
 use cairo_lang_semantic::{Arenas, Expr, ExprId, ExprLoop, Statement};
 use cairo_lang_syntax::node::db::SyntaxGroup;
@@ -9,47 +6,24 @@ use cairo_lang_syntax::node::{
     SyntaxNode, TypedStablePtr, TypedSyntaxNode,
 };
 use if_chain::if_chain;
+use indoc::indoc;
 
-use crate::context::{CairoLintKind, Lint};
+use crate::context::{CairoLintKind, CheckContext, Lint, LintCategory, LintDocs};
+use crate::diagnostics::LintDiagnostic;
+use crate::fixes::Suggestion;
 use crate::helper::{invert_condition, remove_break_from_block, remove_break_from_else_clause};
-use crate::queries::{get_all_function_bodies, get_all_loop_expressions};
 
 pub struct LoopForWhile;
 
-/// ## What it does
-///
-/// Checks for `loop` expressions that contain a conditional `if` statement with break inside that
-/// can be simplified to a `while` loop.
-///
-/// ## Example
-///
-/// ```cairo
-/// fn main() {
-///     let mut x: u16 = 0;
-///     loop {
-///         if x == 10 {
-///             break;
-///         }
-///         x += 1;
-///     }
-/// }
-/// ```
-///
-/// Can be simplified to:
-///
-/// ```cairo
-/// fn main() {
-///     let mut x: u16 = 0;
-///     while x != 10 {
-///         x += 1;
-///     }
-/// }
-/// ```
 impl Lint for LoopForWhile {
     fn allowed_name(&self) -> &'static str {
         "loop_for_while"
     }
 
+    fn id(&self) -> &'static str {
+        "CL0017"
+    }
+
     fn diagnostic_message(&self) -> &'static str {
         "you seem to be trying to use `loop`. Consider replacing this `loop` with a `while` \
                                   loop for clarity and conciseness"
@@ -59,12 +33,48 @@ impl Lint for LoopForWhile {
         CairoLintKind::LoopForWhile
     }
 
+    fn category(&self) -> LintCategory {
+        LintCategory::Style
+    }
+
+    fn docs(&self) -> LintDocs {
+        LintDocs {
+            description: "Checks for `loop` expressions that contain a conditional `if` statement with break inside that can be simplified to a `while` loop.",
+            bad_example: Some(indoc! {r#"
+                fn main() {
+                    let mut x: u16 = 0;
+                    loop {
+                        if x == 10 {
+                            break;
+                        }
+                        x += 1;
+                    }
+                }
+            "#}),
+            good_example: Some(indoc! {r#"
+                fn main() {
+                    let mut x: u16 = 0;
+                    while x != 10 {
+                        x += 1;
+                    }
+                }
+            "#}),
+            ..Default::default()
+        }
+    }
+
     fn has_fixer(&self) -> bool {
         true
     }
 
-    fn fix(&self, db: &dyn SemanticGroup, node: SyntaxNode) -> Option<(SyntaxNode, String)> {
-        fix_loop_break(db.upcast(), node)
+    fn fix(&self, db: &dyn SemanticGroup, node: SyntaxNode) -> Option<Suggestion> {
+        let (node, replacement) = fix_loop_break(db.upcast(), node)?;
+        Some(Suggestion::replace_node(
+            db.upcast(),
+            "Replace the `loop` with `while`",
+            node,
+            replacement,
+        ))
     }
 }
 
@@ -84,24 +94,11 @@ impl Lint for LoopForWhile {
 /// }
 /// ```
 pub fn check_loop_for_while(
-    db: &dyn SemanticGroup,
-    item: &ModuleItemId,
-    diagnostics: &mut Vec<PluginDiagnostic>,
-) {
-    let function_bodies = get_all_function_bodies(db, item);
-    for function_body in function_bodies.iter() {
-        let loop_exprs = get_all_loop_expressions(function_body);
-        let arenas = &function_body.arenas;
-        for loop_expr in loop_exprs.iter() {
-            check_single_loop_for_while(loop_expr, arenas, diagnostics);
-        }
-    }
-}
-
-fn check_single_loop_for_while(
+    _db: &dyn SemanticGroup,
     loop_expr: &ExprLoop,
     arenas: &Arenas,
-    diagnostics: &mut Vec<PluginDiagnostic>,
+    _context: &CheckContext,
+    diagnostics: &mut Vec<LintDiagnostic>,
 ) {
     // Get the else block  expression
     let Expr::Block(block_expr) = &arenas.exprs[loop_expr.body] else {
@@ -114,12 +111,7 @@ fn check_single_loop_for_while(
         if let Statement::Expr(ref expr_statement) = arenas.statements[*statement];
         if check_if_contains_break_with_no_return_value(&expr_statement.expr, arenas);
         then {
-            diagnostics.push(PluginDiagnostic {
-                stable_ptr: loop_expr.stable_ptr.untyped(),
-                message: LoopForWhile.diagnostic_message().to_string(),
-                severity: Severity::Warning,
-                relative_span: None
-            });
+            diagnostics.push(LintDiagnostic::new(&LoopForWhile, loop_expr.stable_ptr.untyped()));
         }
     }
 
@@ -131,12 +123,7 @@ fn check_single_loop_for_while(
         if let Some(tail_expr) = block_expr.tail;
         if check_if_contains_break_with_no_return_value(&tail_expr, arenas);
         then {
-            diagnostics.push(PluginDiagnostic {
-                stable_ptr: loop_expr.stable_ptr.untyped(),
-                message: LoopForWhile.diagnostic_message().to_string(),
-                severity: Severity::Warning,
-                relative_span: None
-            });
+            diagnostics.push(LintDiagnostic::new(&LoopForWhile, loop_expr.stable_ptr.untyped()));
         }
     }
 }
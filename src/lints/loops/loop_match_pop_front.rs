@@ -1,6 +1,4 @@
-use cairo_lang_defs::ids::{ModuleItemId, TopLevelLanguageElementId};
-use cairo_lang_defs::plugin::PluginDiagnostic;
-use cairo_lang_diagnostics::Severity;
+use cairo_lang_defs::ids::TopLevelLanguageElementId;
 use cairo_lang_semantic::db::SemanticGroup;
 use cairo_lang_semantic::{
     Arenas, Expr, ExprBlock, ExprId, ExprLoop, ExprMatch, Pattern, PatternEnumVariant, Statement,
@@ -15,45 +13,27 @@ use cairo_lang_syntax::node::{
     TypedStablePtr, TypedSyntaxNode,
 };
 use if_chain::if_chain;
+use indoc::indoc;
 
-use crate::context::{CairoLintKind, Lint};
+use crate::context::{CairoLintKind, CheckContext, Lint, LintCategory, LintDocs};
+use crate::diagnostics::LintDiagnostic;
+use crate::fixes::Suggestion;
 use crate::helper::indent_snippet;
 use crate::lints::{NONE, SOME};
-use crate::queries::{get_all_function_bodies, get_all_loop_expressions};
 
 const SPAN_MATCH_POP_FRONT: &str = "\"SpanImpl::pop_front\"";
 
 pub struct LoopMatchPopFront;
 
-/// ## What it does
-///
-/// Checks for loops that are used to iterate over a span using `pop_front`.
-///
-/// ## Example
-///
-/// ```cairo
-/// let a: Span<u32> = array![1, 2, 3].span();
-/// loop {
-///     match a.pop_front() {
-///         Option::Some(val) => {do_smth(val); },
-///         Option::None => { break; }
-///     }
-/// }
-/// ```
-///
-/// Which can be rewritten as
-///
-/// ```cairo
-/// let a: Span<u32> = array![1, 2, 3].span();
-/// for val in a {
-///     do_smth(val);
-/// }
-/// ```
 impl Lint for LoopMatchPopFront {
     fn allowed_name(&self) -> &'static str {
         "loop_match_pop_front"
     }
 
+    fn id(&self) -> &'static str {
+        "CL0014"
+    }
+
     fn diagnostic_message(&self) -> &'static str {
         "you seem to be trying to use `loop` for iterating over a span. Consider using `for in`"
     }
@@ -62,35 +42,53 @@ impl Lint for LoopMatchPopFront {
         CairoLintKind::LoopMatchPopFront
     }
 
+    fn category(&self) -> LintCategory {
+        LintCategory::Style
+    }
+
+    fn docs(&self) -> LintDocs {
+        LintDocs {
+            description: "Checks for loops that are used to iterate over a span using `pop_front`.",
+            bad_example: Some(indoc! {r#"
+                let a: Span<u32> = array![1, 2, 3].span();
+                loop {
+                    match a.pop_front() {
+                        Option::Some(val) => {do_smth(val); },
+                        Option::None => { break; }
+                    }
+                }
+            "#}),
+            good_example: Some(indoc! {r#"
+                let a: Span<u32> = array![1, 2, 3].span();
+                for val in a {
+                    do_smth(val);
+                }
+            "#}),
+            ..Default::default()
+        }
+    }
+
     fn has_fixer(&self) -> bool {
         true
     }
 
-    fn fix(&self, db: &dyn SemanticGroup, node: SyntaxNode) -> Option<(SyntaxNode, String)> {
-        fix_loop_match_pop_front(db.upcast(), node)
+    fn fix(&self, db: &dyn SemanticGroup, node: SyntaxNode) -> Option<Suggestion> {
+        let (node, replacement) = fix_loop_match_pop_front(db.upcast(), node)?;
+        Some(Suggestion::replace_node(
+            db.upcast(),
+            "Replace the `loop` with `for`",
+            node,
+            replacement,
+        ))
     }
 }
 
 pub fn check_loop_match_pop_front(
-    db: &dyn SemanticGroup,
-    item: &ModuleItemId,
-    diagnostics: &mut Vec<PluginDiagnostic>,
-) {
-    let function_bodies = get_all_function_bodies(db, item);
-    for function_body in function_bodies.iter() {
-        let loop_exprs = get_all_loop_expressions(function_body);
-        let arenas = &function_body.arenas;
-        for loop_expr in loop_exprs.iter() {
-            check_single_loop_match_pop_front(db, loop_expr, diagnostics, arenas);
-        }
-    }
-}
-
-fn check_single_loop_match_pop_front(
     db: &dyn SemanticGroup,
     loop_expr: &ExprLoop,
-    diagnostics: &mut Vec<PluginDiagnostic>,
     arenas: &Arenas,
+    _context: &CheckContext,
+    diagnostics: &mut Vec<LintDiagnostic>,
 ) {
     // Checks that the loop doesn't return anything
     if !loop_expr.ty.is_unit(db) {
@@ -113,12 +111,7 @@ fn check_single_loop_match_pop_front(
             if !check_single_match(db, expr_match, arenas) {
                 return;
             }
-            diagnostics.push(PluginDiagnostic {
-                stable_ptr: loop_expr.stable_ptr.into(),
-                message: LoopMatchPopFront.diagnostic_message().to_owned(),
-                severity: Severity::Warning,
-                relative_span: None
-            });
+            diagnostics.push(LintDiagnostic::new(&LoopMatchPopFront, loop_expr.stable_ptr.into()));
             return;
         }
     }
@@ -138,12 +131,7 @@ fn check_single_loop_match_pop_front(
                 return;
             };
             if func_call.function.name(db) == SPAN_MATCH_POP_FRONT {
-                diagnostics.push(PluginDiagnostic {
-                    stable_ptr: loop_expr.stable_ptr.into(),
-                    message: LoopMatchPopFront.diagnostic_message().to_owned(),
-                    severity: Severity::Warning,
-                    relative_span: None
-                })
+                diagnostics.push(LintDiagnostic::new(&LoopMatchPopFront, loop_expr.stable_ptr.into()))
             }
         }
     }
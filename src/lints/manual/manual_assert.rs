@@ -1,6 +1,4 @@
 use crate::helper::indent_snippet;
-use cairo_lang_defs::{ids::ModuleItemId, plugin::PluginDiagnostic};
-use cairo_lang_diagnostics::Severity;
 use cairo_lang_semantic::{db::SemanticGroup, Arenas, Expr, ExprBlock, ExprIf, Statement};
 use cairo_lang_syntax::node::{
     ast::{
@@ -13,44 +11,27 @@ use cairo_lang_syntax::node::{
 };
 use cairo_lang_utils::LookupIntern;
 use if_chain::if_chain;
+use indoc::indoc;
 use itertools::Itertools;
 
 use crate::{
-    context::{CairoLintKind, Lint},
+    context::{CairoLintKind, CheckContext, Lint, LintCategory, LintDocs},
+    diagnostics::LintDiagnostic,
+    fixes::Suggestion,
     helper::is_panic_expr,
-    queries::{get_all_function_bodies, get_all_if_expressions},
 };
 
 pub struct ManualAssert;
 
-/// ## What it does
-///
-/// Checks for manual implementations of `assert` macro in `if` expressions.
-///
-/// ## Example
-///
-/// ```cairo
-/// fn main() {
-///     let a = 5;
-///     if a == 5 {
-///         panic!("a shouldn't be equal to 5");
-///     }
-/// }
-/// ```
-///
-/// Can be rewritten as:
-///
-/// ```cairo
-/// fn main() {
-///     let a = 5;
-///     assert!(a != 5, "a shouldn't be equal to 5");
-/// }
-/// ```
 impl Lint for ManualAssert {
     fn allowed_name(&self) -> &'static str {
         "manual_assert"
     }
 
+    fn id(&self) -> &'static str {
+        "CL0045"
+    }
+
     fn diagnostic_message(&self) -> &'static str {
         "Manual assert detected. Consider using assert!() macro instead."
     }
@@ -59,35 +40,52 @@ impl Lint for ManualAssert {
         CairoLintKind::ManualAssert
     }
 
+    fn category(&self) -> LintCategory {
+        LintCategory::Style
+    }
+
+    fn docs(&self) -> LintDocs {
+        LintDocs {
+            description: "Checks for manual implementations of `assert` macro in `if` expressions.",
+            bad_example: Some(indoc! {r#"
+                fn main() {
+                    let a = 5;
+                    if a == 5 {
+                        panic!("a shouldn't be equal to 5");
+                    }
+                }
+            "#}),
+            good_example: Some(indoc! {r#"
+                fn main() {
+                    let a = 5;
+                    assert!(a != 5, "a shouldn't be equal to 5");
+                }
+            "#}),
+            ..Default::default()
+        }
+    }
+
     fn has_fixer(&self) -> bool {
         true
     }
 
-    fn fix(&self, db: &dyn SemanticGroup, node: SyntaxNode) -> Option<(SyntaxNode, String)> {
-        fix_manual_assert(db.upcast(), node)
+    fn fix(&self, db: &dyn SemanticGroup, node: SyntaxNode) -> Option<Suggestion> {
+        let (node, replacement) = fix_manual_assert(db.upcast(), node)?;
+        Some(Suggestion::replace_node(
+            db.upcast(),
+            "Use the `assert!` macro",
+            node,
+            replacement,
+        ))
     }
 }
 
 pub fn check_manual_assert(
-    db: &dyn SemanticGroup,
-    item: &ModuleItemId,
-    diagnostics: &mut Vec<PluginDiagnostic>,
-) {
-    let function_bodies = get_all_function_bodies(db, item);
-    for function_body in function_bodies.iter() {
-        let if_exprs = get_all_if_expressions(function_body);
-        let arenas = &function_body.arenas;
-        for if_expr in if_exprs.iter() {
-            check_single_manual_assert(db, if_expr, arenas, diagnostics);
-        }
-    }
-}
-
-fn check_single_manual_assert(
     db: &dyn SemanticGroup,
     if_expr: &ExprIf,
     arenas: &Arenas,
-    diagnostics: &mut Vec<PluginDiagnostic>,
+    _context: &CheckContext,
+    diagnostics: &mut Vec<LintDiagnostic>,
 ) {
     let Expr::Block(ref if_block) = arenas.exprs[if_expr.if_block] else {
         return;
@@ -109,7 +107,7 @@ fn check_single_condition_block(
     condition_block_expr: &ExprBlock,
     if_expr: &ExprIf,
     arenas: &Arenas,
-    diagnostics: &mut Vec<PluginDiagnostic>,
+    diagnostics: &mut Vec<LintDiagnostic>,
 ) {
     // Without tail.
     if_chain! {
@@ -117,12 +115,7 @@ fn check_single_condition_block(
         if let Statement::Expr(ref inner_expr_stmt) = arenas.statements[condition_block_expr.statements[0]];
         if is_panic_expr(db, arenas, inner_expr_stmt.expr);
         then {
-            diagnostics.push(PluginDiagnostic {
-                stable_ptr: if_expr.stable_ptr.untyped(),
-                message: ManualAssert.diagnostic_message().to_string(),
-                severity: Severity::Warning,
-                relative_span: None
-            });
+            diagnostics.push(LintDiagnostic::new(&ManualAssert, if_expr.stable_ptr.untyped()));
             return;
         }
     }
@@ -133,12 +126,7 @@ fn check_single_condition_block(
         if let Some(expr_id) = condition_block_expr.tail;
         if is_panic_expr(db, arenas, expr_id);
         then {
-            diagnostics.push(PluginDiagnostic {
-                stable_ptr: if_expr.stable_ptr.untyped(),
-                message: ManualAssert.diagnostic_message().to_string(),
-                severity: Severity::Warning,
-                relative_span: None
-            });
+            diagnostics.push(LintDiagnostic::new(&ManualAssert, if_expr.stable_ptr.untyped()));
         }
     }
 }
@@ -1,20 +1,18 @@
-use cairo_lang_defs::ids::ModuleItemId;
-use cairo_lang_defs::plugin::PluginDiagnostic;
-use cairo_lang_diagnostics::Severity;
 use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_semantic::{Arenas, ExprIf, ExprMatch};
 use cairo_lang_syntax::node::{
-    ast::{ExprIf, ExprMatch},
+    ast::{ExprIf as AstExprIf, ExprMatch as AstExprMatch},
     db::SyntaxGroup,
     kind::SyntaxKind,
     SyntaxNode, TypedStablePtr, TypedSyntaxNode,
 };
+use indoc::indoc;
 
-use crate::{
-    context::CairoLintKind,
-    queries::{get_all_function_bodies, get_all_if_expressions, get_all_match_expressions},
-};
+use crate::context::{CairoLintKind, CheckContext, LintCategory, LintDocs};
+use crate::diagnostics::LintDiagnostic;
 use crate::{
     context::Lint,
+    fixes::Suggestion,
     lints::manual::{
         check_manual, check_manual_if,
         helpers::{expr_if_get_var_name_and_err, expr_match_get_var_name_and_err},
@@ -24,37 +22,15 @@ use crate::{
 
 pub struct ManualExpectErr;
 
-/// ## What it does
-///
-/// Checks for manual implementation of `expect_err` method in match and if expressions.
-///
-/// ## Example
-///
-/// ```cairo
-/// fn main() {
-///     let foo: Result<i32> = Result::Err('err');
-///     let err = 'this is an err';
-///     let _foo = match foo {
-///         Result::Ok(_) => core::panic_with_felt252(err),
-///         Result::Err(x) => x,
-///     };
-/// }
-/// ```
-///
-/// Can be rewritten as:
-///
-/// ```cairo
-/// fn main() {
-///     let foo: Result<i32> = Result::Err('err');
-///     let err = 'this is an err';
-///     let _foo = foo.expect_err(err);
-/// }
-/// ```
 impl Lint for ManualExpectErr {
     fn allowed_name(&self) -> &'static str {
         "manual_expect_err"
     }
 
+    fn id(&self) -> &'static str {
+        "CL0029"
+    }
+
     fn diagnostic_message(&self) -> &'static str {
         "Manual match for `expect_err` detected. Consider using `expect_err()` instead"
     }
@@ -63,45 +39,76 @@ impl Lint for ManualExpectErr {
         CairoLintKind::ManualExpectErr
     }
 
+    fn category(&self) -> LintCategory {
+        LintCategory::Style
+    }
+
+    fn docs(&self) -> LintDocs {
+        LintDocs {
+            description: "Checks for manual implementation of `expect_err` method in match and if expressions.",
+            bad_example: Some(indoc! {r#"
+                fn main() {
+                    let foo: Result<i32> = Result::Err('err');
+                    let err = 'this is an err';
+                    let _foo = match foo {
+                        Result::Ok(_) => core::panic_with_felt252(err),
+                        Result::Err(x) => x,
+                    };
+                }
+            "#}),
+            good_example: Some(indoc! {r#"
+                fn main() {
+                    let foo: Result<i32> = Result::Err('err');
+                    let err = 'this is an err';
+                    let _foo = foo.expect_err(err);
+                }
+            "#}),
+            ..Default::default()
+        }
+    }
+
     fn has_fixer(&self) -> bool {
         true
     }
 
-    fn fix(&self, db: &dyn SemanticGroup, node: SyntaxNode) -> Option<(SyntaxNode, String)> {
-        fix_manual_expect_err(db.upcast(), node)
+    fn fix(&self, db: &dyn SemanticGroup, node: SyntaxNode) -> Option<Suggestion> {
+        let (node, replacement) = fix_manual_expect_err(db.upcast(), node)?;
+        Some(Suggestion::replace_node(
+            db.upcast(),
+            "Use `expect_err()`",
+            node,
+            replacement,
+        ))
     }
 }
 
 pub fn check_manual_expect_err(
     db: &dyn SemanticGroup,
-    item: &ModuleItemId,
-    diagnostics: &mut Vec<PluginDiagnostic>,
+    match_expr: &ExprMatch,
+    arenas: &Arenas,
+    _context: &CheckContext,
+    diagnostics: &mut Vec<LintDiagnostic>,
 ) {
-    let function_bodies = get_all_function_bodies(db, item);
-    for function_body in function_bodies.iter() {
-        let if_exprs = get_all_if_expressions(function_body);
-        let match_exprs = get_all_match_expressions(function_body);
-        let arenas = &function_body.arenas;
-        for match_expr in match_exprs.iter() {
-            if check_manual(db, match_expr, arenas, ManualLint::ManualExpectErr) {
-                diagnostics.push(PluginDiagnostic {
-                    stable_ptr: match_expr.stable_ptr.untyped(),
-                    message: ManualExpectErr.diagnostic_message().to_owned(),
-                    severity: Severity::Warning,
-                    relative_span: None,
-                });
-            }
-        }
-        for if_expr in if_exprs.iter() {
-            if check_manual_if(db, if_expr, arenas, ManualLint::ManualExpectErr) {
-                diagnostics.push(PluginDiagnostic {
-                    stable_ptr: if_expr.stable_ptr.untyped(),
-                    message: ManualExpectErr.diagnostic_message().to_owned(),
-                    severity: Severity::Warning,
-                    relative_span: None,
-                });
-            }
-        }
+    if check_manual(db, match_expr, arenas, ManualLint::ManualExpectErr) {
+        diagnostics.push(LintDiagnostic::new(
+            &ManualExpectErr,
+            match_expr.stable_ptr.untyped(),
+        ));
+    }
+}
+
+pub fn check_manual_expect_err_if(
+    db: &dyn SemanticGroup,
+    if_expr: &ExprIf,
+    arenas: &Arenas,
+    _context: &CheckContext,
+    diagnostics: &mut Vec<LintDiagnostic>,
+) {
+    if check_manual_if(db, if_expr, arenas, ManualLint::ManualExpectErr) {
+        diagnostics.push(LintDiagnostic::new(
+            &ManualExpectErr,
+            if_expr.stable_ptr.untyped(),
+        ));
     }
 }
 
@@ -112,7 +119,7 @@ pub fn fix_manual_expect_err(
 ) -> Option<(SyntaxNode, String)> {
     let fix = match node.kind(db) {
         SyntaxKind::ExprMatch => {
-            let expr_match = ExprMatch::from_syntax_node(db, node);
+            let expr_match = AstExprMatch::from_syntax_node(db, node);
 
             let (option_var_name, none_arm_err) =
                 expr_match_get_var_name_and_err(expr_match, db, 0);
@@ -120,7 +127,7 @@ pub fn fix_manual_expect_err(
             format!("{}.expect_err({none_arm_err})", option_var_name.trim_end())
         }
         SyntaxKind::ExprIf => {
-            let expr_if = ExprIf::from_syntax_node(db, node);
+            let expr_if = AstExprIf::from_syntax_node(db, node);
 
             let (option_var_name, err) = expr_if_get_var_name_and_err(expr_if, db);
 
@@ -1,47 +1,27 @@
-use cairo_lang_defs::ids::ModuleItemId;
-use cairo_lang_defs::plugin::PluginDiagnostic;
-use cairo_lang_diagnostics::Severity;
 use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_semantic::{Arenas, ExprIf, ExprMatch};
 use cairo_lang_syntax::node::db::SyntaxGroup;
 use cairo_lang_syntax::node::{SyntaxNode, TypedStablePtr};
+use indoc::indoc;
 
-use crate::context::{CairoLintKind, Lint};
+use crate::context::{CairoLintKind, CheckContext, Lint, LintCategory, LintDocs};
+use crate::diagnostics::LintDiagnostic;
+use crate::fixes::Suggestion;
 use crate::lints::manual::{check_manual, check_manual_if, ManualLint};
-use crate::queries::{get_all_function_bodies, get_all_if_expressions, get_all_match_expressions};
 
 use super::helpers::fix_manual;
 
 pub struct ManualIsSome;
 
-/// ## What it does
-///
-/// Checks for manual implementations of `is_some`.
-///
-/// ## Example
-///
-/// ```cairo
-/// fn main() {
-///     let foo: Option<i32> = Option::None;
-///     let _foo = match foo {
-///         Option::Some(_) => true,
-///         Option::None => false,
-///     };
-/// }
-/// ```
-///
-/// Can be rewritten as:
-///
-/// ```cairo
-/// fn main() {
-///     let foo: Option<i32> = Option::None;
-///     let _foo = foo.is_some();
-/// }
-/// ```
 impl Lint for ManualIsSome {
     fn allowed_name(&self) -> &'static str {
         "manual_is_some"
     }
 
+    fn id(&self) -> &'static str {
+        "CL0023"
+    }
+
     fn diagnostic_message(&self) -> &'static str {
         "Manual match for `is_some` detected. Consider using `is_some()` instead"
     }
@@ -50,46 +30,58 @@ impl Lint for ManualIsSome {
         CairoLintKind::ManualIsSome
     }
 
+    fn category(&self) -> LintCategory {
+        LintCategory::Style
+    }
+
+    fn docs(&self) -> LintDocs {
+        LintDocs {
+            description: "Checks for manual implementations of `is_some`.",
+            bad_example: Some(indoc! {r#"
+                fn main() {
+                    let foo: Option<i32> = Option::None;
+                    let _foo = match foo {
+                        Option::Some(_) => true,
+                        Option::None => false,
+                    };
+                }
+            "#}),
+            good_example: Some(indoc! {r#"
+                fn main() {
+                    let foo: Option<i32> = Option::None;
+                    let _foo = foo.is_some();
+                }
+            "#}),
+            ..Default::default()
+        }
+    }
+
     fn has_fixer(&self) -> bool {
         true
     }
 
-    fn fix(&self, db: &dyn SemanticGroup, node: SyntaxNode) -> Option<(SyntaxNode, String)> {
-        fix_manual_is_some(db.upcast(), node)
+    fn fix(&self, db: &dyn SemanticGroup, node: SyntaxNode) -> Option<Suggestion> {
+        let (node, replacement) = fix_manual_is_some(db.upcast(), node)?;
+        Some(Suggestion::replace_node(
+            db.upcast(),
+            "Use `is_some()`",
+            node,
+            replacement,
+        ))
     }
 }
 
 pub struct ManualIsNone;
 
-/// ## What it does
-///
-/// Checks for manual implementations of `is_none`.
-///
-/// ## Example
-///
-/// ```cairo
-/// fn main() {
-///     let foo: Option<i32> = Option::None;
-///     let _foo = match foo {
-///         Option::Some(_) => false,
-///         Option::None => true,
-///     };
-/// }
-/// ```
-///
-/// Can be rewritten as:
-///
-/// ```cairo
-/// fn main() {
-///     let foo: Option<i32> = Option::None;
-///     let _foo = foo.is_none();
-/// }
-/// ```
 impl Lint for ManualIsNone {
     fn allowed_name(&self) -> &'static str {
         "manual_is_none"
     }
 
+    fn id(&self) -> &'static str {
+        "CL0024"
+    }
+
     fn diagnostic_message(&self) -> &'static str {
         "Manual match for `is_none` detected. Consider using `is_none()` instead"
     }
@@ -98,46 +90,58 @@ impl Lint for ManualIsNone {
         CairoLintKind::ManualIsNone
     }
 
+    fn category(&self) -> LintCategory {
+        LintCategory::Style
+    }
+
+    fn docs(&self) -> LintDocs {
+        LintDocs {
+            description: "Checks for manual implementations of `is_none`.",
+            bad_example: Some(indoc! {r#"
+                fn main() {
+                    let foo: Option<i32> = Option::None;
+                    let _foo = match foo {
+                        Option::Some(_) => false,
+                        Option::None => true,
+                    };
+                }
+            "#}),
+            good_example: Some(indoc! {r#"
+                fn main() {
+                    let foo: Option<i32> = Option::None;
+                    let _foo = foo.is_none();
+                }
+            "#}),
+            ..Default::default()
+        }
+    }
+
     fn has_fixer(&self) -> bool {
         true
     }
 
-    fn fix(&self, db: &dyn SemanticGroup, node: SyntaxNode) -> Option<(SyntaxNode, String)> {
-        fix_manual_is_none(db.upcast(), node)
+    fn fix(&self, db: &dyn SemanticGroup, node: SyntaxNode) -> Option<Suggestion> {
+        let (node, replacement) = fix_manual_is_none(db.upcast(), node)?;
+        Some(Suggestion::replace_node(
+            db.upcast(),
+            "Use `is_none()`",
+            node,
+            replacement,
+        ))
     }
 }
 
 pub struct ManualIsOk;
 
-/// ## What it does
-///
-/// Checks for manual implementations of `is_ok`.
-///
-/// ## Example
-///
-/// ```cairo
-/// fn main() {
-///     let res_val: Result<i32> = Result::Err('err');
-///     let _a = match res_val {
-///         Result::Ok(_) => true,
-///         Result::Err(_) => false
-///     };
-/// }
-/// ```
-///
-/// Can be rewritten as:
-///
-/// ```cairo
-/// fn main() {
-///     let res_val: Result<i32> = Result::Err('err');
-///     let _a = res_val.is_ok();
-/// }
-/// ```
 impl Lint for ManualIsOk {
     fn allowed_name(&self) -> &'static str {
         "manual_is_ok"
     }
 
+    fn id(&self) -> &'static str {
+        "CL0025"
+    }
+
     fn diagnostic_message(&self) -> &'static str {
         "Manual match for `is_ok` detected. Consider using `is_ok()` instead"
     }
@@ -146,46 +150,58 @@ impl Lint for ManualIsOk {
         CairoLintKind::ManualIsOk
     }
 
+    fn category(&self) -> LintCategory {
+        LintCategory::Style
+    }
+
+    fn docs(&self) -> LintDocs {
+        LintDocs {
+            description: "Checks for manual implementations of `is_ok`.",
+            bad_example: Some(indoc! {r#"
+                fn main() {
+                    let res_val: Result<i32> = Result::Err('err');
+                    let _a = match res_val {
+                        Result::Ok(_) => true,
+                        Result::Err(_) => false
+                    };
+                }
+            "#}),
+            good_example: Some(indoc! {r#"
+                fn main() {
+                    let res_val: Result<i32> = Result::Err('err');
+                    let _a = res_val.is_ok();
+                }
+            "#}),
+            ..Default::default()
+        }
+    }
+
     fn has_fixer(&self) -> bool {
         true
     }
 
-    fn fix(&self, db: &dyn SemanticGroup, node: SyntaxNode) -> Option<(SyntaxNode, String)> {
-        fix_manual_is_ok(db.upcast(), node)
+    fn fix(&self, db: &dyn SemanticGroup, node: SyntaxNode) -> Option<Suggestion> {
+        let (node, replacement) = fix_manual_is_ok(db.upcast(), node)?;
+        Some(Suggestion::replace_node(
+            db.upcast(),
+            "Use `is_ok()`",
+            node,
+            replacement,
+        ))
     }
 }
 
 pub struct ManualIsErr;
 
-/// ## What it does
-///
-/// Checks for manual implementations of `is_err`.
-///
-/// ## Example
-///
-/// ```cairo
-/// fn main() {
-///     let res_val: Result<i32> = Result::Err('err');
-///     let _a = match res_val {
-///         Result::Ok(_) => false,
-///         Result::Err(_) => true
-///     };
-/// }
-/// ```
-///
-/// Can be rewritten as:
-///
-/// ```cairo
-/// fn main() {
-///     let res_val: Result<i32> = Result::Err('err');
-///     let _a = res_val.is_err();
-/// }
-/// ```
 impl Lint for ManualIsErr {
     fn allowed_name(&self) -> &'static str {
         "manual_is_err"
     }
 
+    fn id(&self) -> &'static str {
+        "CL0026"
+    }
+
     fn diagnostic_message(&self) -> &'static str {
         "Manual match for `is_err` detected. Consider using `is_err()` instead"
     }
@@ -194,93 +210,110 @@ impl Lint for ManualIsErr {
         CairoLintKind::ManualIsErr
     }
 
+    fn category(&self) -> LintCategory {
+        LintCategory::Style
+    }
+
+    fn docs(&self) -> LintDocs {
+        LintDocs {
+            description: "Checks for manual implementations of `is_err`.",
+            bad_example: Some(indoc! {r#"
+                fn main() {
+                    let res_val: Result<i32> = Result::Err('err');
+                    let _a = match res_val {
+                        Result::Ok(_) => false,
+                        Result::Err(_) => true
+                    };
+                }
+            "#}),
+            good_example: Some(indoc! {r#"
+                fn main() {
+                    let res_val: Result<i32> = Result::Err('err');
+                    let _a = res_val.is_err();
+                }
+            "#}),
+            ..Default::default()
+        }
+    }
+
     fn has_fixer(&self) -> bool {
         true
     }
 
-    fn fix(&self, db: &dyn SemanticGroup, node: SyntaxNode) -> Option<(SyntaxNode, String)> {
-        fix_manual_is_err(db.upcast(), node)
+    fn fix(&self, db: &dyn SemanticGroup, node: SyntaxNode) -> Option<Suggestion> {
+        let (node, replacement) = fix_manual_is_err(db.upcast(), node)?;
+        Some(Suggestion::replace_node(
+            db.upcast(),
+            "Use `is_err()`",
+            node,
+            replacement,
+        ))
     }
 }
 
 pub fn check_manual_is(
     db: &dyn SemanticGroup,
-    item: &ModuleItemId,
-    diagnostics: &mut Vec<PluginDiagnostic>,
+    match_expr: &ExprMatch,
+    arenas: &Arenas,
+    _context: &CheckContext,
+    diagnostics: &mut Vec<LintDiagnostic>,
 ) {
-    let function_bodies = get_all_function_bodies(db, item);
-    for function_body in function_bodies.iter() {
-        let if_exprs = get_all_if_expressions(function_body);
-        let match_exprs = get_all_match_expressions(function_body);
-        let arenas = &function_body.arenas;
-        for match_expr in match_exprs.iter() {
-            if check_manual(db, match_expr, arenas, ManualLint::ManualIsSome) {
-                diagnostics.push(PluginDiagnostic {
-                    stable_ptr: match_expr.stable_ptr.untyped(),
-                    message: ManualIsSome.diagnostic_message().to_owned(),
-                    severity: Severity::Warning,
-                    relative_span: None,
-                });
-            }
-            if check_manual(db, match_expr, arenas, ManualLint::ManualIsNone) {
-                diagnostics.push(PluginDiagnostic {
-                    stable_ptr: match_expr.stable_ptr.untyped(),
-                    message: ManualIsNone.diagnostic_message().to_owned(),
-                    severity: Severity::Warning,
-                    relative_span: None,
-                });
-            }
-            if check_manual(db, match_expr, arenas, ManualLint::ManualIsOk) {
-                diagnostics.push(PluginDiagnostic {
-                    stable_ptr: match_expr.stable_ptr.untyped(),
-                    message: ManualIsOk.diagnostic_message().to_owned(),
-                    severity: Severity::Warning,
-                    relative_span: None,
-                });
-            }
-            if check_manual(db, match_expr, arenas, ManualLint::ManualIsErr) {
-                diagnostics.push(PluginDiagnostic {
-                    stable_ptr: match_expr.stable_ptr.untyped(),
-                    message: ManualIsErr.diagnostic_message().to_owned(),
-                    severity: Severity::Warning,
-                    relative_span: None,
-                });
-            }
-        }
-        for if_expr in if_exprs.iter() {
-            if check_manual_if(db, if_expr, arenas, ManualLint::ManualIsSome) {
-                diagnostics.push(PluginDiagnostic {
-                    stable_ptr: if_expr.stable_ptr.untyped(),
-                    message: ManualIsSome.diagnostic_message().to_owned(),
-                    severity: Severity::Warning,
-                    relative_span: None,
-                });
-            }
-            if check_manual_if(db, if_expr, arenas, ManualLint::ManualIsNone) {
-                diagnostics.push(PluginDiagnostic {
-                    stable_ptr: if_expr.stable_ptr.untyped(),
-                    message: ManualIsNone.diagnostic_message().to_owned(),
-                    severity: Severity::Warning,
-                    relative_span: None,
-                });
-            }
-            if check_manual_if(db, if_expr, arenas, ManualLint::ManualIsOk) {
-                diagnostics.push(PluginDiagnostic {
-                    stable_ptr: if_expr.stable_ptr.untyped(),
-                    message: ManualIsOk.diagnostic_message().to_owned(),
-                    severity: Severity::Warning,
-                    relative_span: None,
-                });
-            }
-            if check_manual_if(db, if_expr, arenas, ManualLint::ManualIsErr) {
-                diagnostics.push(PluginDiagnostic {
-                    stable_ptr: if_expr.stable_ptr.untyped(),
-                    message: ManualIsErr.diagnostic_message().to_owned(),
-                    severity: Severity::Warning,
-                    relative_span: None,
-                });
-            }
-        }
+    if check_manual(db, match_expr, arenas, ManualLint::ManualIsSome) {
+        diagnostics.push(LintDiagnostic::new(
+            &ManualIsSome,
+            match_expr.stable_ptr.untyped(),
+        ));
+    }
+    if check_manual(db, match_expr, arenas, ManualLint::ManualIsNone) {
+        diagnostics.push(LintDiagnostic::new(
+            &ManualIsNone,
+            match_expr.stable_ptr.untyped(),
+        ));
+    }
+    if check_manual(db, match_expr, arenas, ManualLint::ManualIsOk) {
+        diagnostics.push(LintDiagnostic::new(
+            &ManualIsOk,
+            match_expr.stable_ptr.untyped(),
+        ));
+    }
+    if check_manual(db, match_expr, arenas, ManualLint::ManualIsErr) {
+        diagnostics.push(LintDiagnostic::new(
+            &ManualIsErr,
+            match_expr.stable_ptr.untyped(),
+        ));
+    }
+}
+
+pub fn check_manual_is_if(
+    db: &dyn SemanticGroup,
+    if_expr: &ExprIf,
+    arenas: &Arenas,
+    _context: &CheckContext,
+    diagnostics: &mut Vec<LintDiagnostic>,
+) {
+    if check_manual_if(db, if_expr, arenas, ManualLint::ManualIsSome) {
+        diagnostics.push(LintDiagnostic::new(
+            &ManualIsSome,
+            if_expr.stable_ptr.untyped(),
+        ));
+    }
+    if check_manual_if(db, if_expr, arenas, ManualLint::ManualIsNone) {
+        diagnostics.push(LintDiagnostic::new(
+            &ManualIsNone,
+            if_expr.stable_ptr.untyped(),
+        ));
+    }
+    if check_manual_if(db, if_expr, arenas, ManualLint::ManualIsOk) {
+        diagnostics.push(LintDiagnostic::new(
+            &ManualIsOk,
+            if_expr.stable_ptr.untyped(),
+        ));
+    }
+    if check_manual_if(db, if_expr, arenas, ManualLint::ManualIsErr) {
+        diagnostics.push(LintDiagnostic::new(
+            &ManualIsErr,
+            if_expr.stable_ptr.untyped(),
+        ));
     }
 }
 
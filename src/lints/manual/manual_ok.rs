@@ -1,47 +1,27 @@
-use cairo_lang_defs::ids::ModuleItemId;
-use cairo_lang_defs::plugin::PluginDiagnostic;
-use cairo_lang_diagnostics::Severity;
 use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_semantic::{Arenas, ExprIf, ExprMatch};
 use cairo_lang_syntax::node::db::SyntaxGroup;
 use cairo_lang_syntax::node::{SyntaxNode, TypedStablePtr};
+use indoc::indoc;
 
-use crate::context::{CairoLintKind, Lint};
+use crate::context::{CairoLintKind, CheckContext, Lint, LintCategory, LintDocs};
+use crate::diagnostics::LintDiagnostic;
+use crate::fixes::Suggestion;
 use crate::lints::manual::{check_manual, check_manual_if, ManualLint};
-use crate::queries::{get_all_function_bodies, get_all_if_expressions, get_all_match_expressions};
 
 use super::helpers::fix_manual;
 
 pub struct ManualOk;
 
-/// ## What it does
-///
-/// Checks for manual implementation of `ok` method in match and if expressions.
-///
-/// ## Example
-///
-/// ```cairo
-/// fn main() {
-///     let res_val: Result<i32> = Result::Err('err');
-///     let _a = match res_val {
-///         Result::Ok(x) => Option::Some(x),
-///         Result::Err(_) => Option::None,
-///     };
-/// }
-/// ```
-///
-/// Can be replaced with:
-///
-/// ```cairo
-// fn main() {
-///     let res_val: Result<i32> = Result::Err('err');
-///     let _a = res_val.ok();
-/// }
-/// ```
 impl Lint for ManualOk {
     fn allowed_name(&self) -> &'static str {
         "manual_ok"
     }
 
+    fn id(&self) -> &'static str {
+        "CL0021"
+    }
+
     fn diagnostic_message(&self) -> &'static str {
         "Manual match for `ok` detected. Consider using `ok()` instead"
     }
@@ -50,45 +30,72 @@ impl Lint for ManualOk {
         CairoLintKind::ManualOk
     }
 
+    fn category(&self) -> LintCategory {
+        LintCategory::Style
+    }
+
+    fn docs(&self) -> LintDocs {
+        LintDocs {
+            description:
+                "Checks for manual implementation of `ok` method in match and if expressions.",
+            bad_example: Some(indoc! {r#"
+                fn main() {
+                    let res_val: Result<i32> = Result::Err('err');
+                    let _a = match res_val {
+                        Result::Ok(x) => Option::Some(x),
+                        Result::Err(_) => Option::None,
+                    };
+                }
+            "#}),
+            good_example: Some(indoc! {r#"
+                fn main() {
+                    let res_val: Result<i32> = Result::Err('err');
+                    let _a = res_val.ok();
+                }
+            "#}),
+            ..Default::default()
+        }
+    }
+
     fn has_fixer(&self) -> bool {
         true
     }
 
-    fn fix(&self, db: &dyn SemanticGroup, node: SyntaxNode) -> Option<(SyntaxNode, String)> {
-        fix_manual_ok(db.upcast(), node)
+    fn fix(&self, db: &dyn SemanticGroup, node: SyntaxNode) -> Option<Suggestion> {
+        let (node, replacement) = fix_manual_ok(db.upcast(), node)?;
+        Some(Suggestion::replace_node(
+            db.upcast(),
+            "Use `ok()`",
+            node,
+            replacement,
+        ))
     }
 }
 
 pub fn check_manual_ok(
     db: &dyn SemanticGroup,
-    item: &ModuleItemId,
-    diagnostics: &mut Vec<PluginDiagnostic>,
+    match_expr: &ExprMatch,
+    arenas: &Arenas,
+    _context: &CheckContext,
+    diagnostics: &mut Vec<LintDiagnostic>,
 ) {
-    let function_bodies = get_all_function_bodies(db, item);
-    for function_body in function_bodies.iter() {
-        let if_exprs = get_all_if_expressions(function_body);
-        let match_exprs = get_all_match_expressions(function_body);
-        let arenas = &function_body.arenas;
-        for match_expr in match_exprs.iter() {
-            if check_manual(db, match_expr, arenas, ManualLint::ManualOk) {
-                diagnostics.push(PluginDiagnostic {
-                    stable_ptr: match_expr.stable_ptr.untyped(),
-                    message: ManualOk.diagnostic_message().to_owned(),
-                    severity: Severity::Warning,
-                    relative_span: None,
-                });
-            }
-        }
-        for if_expr in if_exprs.iter() {
-            if check_manual_if(db, if_expr, arenas, ManualLint::ManualOk) {
-                diagnostics.push(PluginDiagnostic {
-                    stable_ptr: if_expr.stable_ptr.untyped(),
-                    message: ManualOk.diagnostic_message().to_owned(),
-                    severity: Severity::Warning,
-                    relative_span: None,
-                });
-            }
-        }
+    if check_manual(db, match_expr, arenas, ManualLint::ManualOk) {
+        diagnostics.push(LintDiagnostic::new(
+            &ManualOk,
+            match_expr.stable_ptr.untyped(),
+        ));
+    }
+}
+
+pub fn check_manual_ok_if(
+    db: &dyn SemanticGroup,
+    if_expr: &ExprIf,
+    arenas: &Arenas,
+    _context: &CheckContext,
+    diagnostics: &mut Vec<LintDiagnostic>,
+) {
+    if check_manual_if(db, if_expr, arenas, ManualLint::ManualOk) {
+        diagnostics.push(LintDiagnostic::new(&ManualOk, if_expr.stable_ptr.untyped()));
     }
 }
 
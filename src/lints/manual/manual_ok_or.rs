@@ -1,20 +1,18 @@
-use cairo_lang_defs::ids::ModuleItemId;
-use cairo_lang_defs::plugin::PluginDiagnostic;
-use cairo_lang_diagnostics::Severity;
 use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_semantic::{Arenas, ExprIf, ExprMatch};
 use cairo_lang_syntax::node::{
-    ast::{ExprIf, ExprMatch},
+    ast::{ExprIf as AstExprIf, ExprMatch as AstExprMatch},
     db::SyntaxGroup,
     kind::SyntaxKind,
     SyntaxNode, TypedStablePtr, TypedSyntaxNode,
 };
+use indoc::indoc;
 
-use crate::{
-    context::CairoLintKind,
-    queries::{get_all_function_bodies, get_all_if_expressions, get_all_match_expressions},
-};
+use crate::context::{CairoLintKind, CheckContext, LintCategory, LintDocs};
+use crate::diagnostics::LintDiagnostic;
 use crate::{
     context::Lint,
+    fixes::Suggestion,
     lints::manual::{
         check_manual, check_manual_if,
         helpers::{expr_if_get_var_name_and_err, expr_match_get_var_name_and_err},
@@ -24,35 +22,15 @@ use crate::{
 
 pub struct ManualOkOr;
 
-/// ## What it does
-///
-/// Checks for manual implementations of ok_or.
-///
-/// ## Example
-///
-/// ```cairo
-/// fn main() {
-///     let foo: Option<i32> = Option::None;
-///     let _foo = match foo {
-///         Option::Some(v) => Result::Ok(v),
-///         Option::None => Result::Err('this is an err'),
-///     };
-/// }
-/// ```
-///
-/// Can be rewritten as:
-///
-/// ```cairo
-/// fn main() {
-///     let foo: Option<i32> = Option::None;
-///     let _foo = foo.ok_or('this is an err');
-/// }
-/// ```
 impl Lint for ManualOkOr {
     fn allowed_name(&self) -> &'static str {
         "manual_ok_or"
     }
 
+    fn id(&self) -> &'static str {
+        "CL0020"
+    }
+
     fn diagnostic_message(&self) -> &'static str {
         "Manual match for Option<T> detected. Consider using ok_or instead"
     }
@@ -61,45 +39,74 @@ impl Lint for ManualOkOr {
         CairoLintKind::ManualOkOr
     }
 
+    fn category(&self) -> LintCategory {
+        LintCategory::Style
+    }
+
+    fn docs(&self) -> LintDocs {
+        LintDocs {
+            description: "Checks for manual implementations of ok_or.",
+            bad_example: Some(indoc! {r#"
+                fn main() {
+                    let foo: Option<i32> = Option::None;
+                    let _foo = match foo {
+                        Option::Some(v) => Result::Ok(v),
+                        Option::None => Result::Err('this is an err'),
+                    };
+                }
+            "#}),
+            good_example: Some(indoc! {r#"
+                fn main() {
+                    let foo: Option<i32> = Option::None;
+                    let _foo = foo.ok_or('this is an err');
+                }
+            "#}),
+            ..Default::default()
+        }
+    }
+
     fn has_fixer(&self) -> bool {
         true
     }
 
-    fn fix(&self, db: &dyn SemanticGroup, node: SyntaxNode) -> Option<(SyntaxNode, String)> {
-        fix_manual_ok_or(db.upcast(), node)
+    fn fix(&self, db: &dyn SemanticGroup, node: SyntaxNode) -> Option<Suggestion> {
+        let (node, replacement) = fix_manual_ok_or(db.upcast(), node)?;
+        Some(Suggestion::replace_node(
+            db.upcast(),
+            "Use `ok_or()`",
+            node,
+            replacement,
+        ))
     }
 }
 
 pub fn check_manual_ok_or(
     db: &dyn SemanticGroup,
-    item: &ModuleItemId,
-    diagnostics: &mut Vec<PluginDiagnostic>,
+    match_expr: &ExprMatch,
+    arenas: &Arenas,
+    _context: &CheckContext,
+    diagnostics: &mut Vec<LintDiagnostic>,
 ) {
-    let function_bodies = get_all_function_bodies(db, item);
-    for function_body in function_bodies.iter() {
-        let if_exprs = get_all_if_expressions(function_body);
-        let match_exprs = get_all_match_expressions(function_body);
-        let arenas = &function_body.arenas;
-        for match_expr in match_exprs.iter() {
-            if check_manual(db, match_expr, arenas, ManualLint::ManualOkOr) {
-                diagnostics.push(PluginDiagnostic {
-                    stable_ptr: match_expr.stable_ptr.untyped(),
-                    message: ManualOkOr.diagnostic_message().to_owned(),
-                    severity: Severity::Warning,
-                    relative_span: None,
-                });
-            }
-        }
-        for if_expr in if_exprs.iter() {
-            if check_manual_if(db, if_expr, arenas, ManualLint::ManualOkOr) {
-                diagnostics.push(PluginDiagnostic {
-                    stable_ptr: if_expr.stable_ptr.untyped(),
-                    message: ManualOkOr.diagnostic_message().to_owned(),
-                    severity: Severity::Warning,
-                    relative_span: None,
-                });
-            }
-        }
+    if check_manual(db, match_expr, arenas, ManualLint::ManualOkOr) {
+        diagnostics.push(LintDiagnostic::new(
+            &ManualOkOr,
+            match_expr.stable_ptr.untyped(),
+        ));
+    }
+}
+
+pub fn check_manual_ok_or_if(
+    db: &dyn SemanticGroup,
+    if_expr: &ExprIf,
+    arenas: &Arenas,
+    _context: &CheckContext,
+    diagnostics: &mut Vec<LintDiagnostic>,
+) {
+    if check_manual_if(db, if_expr, arenas, ManualLint::ManualOkOr) {
+        diagnostics.push(LintDiagnostic::new(
+            &ManualOkOr,
+            if_expr.stable_ptr.untyped(),
+        ));
     }
 }
 
@@ -107,7 +114,7 @@ pub fn check_manual_ok_or(
 pub fn fix_manual_ok_or(db: &dyn SyntaxGroup, node: SyntaxNode) -> Option<(SyntaxNode, String)> {
     let fix = match node.kind(db) {
         SyntaxKind::ExprMatch => {
-            let expr_match = ExprMatch::from_syntax_node(db, node);
+            let expr_match = AstExprMatch::from_syntax_node(db, node);
 
             let (option_var_name, none_arm_err) =
                 expr_match_get_var_name_and_err(expr_match, db, 1);
@@ -115,7 +122,7 @@ pub fn fix_manual_ok_or(db: &dyn SyntaxGroup, node: SyntaxNode) -> Option<(Synta
             format!("{}.ok_or({none_arm_err})", option_var_name.trim_end())
         }
         SyntaxKind::ExprIf => {
-            let expr_if = ExprIf::from_syntax_node(db, node);
+            let expr_if = AstExprIf::from_syntax_node(db, node);
 
             let (option_var_name, err) = expr_if_get_var_name_and_err(expr_if, db);
 
@@ -1,101 +1,117 @@
-use cairo_lang_defs::ids::ModuleItemId;
-use cairo_lang_defs::plugin::PluginDiagnostic;
-use cairo_lang_diagnostics::Severity;
 use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_semantic::{Arenas, ExprIf, ExprMatch};
 use cairo_lang_syntax::node::{
     ast::{Condition, Expr},
     db::SyntaxGroup,
+    ids::SyntaxStablePtrId,
     SyntaxNode, TypedStablePtr, TypedSyntaxNode,
 };
+use indoc::indoc;
 
-use crate::{
-    context::CairoLintKind,
-    queries::{get_all_function_bodies, get_all_if_expressions, get_all_match_expressions},
-};
+use crate::context::{CairoLintKind, CheckContext, LintCategory, LintDocs};
+use crate::diagnostics::LintDiagnostic;
 use crate::{
     context::Lint,
+    fixes::Suggestion,
     lints::manual::{check_manual, check_manual_if, ManualLint},
 };
 
 pub struct ManualUnwrapOrDefault;
 
-/// ## What it does
-///
-/// Checks for manual unwrapping of an Option or Result.
-///
-/// ## Example
-///
-/// ```cairo
-/// fn main() {
-///     let x: Option<u128> = Option::Some(1038);
-///     if let Option::Some(v) = x {
-///         v
-///     } else {
-///         0
-///     };
-/// }
-/// ```
-///
-/// Can be simplified to:
-///
-/// ```cairo
-/// fn main() {
-///     let x: Option<u128> = Option::Some(1038);
-///     x.unwrap_or_default();
-/// }
-/// ```
 impl Lint for ManualUnwrapOrDefault {
     fn allowed_name(&self) -> &'static str {
         "manual_unwrap_or_default"
     }
 
+    fn id(&self) -> &'static str {
+        "CL0015"
+    }
+
     fn diagnostic_message(&self) -> &'static str {
         "This can be done in one call with `.unwrap_or_default()`"
     }
 
+    fn message_template(&self) -> &'static str {
+        "This can be done in one call with `{replacement}`"
+    }
+
     fn kind(&self) -> CairoLintKind {
         CairoLintKind::ManualUnwrapOrDefault
     }
 
+    fn category(&self) -> LintCategory {
+        LintCategory::Style
+    }
+
+    fn docs(&self) -> LintDocs {
+        LintDocs {
+            description: "Checks for manual unwrapping of an Option or Result.",
+            bad_example: Some(indoc! {r#"
+                fn main() {
+                    let x: Option<u128> = Option::Some(1038);
+                    if let Option::Some(v) = x {
+                        v
+                    } else {
+                        0
+                    };
+                }
+            "#}),
+            good_example: Some(indoc! {r#"
+                fn main() {
+                    let x: Option<u128> = Option::Some(1038);
+                    x.unwrap_or_default();
+                }
+            "#}),
+            ..Default::default()
+        }
+    }
+
     fn has_fixer(&self) -> bool {
         true
     }
 
-    fn fix(&self, db: &dyn SemanticGroup, node: SyntaxNode) -> Option<(SyntaxNode, String)> {
-        fix_manual_unwrap_or_default(db.upcast(), node)
+    fn fix(&self, db: &dyn SemanticGroup, node: SyntaxNode) -> Option<Suggestion> {
+        let (node, replacement) = fix_manual_unwrap_or_default(db.upcast(), node)?;
+        Some(Suggestion::replace_node(
+            db.upcast(),
+            "Use `unwrap_or_default()`",
+            node,
+            replacement,
+        ))
     }
 }
 
 pub fn check_manual_unwrap_or_default(
     db: &dyn SemanticGroup,
-    item: &ModuleItemId,
-    diagnostics: &mut Vec<PluginDiagnostic>,
+    match_expr: &ExprMatch,
+    arenas: &Arenas,
+    _context: &CheckContext,
+    diagnostics: &mut Vec<LintDiagnostic>,
 ) {
-    let function_bodies = get_all_function_bodies(db, item);
-    for function_body in function_bodies.iter() {
-        let if_exprs = get_all_if_expressions(function_body);
-        let match_exprs = get_all_match_expressions(function_body);
-        let arenas = &function_body.arenas;
-        for match_expr in match_exprs.iter() {
-            if check_manual(db, match_expr, arenas, ManualLint::ManualUnwrapOrDefault) {
-                diagnostics.push(PluginDiagnostic {
-                    stable_ptr: match_expr.stable_ptr.untyped(),
-                    message: ManualUnwrapOrDefault.diagnostic_message().to_owned(),
-                    severity: Severity::Warning,
-                    relative_span: None,
-                });
-            }
-        }
-        for if_expr in if_exprs.iter() {
-            if check_manual_if(db, if_expr, arenas, ManualLint::ManualUnwrapOrDefault) {
-                diagnostics.push(PluginDiagnostic {
-                    stable_ptr: if_expr.stable_ptr.untyped(),
-                    message: ManualUnwrapOrDefault.diagnostic_message().to_owned(),
-                    severity: Severity::Warning,
-                    relative_span: None,
-                });
-            }
-        }
+    if check_manual(db, match_expr, arenas, ManualLint::ManualUnwrapOrDefault) {
+        let replacement = get_replacement(db.upcast(), match_expr.stable_ptr.untyped());
+        diagnostics.push(LintDiagnostic::with_args(
+            &ManualUnwrapOrDefault,
+            match_expr.stable_ptr.untyped(),
+            &[("replacement", replacement.as_str())],
+        ));
+    }
+}
+
+pub fn check_manual_unwrap_or_default_if(
+    db: &dyn SemanticGroup,
+    if_expr: &ExprIf,
+    arenas: &Arenas,
+    _context: &CheckContext,
+    diagnostics: &mut Vec<LintDiagnostic>,
+) {
+    if check_manual_if(db, if_expr, arenas, ManualLint::ManualUnwrapOrDefault) {
+        let replacement = get_replacement(db.upcast(), if_expr.stable_ptr.untyped());
+        diagnostics.push(LintDiagnostic::with_args(
+            &ManualUnwrapOrDefault,
+            if_expr.stable_ptr.untyped(),
+            &[("replacement", replacement.as_str())],
+        ));
     }
 }
 
@@ -104,29 +120,7 @@ pub fn fix_manual_unwrap_or_default(
     db: &dyn SyntaxGroup,
     node: SyntaxNode,
 ) -> Option<(SyntaxNode, String)> {
-    // Check if the node is a general expression
-    let expr = Expr::from_syntax_node(db, node);
-
-    let matched_expr = match expr {
-        // Handle the case where the expression is a match expression
-        Expr::Match(expr_match) => expr_match.expr(db).as_syntax_node(),
-
-        // Handle the case where the expression is an if-let expression
-        Expr::If(expr_if) => {
-            // Extract the condition from the if-let expression
-            let condition = expr_if.condition(db);
-
-            match condition {
-                Condition::Let(condition_let) => {
-                    // Extract and return the syntax node for the matched expression
-                    condition_let.expr(db).as_syntax_node()
-                }
-                _ => panic!("Expected an `if let` expression."),
-            }
-        }
-        // Handle unsupported expressions
-        _ => panic!("The expression cannot be simplified to `.unwrap_or_default()`."),
-    };
+    let matched_expr = get_matched_expr(db, node);
 
     let indent = node
         .get_text(db)
@@ -151,3 +145,39 @@ pub fn fix_manual_unwrap_or_default(
         ),
     ))
 }
+
+/// Returns the call replacing the manual unwrap, e.g. `x.unwrap_or_default()`.
+fn get_replacement(db: &dyn SyntaxGroup, stable_ptr: SyntaxStablePtrId) -> String {
+    let matched_expr = get_matched_expr(db, stable_ptr.lookup(db));
+    format!(
+        "{}.unwrap_or_default()",
+        matched_expr.get_text_without_trivia(db)
+    )
+}
+
+/// Returns the expression matched by the `match` or the `if let` expression.
+fn get_matched_expr(db: &dyn SyntaxGroup, node: SyntaxNode) -> SyntaxNode {
+    // Check if the node is a general expression
+    let expr = Expr::from_syntax_node(db, node);
+
+    match expr {
+        // Handle the case where the expression is a match expression
+        Expr::Match(expr_match) => expr_match.expr(db).as_syntax_node(),
+
+        // Handle the case where the expression is an if-let expression
+        Expr::If(expr_if) => {
+            // Extract the condition from the if-let expression
+            let condition = expr_if.condition(db);
+
+            match condition {
+                Condition::Let(condition_let) => {
+                    // Extract and return the syntax node for the matched expression
+                    condition_let.expr(db).as_syntax_node()
+                }
+                _ => panic!("Expected an `if let` expression."),
+            }
+        }
+        // Handle unsupported expressions
+        _ => panic!("The expression cannot be simplified to `.unwrap_or_default()`."),
+    }
+}
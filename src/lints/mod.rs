@@ -5,6 +5,7 @@ use cairo_lang_semantic::FunctionId;
 pub mod bitwise_for_parity_check;
 pub mod bool_comparison;
 pub mod breaks;
+pub mod call_patterns;
 pub mod clone_on_copy;
 pub mod double_comparison;
 pub mod double_parens;
@@ -15,6 +16,7 @@ pub mod eq_op;
 pub mod erasing_op;
 pub mod ifs;
 pub mod int_op_one;
+pub mod lint_attributes;
 pub mod loops;
 pub mod manual;
 pub mod panic;
@@ -1,44 +1,54 @@
 use cairo_lang_defs::diagnostic_utils::StableLocation;
-use cairo_lang_defs::ids::ModuleItemId;
-use cairo_lang_defs::plugin::PluginDiagnostic;
-use cairo_lang_diagnostics::Severity;
 use cairo_lang_filesystem::db::get_originating_location;
 use cairo_lang_semantic::db::SemanticGroup;
-use cairo_lang_semantic::ExprFunctionCall;
+use cairo_lang_semantic::{Arenas, ExprFunctionCall};
 use cairo_lang_syntax::node::{TypedStablePtr, TypedSyntaxNode};
 use if_chain::if_chain;
-use itertools::Itertools;
+use indoc::indoc;
 
-use crate::context::{CairoLintKind, Lint};
-use crate::helper::{ASSERT_FORMATTER_NAME, PANIC_PATH, PANIC_WITH_BYTE_ARRAY_PATH};
-use crate::queries::{get_all_function_bodies, get_all_function_calls};
+use crate::context::{CairoLintKind, CheckContext, Lint, LintCategory, LintDocs};
+use crate::diagnostics::LintDiagnostic;
+use crate::helper::{ASSERT_FORMATTER_NAME, PANIC_WITH_BYTE_ARRAY_PATH};
 
 pub struct PanicInCode;
 
-/// ## What it does
-///
-/// Checks for panic usages.
-///
-/// ## Example
-///
-/// ```cairo
-/// fn main() {
-///     panic!("panic");
-/// }
-/// ```
 impl Lint for PanicInCode {
     fn allowed_name(&self) -> &'static str {
         "panic"
     }
 
+    fn id(&self) -> &'static str {
+        "CL0018"
+    }
+
     fn diagnostic_message(&self) -> &'static str {
         "Leaving `panic` in the code is discouraged."
     }
 
+    fn message_template(&self) -> &'static str {
+        "Leaving `{panic}` in the code is discouraged."
+    }
+
     fn kind(&self) -> CairoLintKind {
         CairoLintKind::Panic
     }
 
+    fn category(&self) -> LintCategory {
+        LintCategory::Pedantic
+    }
+
+    fn docs(&self) -> LintDocs {
+        LintDocs {
+            description: "Checks for panic usages.",
+            bad_example: Some(indoc! {r#"
+                fn main() {
+                    panic!("panic");
+                }
+            "#}),
+            ..Default::default()
+        }
+    }
+
     fn is_enabled(&self) -> bool {
         false
     }
@@ -46,35 +56,25 @@ impl Lint for PanicInCode {
 
 /// Checks for panic usage.
 pub fn check_panic_usage(
-    db: &dyn SemanticGroup,
-    item: &ModuleItemId,
-    diagnostics: &mut Vec<PluginDiagnostic>,
-) {
-    let function_bodies = get_all_function_bodies(db, item);
-    for function_body in function_bodies.iter() {
-        let function_call_exprs = get_all_function_calls(function_body);
-        for function_call_expr in function_call_exprs.unique() {
-            check_single_panic_usage(db, &function_call_expr, diagnostics);
-        }
-    }
-}
-
-fn check_single_panic_usage(
     db: &dyn SemanticGroup,
     function_call_expr: &ExprFunctionCall,
-    diagnostics: &mut Vec<PluginDiagnostic>,
+    _arenas: &Arenas,
+    context: &CheckContext,
+    diagnostics: &mut Vec<LintDiagnostic>,
 ) {
+    let panic_functions = &context.options().panic.functions;
     let init_node = function_call_expr
         .stable_ptr
         .lookup(db.upcast())
         .as_syntax_node();
 
-    // If the function is the panic function from the corelib.
-    let is_panic = function_call_expr.function.full_path(db) == PANIC_PATH
-        || function_call_expr.function.full_path(db) == PANIC_WITH_BYTE_ARRAY_PATH;
+    let function_path = function_call_expr.function.full_path(db);
+
+    // If the function is one of the panic functions, by default the ones from the corelib.
+    let is_panic = panic_functions.contains(&function_path);
 
     // We check if the panic comes from the `assert!` macro.
-    let is_assert_panic = function_call_expr.function.full_path(db) == PANIC_WITH_BYTE_ARRAY_PATH
+    let is_assert_panic = function_path == PANIC_WITH_BYTE_ARRAY_PATH
         && function_call_expr
             .stable_ptr
             .lookup(db.upcast())
@@ -102,12 +102,12 @@ fn check_single_panic_usage(
     );
     // If the panic comes from a real file (macros generate code in new virtual files)
     if initial_file_id == file_id {
-        diagnostics.push(PluginDiagnostic {
-            stable_ptr: init_node.stable_ptr(db.upcast()),
-            message: PanicInCode.diagnostic_message().to_owned(),
-            severity: Severity::Warning,
-            relative_span: None,
-        });
+        let function_name = function_path.rsplit("::").next().unwrap_or(&function_path);
+        diagnostics.push(LintDiagnostic::with_args(
+            &PanicInCode,
+            init_node.stable_ptr(db.upcast()),
+            &[("panic", function_name)],
+        ));
     } else {
         // If the originating location is a different file get the syntax node that generated the
         // code that contains a panic.
@@ -116,12 +116,7 @@ fn check_single_panic_usage(
             if let Ok(file_node) = db.file_syntax(file_id);
             then {
                 let syntax_node = file_node.lookup_position(db.upcast(), text_position.start);
-                diagnostics.push(PluginDiagnostic {
-                    stable_ptr: syntax_node.stable_ptr(db.upcast()),
-                    message: PanicInCode.diagnostic_message().to_owned(),
-                    severity: Severity::Warning,
-                    relative_span: None
-                });
+                diagnostics.push(LintDiagnostic::with_args(&PanicInCode, syntax_node.stable_ptr(db.upcast()), &[("panic", "panic!")]));
             }
         }
     }
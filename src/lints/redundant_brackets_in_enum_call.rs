@@ -1,49 +1,24 @@
 use crate::{
-    context::{CairoLintKind, Lint},
-    queries::get_all_function_bodies,
+    context::{CairoLintKind, CheckContext, Lint, LintCategory, LintDocs},
+    diagnostics::LintDiagnostic,
+    fixes::Suggestion,
 };
-use cairo_lang_defs::{ids::ModuleItemId, plugin::PluginDiagnostic};
-use cairo_lang_diagnostics::Severity;
-use cairo_lang_semantic::{db::SemanticGroup, Expr};
+use cairo_lang_semantic::{db::SemanticGroup, Arenas, ExprEnumVariantCtor};
 use cairo_lang_syntax::node::{ast, db::SyntaxGroup, SyntaxNode, TypedStablePtr, TypedSyntaxNode};
 use if_chain::if_chain;
+use indoc::indoc;
 
 pub struct RedundantBracketsInEnumCall;
 
-/// ## What it does
-///
-/// Detects calls to enum variant constructors with redundant parentheses
-///
-/// ## Example
-///
-/// ```cairo
-/// enum MyEnum {
-///     Data: u8,
-///     Empty,
-/// }
-///
-/// fn main() {
-///     let a = MyEnum::Empty(()); // redundant parentheses
-/// }
-/// ```
-///
-/// Can be simplified to:
-///
-/// ```cairo
-/// enum MyEnum {
-///     Data: u8,
-///     Empty,
-/// }
-///
-/// fn main() {
-///     let a = MyEnum::Empty;
-/// }
-/// ```
 impl Lint for RedundantBracketsInEnumCall {
     fn allowed_name(&self) -> &'static str {
         "redundant_brackets_in_enum_call"
     }
 
+    fn id(&self) -> &'static str {
+        "CL0046"
+    }
+
     fn diagnostic_message(&self) -> &'static str {
         "redundant parentheses in enum call"
     }
@@ -52,40 +27,72 @@ impl Lint for RedundantBracketsInEnumCall {
         CairoLintKind::EnumEmptyVariantBrackets
     }
 
+    fn category(&self) -> LintCategory {
+        LintCategory::Style
+    }
+
+    fn docs(&self) -> LintDocs {
+        LintDocs {
+            description: "Detects calls to enum variant constructors with redundant parentheses",
+            bad_example: Some(indoc! {r#"
+                enum MyEnum {
+                    Data: u8,
+                    Empty,
+                }
+
+                fn main() {
+                    let a = MyEnum::Empty(()); // redundant parentheses
+                }
+            "#}),
+            good_example: Some(indoc! {r#"
+                enum MyEnum {
+                    Data: u8,
+                    Empty,
+                }
+
+                fn main() {
+                    let a = MyEnum::Empty;
+                }
+            "#}),
+            ..Default::default()
+        }
+    }
+
     fn has_fixer(&self) -> bool {
         true
     }
 
-    fn fix(&self, db: &dyn SemanticGroup, node: SyntaxNode) -> Option<(SyntaxNode, String)> {
-        fix_redundant_brackets_in_enum_call(db.upcast(), node)
+    fn fix(&self, db: &dyn SemanticGroup, node: SyntaxNode) -> Option<Suggestion> {
+        let (node, replacement) = fix_redundant_brackets_in_enum_call(db.upcast(), node)?;
+        Some(Suggestion::replace_node(
+            db.upcast(),
+            "Remove the redundant parentheses from the enum call",
+            node,
+            replacement,
+        ))
     }
 }
 
 pub fn check_redundant_brackets_in_enum_call(
     db: &dyn SemanticGroup,
-    item: &ModuleItemId,
-    diagnostics: &mut Vec<PluginDiagnostic>,
+    enum_expr: &ExprEnumVariantCtor,
+    _arenas: &Arenas,
+    _context: &CheckContext,
+    diagnostics: &mut Vec<LintDiagnostic>,
 ) {
-    let function_bodies = get_all_function_bodies(db, item);
-    for function_body in function_bodies.iter() {
-        for (_, expr) in &function_body.arenas.exprs {
-            if is_redundant_enum_brackets_call(expr, db) {
-                diagnostics.push(PluginDiagnostic {
-                    stable_ptr: expr.stable_ptr().untyped(),
-                    message: RedundantBracketsInEnumCall.diagnostic_message().to_string(),
-                    severity: Severity::Warning,
-                    relative_span: None,
-                });
-            }
-        }
+    if is_redundant_enum_brackets_call(enum_expr, db) {
+        diagnostics.push(LintDiagnostic::new(
+            &RedundantBracketsInEnumCall,
+            enum_expr.stable_ptr.untyped(),
+        ));
     }
 }
 
-fn is_redundant_enum_brackets_call(expr: &Expr, db: &dyn SemanticGroup) -> bool {
+fn is_redundant_enum_brackets_call(
+    enum_expr: &ExprEnumVariantCtor,
+    db: &dyn SemanticGroup,
+) -> bool {
     if_chain! {
-        // Check if the expression is a constructor call for an enum variant,
-        if let Expr::EnumVariantCtor(enum_expr) = expr;
-
         // Check if the type of the enum variant is of unit type `()`.
         if enum_expr.variant.ty.is_unit(db.upcast());
 
@@ -1,43 +1,24 @@
 use super::{ADD, DIV, MUL, SUB};
-use crate::context::{CairoLintKind, Lint};
+use crate::context::{CairoLintKind, CheckContext, Lint, LintCategory, LintDocs};
+use crate::diagnostics::LintDiagnostic;
 use crate::helper::{is_one, is_zero};
 use crate::lints::function_trait_name_from_fn_id;
-use crate::queries::{get_all_function_bodies, get_all_function_calls};
-use cairo_lang_defs::ids::ModuleItemId;
-use cairo_lang_defs::plugin::PluginDiagnostic;
-use cairo_lang_diagnostics::Severity;
 use cairo_lang_semantic::db::SemanticGroup;
 use cairo_lang_semantic::{Arenas, ExprFunctionCall};
 use cairo_lang_syntax::node::TypedStablePtr;
+use indoc::indoc;
 
 pub struct RedundantOperation;
 
-/// ## What it does
-///
-/// Checks for redundant arithmetic operations like `x + 0`, `x - 0`, `x * 1`, `x / 1`
-///
-/// ## Example
-///
-/// ```cairo
-/// fn main() {
-///     let x = 42;
-///     let _y = x * 1;
-/// }
-/// ```
-///
-/// Can be simplified to
-///
-/// ```cairo
-/// fn main() {
-///     let x = 42;
-///     let _y = x;
-/// }
-/// ```
 impl Lint for RedundantOperation {
     fn allowed_name(&self) -> &'static str {
         "redundant_op"
     }
 
+    fn id(&self) -> &'static str {
+        "CL0041"
+    }
+
     fn diagnostic_message(&self) -> &'static str {
         "This operation doesn't change the value and can be simplified."
     }
@@ -45,28 +26,38 @@ impl Lint for RedundantOperation {
     fn kind(&self) -> CairoLintKind {
         CairoLintKind::RedundantOperation
     }
-}
 
-pub fn check_redundant_operation(
-    db: &dyn SemanticGroup,
-    item: &ModuleItemId,
-    diagnostics: &mut Vec<PluginDiagnostic>,
-) {
-    let function_bodies = get_all_function_bodies(db, item);
-    for function_body in function_bodies.iter() {
-        let function_call_exprs = get_all_function_calls(function_body);
-        let arenas = &function_body.arenas;
-        for function_call_expr in function_call_exprs {
-            check_single_redundant_operation(db, &function_call_expr, arenas, diagnostics);
+    fn category(&self) -> LintCategory {
+        LintCategory::Complexity
+    }
+
+    fn docs(&self) -> LintDocs {
+        LintDocs {
+            description:
+                "Checks for redundant arithmetic operations like `x + 0`, `x - 0`, `x * 1`, `x / 1`",
+            bad_example: Some(indoc! {r#"
+                fn main() {
+                    let x = 42;
+                    let _y = x * 1;
+                }
+            "#}),
+            good_example: Some(indoc! {r#"
+                fn main() {
+                    let x = 42;
+                    let _y = x;
+                }
+            "#}),
+            ..Default::default()
         }
     }
 }
 
-fn check_single_redundant_operation(
+pub fn check_redundant_operation(
     db: &dyn SemanticGroup,
     expr_func: &ExprFunctionCall,
     arenas: &Arenas,
-    diagnostics: &mut Vec<PluginDiagnostic>,
+    _context: &CheckContext,
+    diagnostics: &mut Vec<LintDiagnostic>,
 ) {
     let func = function_trait_name_from_fn_id(db, &expr_func.function);
     let is_redundant = match func.as_str() {
@@ -78,11 +69,9 @@ fn check_single_redundant_operation(
     };
 
     if is_redundant {
-        diagnostics.push(PluginDiagnostic {
-            stable_ptr: expr_func.stable_ptr.untyped(),
-            message: RedundantOperation.diagnostic_message().to_string(),
-            severity: Severity::Warning,
-            relative_span: None,
-        });
+        diagnostics.push(LintDiagnostic::new(
+            &RedundantOperation,
+            expr_func.stable_ptr.untyped(),
+        ));
     }
 }
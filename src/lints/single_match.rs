@@ -1,6 +1,3 @@
-use cairo_lang_defs::ids::ModuleItemId;
-use cairo_lang_defs::plugin::PluginDiagnostic;
-use cairo_lang_diagnostics::Severity;
 use cairo_lang_semantic::db::SemanticGroup;
 use cairo_lang_semantic::{Arenas, ExprMatch, Pattern};
 use cairo_lang_syntax::node::ast::{Expr as AstExpr, ExprBlock, ExprListParenthesized, Statement};
@@ -10,39 +7,24 @@ use cairo_lang_syntax::node::{
     SyntaxNode, TypedStablePtr, TypedSyntaxNode,
 };
 use if_chain::if_chain;
+use indoc::indoc;
 
-use crate::context::{CairoLintKind, Lint};
+use crate::context::{CairoLintKind, CheckContext, Lint, LintCategory, LintDocs};
+use crate::diagnostics::LintDiagnostic;
+use crate::fixes::Suggestion;
 use crate::helper::indent_snippet;
-use crate::queries::{get_all_function_bodies, get_all_match_expressions};
 
 pub struct DestructMatch;
 
-/// ## What it does
-///
-/// Checks for matches that do something only in 1 arm and can be rewrote as an `if let`
-///
-/// ## Example
-///
-/// ```cairo
-/// let var = Option::Some(1_u32);
-/// match var {
-///     Option::Some(val) => do_smth(val),
-///     _ => (),
-/// }
-/// ```
-///
-/// Which can be rewritten as
-///
-/// ```cairo
-/// if let Option::Some(val) = var {
-///     do_smth(val),
-/// }
-/// ```
 impl Lint for DestructMatch {
     fn allowed_name(&self) -> &'static str {
         "destruct_match"
     }
 
+    fn id(&self) -> &'static str {
+        "CL0001"
+    }
+
     fn diagnostic_message(&self) -> &'static str {
         "you seem to be trying to use `match` for destructuring a single pattern. Consider using `if let`"
     }
@@ -51,42 +33,55 @@ impl Lint for DestructMatch {
         CairoLintKind::DestructMatch
     }
 
+    fn category(&self) -> LintCategory {
+        LintCategory::Style
+    }
+
+    fn docs(&self) -> LintDocs {
+        LintDocs {
+            description: "Checks for matches that do something only in 1 arm and can be rewrote as an `if let`",
+            bad_example: Some(indoc! {r#"
+                let var = Option::Some(1_u32);
+                match var {
+                    Option::Some(val) => do_smth(val),
+                    _ => (),
+                }
+            "#}),
+            good_example: Some(indoc! {r#"
+                if let Option::Some(val) = var {
+                    do_smth(val),
+                }
+            "#}),
+            ..Default::default()
+        }
+    }
+
     fn has_fixer(&self) -> bool {
         true
     }
 
-    fn fix(&self, db: &dyn SemanticGroup, node: SyntaxNode) -> Option<(SyntaxNode, String)> {
-        fix_destruct_match(db.upcast(), node)
+    fn fix(&self, db: &dyn SemanticGroup, node: SyntaxNode) -> Option<Suggestion> {
+        let (node, replacement) = fix_destruct_match(db.upcast(), node)?;
+        Some(Suggestion::replace_node(
+            db.upcast(),
+            "Replace the `match` with `if let`",
+            node,
+            replacement,
+        ))
     }
 }
 
 pub struct EqualityMatch;
 
-/// ## What it does
-///
-/// Checks for matches that do something only in 1 arm and can be rewrote as an `if`
-///
-/// ## Example
-///
-/// ```cairo
-/// match variable {
-///     Option::None => println!("None"),
-///     Option::Some => (),
-/// };
-/// ```
-///
-/// Which can be probably rewritten as
-///
-/// ```cairo
-/// if variable.is_none() {
-///     println!("None");
-/// }
-/// ```
 impl Lint for EqualityMatch {
     fn allowed_name(&self) -> &'static str {
         "equality_match"
     }
 
+    fn id(&self) -> &'static str {
+        "CL0002"
+    }
+
     fn diagnostic_message(&self) -> &'static str {
         "you seem to be trying to use `match` for an equality check. Consider using `if`"
     }
@@ -94,28 +89,37 @@ impl Lint for EqualityMatch {
     fn kind(&self) -> CairoLintKind {
         CairoLintKind::MatchForEquality
     }
-}
 
-pub fn check_single_matches(
-    db: &dyn SemanticGroup,
-    item: &ModuleItemId,
-    diagnostics: &mut Vec<PluginDiagnostic>,
-) {
-    let function_bodies = get_all_function_bodies(db, item);
-    for function_body in function_bodies.iter() {
-        let match_exprs = get_all_match_expressions(function_body);
-        let arenas = &function_body.arenas;
-        for match_expr in match_exprs.iter() {
-            check_single_match(db, match_expr, arenas, diagnostics);
+    fn category(&self) -> LintCategory {
+        LintCategory::Style
+    }
+
+    fn docs(&self) -> LintDocs {
+        LintDocs {
+            description:
+                "Checks for matches that do something only in 1 arm and can be rewrote as an `if`",
+            bad_example: Some(indoc! {r#"
+                match variable {
+                    Option::None => println!("None"),
+                    Option::Some => (),
+                };
+            "#}),
+            good_example: Some(indoc! {r#"
+                if variable.is_none() {
+                    println!("None");
+                }
+            "#}),
+            ..Default::default()
         }
     }
 }
 
-fn check_single_match(
+pub fn check_single_match(
     db: &dyn SemanticGroup,
     match_expr: &ExprMatch,
     arenas: &Arenas,
-    diagnostics: &mut Vec<PluginDiagnostic>,
+    _context: &CheckContext,
+    diagnostics: &mut Vec<LintDiagnostic>,
 ) {
     let arms = &match_expr.arms;
     let mut is_single_armed = false;
@@ -177,18 +181,14 @@ fn check_single_match(
     };
 
     match (is_single_armed, is_destructuring) {
-        (true, false) => diagnostics.push(PluginDiagnostic {
-            stable_ptr: match_expr.stable_ptr.into(),
-            message: EqualityMatch.diagnostic_message().to_string(),
-            severity: Severity::Warning,
-            relative_span: None,
-        }),
-        (true, true) => diagnostics.push(PluginDiagnostic {
-            stable_ptr: match_expr.stable_ptr.into(),
-            message: DestructMatch.diagnostic_message().to_string(),
-            severity: Severity::Warning,
-            relative_span: None,
-        }),
+        (true, false) => diagnostics.push(LintDiagnostic::new(
+            &EqualityMatch,
+            match_expr.stable_ptr.into(),
+        )),
+        (true, true) => diagnostics.push(LintDiagnostic::new(
+            &DestructMatch,
+            match_expr.stable_ptr.into(),
+        )),
         (_, _) => (),
     }
 }
@@ -287,6 +287,10 @@ pub fn fix_destruct_match(db: &dyn SyntaxGroup, node: SyntaxNode) -> Option<(Syn
         .take_while(|c| c.is_whitespace())
         .collect::<String>();
     let trivia = pattern.get_text_of_span(db, pattern_span);
+    // Keep the trailing trivia (e.g. a newline after a `match` in the tail position) untouched.
+    let mut trailing_trivia_span = node.span(db);
+    trailing_trivia_span.start = node.span_end_without_trivia(db);
+    let trailing_trivia = node.get_text_of_span(db, trailing_trivia_span);
     Some((
         node,
         indent_snippet(
@@ -300,6 +304,6 @@ pub fn fix_destruct_match(db: &dyn SyntaxGroup, node: SyntaxNode) -> Option<(Syn
                 first_expr.expression(db).as_syntax_node().get_text(db),
             ),
             indent.len() / 4,
-        ),
+        ) + &trailing_trivia,
     ))
 }
@@ -0,0 +1,68 @@
+//! # Lint listing for Cairo Lint
+//!
+//! This module lists all of the lint rules registered in the [`LintRegistry`], together with their
+//! metadata and documentation, in a machine-readable form. The editors can use it to complete the
+//! keys of the `[tool.cairo-lint]` table of the `Scarb.toml` manifest, and the listings of two
+//! versions of the Cairo lint can be compared to find the added, removed or changed lint rules.
+
+use serde::Serialize;
+
+use crate::context::{CairoLintKind, Lint, LintDocs, LintRegistry};
+
+/// Metadata of a single lint rule.
+/// IMPORTANT: This one is a part of the JSON output schema, so watch out when modifying it,
+/// as it might break the tools consuming the output.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct LintListEntry {
+    /// The name used in the tool metadata and in the lint level attributes.
+    pub name: &'static str,
+    /// The stable id of the lint rule, e.g. `CL0003`.
+    pub id: &'static str,
+    pub kind: CairoLintKind,
+    /// The name of the category of the lint rule.
+    pub category: &'static str,
+    /// Whether the lint rule is reported if it is not configured in the tool metadata.
+    pub enabled_by_default: bool,
+    /// The level of the lint rule if it is not configured in the tool metadata.
+    pub default_level: &'static str,
+    /// Whether the diagnostics of the lint rule can be fixed automatically.
+    pub fixable: bool,
+    /// The message of the diagnostics emitted by the lint rule, without the details of the occurrence.
+    pub message: &'static str,
+    pub docs: LintDocs,
+}
+
+impl LintListEntry {
+    /// Collects the metadata of the lint rule.
+    pub fn new(lint: &dyn Lint) -> Self {
+        Self {
+            name: lint.allowed_name(),
+            id: lint.id(),
+            kind: lint.kind(),
+            category: lint.category().name(),
+            enabled_by_default: lint.is_enabled(),
+            default_level: lint.default_level().as_str(),
+            fixable: lint.has_fixer(),
+            message: lint.diagnostic_message(),
+            docs: lint.docs(),
+        }
+    }
+}
+
+/// Lists all of the lint rules registered in the registry, including the custom ones, sorted by their names.
+pub fn list_lints(lint_registry: &LintRegistry) -> Vec<LintListEntry> {
+    let mut entries = lint_registry
+        .lints()
+        .map(LintListEntry::new)
+        .collect::<Vec<_>>();
+    entries.sort_by_key(|entry| entry.name);
+    entries
+}
+
+/// Formats the listing of the lint rules as a pretty-printed JSON array.
+///
+/// See [`LintListEntry`] for the schema of the objects.
+pub fn format_lint_list_as_json(lint_registry: &LintRegistry) -> String {
+    // Serializing the structs with string keys cannot fail.
+    serde_json::to_string_pretty(&list_lints(lint_registry)).unwrap() + "\n"
+}
@@ -0,0 +1,57 @@
+//! # Parallel linting for Cairo Lint
+//!
+//! This module collects the diagnostics of all of the modules of the crates, including the ones
+//! emitted by the [`crate::plugin::CairoLint`] plugin. The checking functions only read the
+//! database, so the modules can be checked in parallel, every one of them on a separate snapshot
+//! of the database.
+
+use cairo_lang_compiler::db::RootDatabase;
+use cairo_lang_defs::db::DefsGroup;
+use cairo_lang_defs::ids::ModuleId;
+use cairo_lang_filesystem::ids::CrateId;
+use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_semantic::SemanticDiagnostic;
+use itertools::Itertools;
+use rayon::prelude::*;
+
+/// Collects the semantic diagnostics of all of the modules of the crates, checking the modules one by one.
+pub fn collect_diagnostics(db: &RootDatabase, crate_ids: &[CrateId]) -> Vec<SemanticDiagnostic> {
+    get_crate_modules(db, crate_ids)
+        .into_iter()
+        .flat_map(|module_id| get_module_diagnostics(db, module_id))
+        .collect()
+}
+
+/// Collects the semantic diagnostics of all of the modules of the crates, checking the modules
+/// in parallel. The diagnostics are the same, and in the same order, as the ones returned by
+/// [`collect_diagnostics`].
+///
+/// The snapshots share the storage of the database, so the diagnostics are memoized by the database
+/// as if they were collected with [`collect_diagnostics`].
+pub fn collect_diagnostics_in_parallel(
+    db: &RootDatabase,
+    crate_ids: &[CrateId],
+) -> Vec<SemanticDiagnostic> {
+    // The database is not `Sync`, so the snapshots have to be taken before spawning the tasks.
+    let snapshots = get_crate_modules(db, crate_ids)
+        .into_iter()
+        .map(|module_id| (db.snapshot(), module_id))
+        .collect_vec();
+    snapshots
+        .into_par_iter()
+        .flat_map_iter(|(snapshot, module_id)| get_module_diagnostics(&snapshot, module_id))
+        .collect()
+}
+
+fn get_crate_modules(db: &RootDatabase, crate_ids: &[CrateId]) -> Vec<ModuleId> {
+    crate_ids
+        .iter()
+        .flat_map(|crate_id| db.crate_modules(*crate_id).iter().copied().collect_vec())
+        .collect()
+}
+
+fn get_module_diagnostics(db: &RootDatabase, module_id: ModuleId) -> Vec<SemanticDiagnostic> {
+    db.module_semantic_diagnostics(module_id)
+        .map(|diagnostics| diagnostics.get_all())
+        .unwrap_or_default()
+}
@@ -1,81 +1,357 @@
 use anyhow::{anyhow, Result};
-use cairo_lang_defs::ids::{LanguageElementId, ModuleId};
-use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_defs::ids::{LanguageElementId, ModuleId, ModuleItemId};
+use cairo_lang_defs::plugin::{MacroPlugin, MacroPluginMetadata, PluginDiagnostic, PluginResult};
 use cairo_lang_filesystem::ids::{FileId, FileLongId};
+use cairo_lang_filesystem::span::TextSpan;
 use cairo_lang_semantic::db::SemanticGroup;
 use cairo_lang_semantic::plugin::{AnalyzerPlugin, PluginSuite};
 use cairo_lang_syntax::node::db::SyntaxGroup;
 use cairo_lang_syntax::node::helpers::QueryAttrs;
-use cairo_lang_syntax::node::SyntaxNode;
+use cairo_lang_syntax::node::ids::SyntaxStablePtrId;
+use cairo_lang_syntax::node::kind::SyntaxKind;
+use cairo_lang_syntax::node::{ast, SyntaxNode, TypedSyntaxNode};
 use cairo_lang_utils::LookupIntern;
-use std::sync::Arc;
+use itertools::Itertools;
+use std::cell::OnceCell;
+use std::collections::{HashMap, HashSet};
+use std::iter;
+use std::path::Path;
+use std::sync::{Arc, Mutex};
 
+use crate::changed_lines::ChangedLines;
+use crate::config::{LintLevel, LintOptions, CALL_PATTERNS_KEY};
 use crate::context::{
-    get_all_checking_functions, get_name_for_diagnostic_message, get_unique_allowed_names,
-    is_lint_enabled_by_default,
+    get_unique_category_names, CheckContext, DeprecatedLintName, Lint, LintCategory, LintChecker,
+    LintRegistry,
 };
-use crate::CairoLintToolMetadata;
+use crate::diagnostics::LintDiagnostic;
+use crate::lints::call_patterns::{call_pattern_rules_for_item, check_call_patterns};
+use crate::lints::lint_attributes::{
+    RemovedLintName, RenamedLintName, UnfulfilledLintExpectation, UnknownLintName, UnusedLintAllow,
+};
+use crate::queries::{walk_function_bodies, FunctionBodyVisitor};
+use crate::CairoLintConfig;
+
+pub fn cairo_lint_plugin_suite(tool_metadata: impl Into<CairoLintConfig>) -> Result<PluginSuite> {
+    cairo_lint_plugin_suite_with_lint_registry(tool_metadata, LintRegistry::default())
+}
 
-pub fn cairo_lint_plugin_suite(tool_metadata: CairoLintToolMetadata) -> Result<PluginSuite> {
+/// Creates the plugin suite with the custom lint rules from the registry.
+/// The call pattern rules defined in the tool metadata are registered as well.
+pub fn cairo_lint_plugin_suite_with_lint_registry(
+    tool_metadata: impl Into<CairoLintConfig>,
+    mut lint_registry: LintRegistry,
+) -> Result<PluginSuite> {
+    let tool_metadata = tool_metadata.into();
     let mut suite = PluginSuite::default();
-    validate_cairo_lint_metadata(&tool_metadata)?;
-    suite.add_analyzer_plugin_ex(Arc::new(CairoLint::new(false, tool_metadata)));
+    lint_registry
+        .register_call_patterns(&tool_metadata)
+        .map_err(|err| anyhow!("Invalid call pattern rules in `Scarb.toml`: {}", err))?;
+    validate_cairo_lint_metadata(&tool_metadata, &lint_registry)?;
+    suite.add_analyzer_plugin_ex(Arc::new(
+        CairoLint::new(false, tool_metadata).with_lint_registry(lint_registry),
+    ));
+    suite.add_plugin::<CairoLintAttributes>();
     Ok(suite)
 }
 
 pub fn cairo_lint_plugin_suite_without_metadata_validation(
-    tool_metadata: CairoLintToolMetadata,
+    tool_metadata: impl Into<CairoLintConfig>,
 ) -> PluginSuite {
+    let tool_metadata = tool_metadata.into();
     let mut suite = PluginSuite::default();
-    suite.add_analyzer_plugin_ex(Arc::new(CairoLint::new(false, tool_metadata)));
+    let mut lint_registry = LintRegistry::default();
+    // The metadata is not validated here, so the registration just stops at the first invalid rule.
+    let _ = lint_registry.register_call_patterns(&tool_metadata);
+    suite.add_analyzer_plugin_ex(Arc::new(
+        CairoLint::new(false, tool_metadata).with_lint_registry(lint_registry),
+    ));
+    suite.add_plugin::<CairoLintAttributes>();
     suite
 }
 
 pub fn cairo_lint_allow_plugin_suite() -> PluginSuite {
     let mut suite = PluginSuite::default();
     suite.add_analyzer_plugin::<CairoLintAllow>();
+    suite.add_plugin::<CairoLintAttributes>();
     suite
 }
 
 #[derive(Debug, Default)]
 pub struct CairoLint {
     include_compiler_generated_files: bool,
-    tool_metadata: CairoLintToolMetadata,
+    tool_metadata: CairoLintConfig,
+    changed_lines: Option<ChangedLines>,
+    lint_registry: LintRegistry,
+    /// The level attributes used by the diagnostics of each of the linted modules, and of all the
+    /// submodules inside of it. They are recorded when the diagnostics of the module are computed.
+    used_attributes: Mutex<HashMap<ModuleId, HashSet<AttributeLintName>>>,
 }
 
 impl CairoLint {
     pub fn new(
         include_compiler_generated_files: bool,
-        tool_metadata: CairoLintToolMetadata,
+        tool_metadata: impl Into<CairoLintConfig>,
     ) -> Self {
         Self {
             include_compiler_generated_files,
-            tool_metadata,
+            tool_metadata: tool_metadata.into(),
+            changed_lines: None,
+            lint_registry: LintRegistry::default(),
+            used_attributes: Mutex::new(HashMap::new()),
         }
     }
 
+    /// Uses the registry with the custom lint rules.
+    pub fn with_lint_registry(mut self, lint_registry: LintRegistry) -> Self {
+        self.lint_registry = lint_registry;
+        self
+    }
+
+    pub fn lint_registry(&self) -> &LintRegistry {
+        &self.lint_registry
+    }
+
+    /// Limits the diagnostics to the ones pointing to the changed lines.
+    pub fn with_changed_lines(mut self, changed_lines: ChangedLines) -> Self {
+        self.changed_lines = Some(changed_lines);
+        self
+    }
+
+    pub fn changed_lines(&self) -> Option<&ChangedLines> {
+        self.changed_lines.as_ref()
+    }
+
     pub fn include_compiler_generated_files(&self) -> bool {
         self.include_compiler_generated_files
     }
 
-    pub fn tool_metadata(&self) -> &CairoLintToolMetadata {
+    pub fn tool_metadata(&self) -> &CairoLintConfig {
         &self.tool_metadata
     }
-}
 
-impl AnalyzerPlugin for CairoLint {
-    fn declared_allows(&self) -> Vec<String> {
-        get_unique_allowed_names()
+    /// The names under which the lint rule can appear in the tool metadata and inside the level attributes:
+    /// its name and the name of its category, each one followed by its old names if it was renamed.
+    fn lint_names(&self, lint: &dyn Lint) -> Vec<&'static str> {
+        [lint.allowed_name(), lint.category().name()]
+            .into_iter()
+            .flat_map(|name| iter::once(name).chain(self.lint_registry.old_names(name)))
+            .collect()
+    }
+
+    /// Gets the level of the lint rule, as configured in the tool metadata.
+    /// The configuration of the lint rule itself takes precedence over the configuration of its category.
+    /// Falls back to the default level of the rule if it is not configured (or configured incorrectly).
+    pub fn lint_level(&self, lint: &dyn Lint) -> LintLevel {
+        self.lint_names(lint)
+            .into_iter()
+            .find_map(|name| self.tool_metadata.get(name)?.level().transpose())
+            .and_then(|level| level.ok())
+            .unwrap_or_else(|| lint.default_level())
+    }
+
+    /// Gets the options of the lint rules, as configured in the tool metadata.
+    /// The options of a lint rule are taken from the first of its names (the current name, followed by
+    /// its old names) which configures any. The invalid options fall back to the defaults.
+    pub fn lint_options(&self) -> LintOptions {
+        let mut options = LintOptions::default();
+        for name in LintOptions::LINT_NAMES {
+            let lint_options = iter::once(name)
+                .chain(self.lint_registry.old_names(name))
+                .find_map(|name| self.tool_metadata.get(name)?.options());
+            if let Some(lint_options) = lint_options {
+                // The options are validated together with the rest of the tool metadata.
+                let _ = options.set(name, lint_options);
+            }
+        }
+        options
+    }
+
+    /// Checks if any of the lint rules is enabled in the tool metadata.
+    fn is_enabled_in_metadata(&self, lints: &[&dyn Lint]) -> bool {
+        lints
             .iter()
-            .map(ToString::to_string)
+            .any(|lint| self.lint_level(*lint) != LintLevel::Allow)
+    }
+
+    /// Checks if any of the lint rules may report a diagnostic inside the item, so they have to be checked.
+    ///
+    /// The rules disabled in the tool metadata are skipped, unless they are named in any of the level
+    /// attributes applying to the item, as the attribute can enable them again. That goes for the `allow`
+    /// attributes as well, so they are not reported as unused.
+    /// The enabled rules are skipped if an `allow` attribute covers the whole item and nothing inside
+    /// of it changes their level again, unless the unused `allow` attributes may be reported.
+    fn should_check<'a>(
+        &self,
+        db: &dyn SemanticGroup,
+        lints: &[&dyn Lint],
+        enabled_in_metadata: bool,
+        item_attributes: impl FnOnce() -> &'a ItemLevelAttributes,
+    ) -> bool {
+        let item_attributes = item_attributes();
+        if !enabled_in_metadata {
+            return lints
+                .iter()
+                .any(|lint| item_attributes.names_any(&self.lint_names(*lint)));
+        }
+        if item_attributes.names.is_empty() || self.may_report_unused_allows(&item_attributes.names)
+        {
+            return true;
+        }
+        !lints
+            .iter()
+            .all(|lint| self.is_allowed_in_whole_item(db, *lint, item_attributes))
+    }
+
+    /// Checks if the lint rule is suppressed by an `allow` attribute in the whole item,
+    /// i.e. the attribute is placed on the item or around it, and nothing inside of the item changes the level again.
+    fn is_allowed_in_whole_item(
+        &self,
+        db: &dyn SemanticGroup,
+        lint: &dyn Lint,
+        item_attributes: &ItemLevelAttributes,
+    ) -> bool {
+        let names = self.lint_names(lint);
+        self.lint_level(lint) != LintLevel::Forbid
+            && !names
+                .iter()
+                .any(|name| item_attributes.inner_non_allow_names.contains(*name))
+            && find_nearest_level_attribute(db.upcast(), item_attributes.item_node.clone(), &names)
+                .is_some_and(|attribute| attribute.kind == LevelAttributeKind::Allow)
+    }
+
+    /// Checks if the unused `allow` attributes may be reported, either because the rule is enabled
+    /// in the tool metadata, or it is named in any of the level attributes.
+    fn may_report_unused_allows(&self, attribute_names: &HashSet<String>) -> bool {
+        self.lint_level(&UnusedLintAllow) != LintLevel::Allow
+            || self
+                .lint_names(&UnusedLintAllow)
+                .iter()
+                .any(|name| attribute_names.contains(*name))
+    }
+
+    /// Resolves the final level of the lint diagnostic, based on the tool metadata and the nearest
+    /// level attribute (`allow`, `warn`, `deny` or `expect`) placed on the diagnostic node or its ancestors.
+    /// Returns `None` if the diagnostic should not be reported, or the lint rule which reported it is not registered.
+    ///
+    /// The level attribute which applied to the diagnostic is added to the `used_attributes`.
+    /// The details of the reported diagnostic are kept in the registry, see [`LintRegistry::diagnostic_details`].
+    fn apply_lint_level(
+        &self,
+        db: &dyn SemanticGroup,
+        diagnostic: LintDiagnostic,
+        used_attributes: &mut HashSet<AttributeLintName>,
+    ) -> Option<PluginDiagnostic> {
+        let lint = self
+            .lint_registry
+            .find_lint_by_id(diagnostic.details.lint_id)?;
+        let mut level = self.lint_level(lint);
+        // Forbidden lints cannot be overridden by any of the level attributes.
+        if level != LintLevel::Forbid {
+            let node = diagnostic.stable_ptr.lookup(db.upcast());
+            let names = self.lint_names(lint);
+            if let Some(attribute) = find_nearest_level_attribute(db.upcast(), node, &names) {
+                used_attributes.insert(attribute.lint_name);
+                level = attribute.kind.level();
+            }
+        }
+        let (diagnostic, details) = diagnostic.into_plugin_diagnostic(level.severity()?);
+        self.lint_registry
+            .record_diagnostic_details(&diagnostic, details);
+        Some(diagnostic)
+    }
+
+    /// Resolves the level of the lint diagnostic which points to the start of the file, based on
+    /// the tool metadata only. None of the level attributes applies to the whole file.
+    fn apply_metadata_lint_level(&self, diagnostic: LintDiagnostic) -> Option<PluginDiagnostic> {
+        let lint = self
+            .lint_registry
+            .find_lint_by_id(diagnostic.details.lint_id)?;
+        let (mut diagnostic, details) =
+            diagnostic.into_plugin_diagnostic(self.lint_level(lint).severity()?);
+        diagnostic.relative_span = Some(TextSpan::default());
+        self.lint_registry
+            .record_diagnostic_details(&diagnostic, details);
+        Some(diagnostic)
+    }
+
+    /// Reports the deprecated lint names used in the tool metadata, once for every crate.
+    /// The tool metadata does not belong to any Cairo file, so the diagnostics point to the start of
+    /// the main file of the crate root, and only the tool metadata decides their level.
+    fn find_deprecated_lint_names_in_metadata(
+        &self,
+        db: &dyn SemanticGroup,
+        module_id: ModuleId,
+    ) -> Vec<PluginDiagnostic> {
+        let ModuleId::CrateRoot(_) = module_id else {
+            return Vec::new();
+        };
+        let Ok(file_node) = db
+            .module_main_file(module_id)
+            .and_then(|file_id| db.file_syntax(file_id))
+        else {
+            return Vec::new();
+        };
+        let stable_ptr = file_node.stable_ptr(db.upcast());
+        self.tool_metadata
+            .keys()
+            .sorted()
+            .filter_map(|name| self.lint_registry.find_deprecated_lint_name(name))
+            .map(|deprecated_lint_name| {
+                let (lint, help): (&'static dyn Lint, _) = match deprecated_lint_name {
+                    DeprecatedLintName::Renamed { new_name, .. } => (
+                        &RenamedLintName,
+                        format!(
+                            "rename it to `{new_name}` in the `[tool.cairo-lint]` table of `Scarb.toml`"
+                        ),
+                    ),
+                    DeprecatedLintName::Removed { .. } => (
+                        &RemovedLintName,
+                        "remove it from the `[tool.cairo-lint]` table of `Scarb.toml`".to_string(),
+                    ),
+                };
+                LintDiagnostic::new(lint, stable_ptr)
+                    .with_message(deprecated_lint_name.message())
+                    .with_help(&help)
+            })
+            .filter_map(|diagnostic| self.apply_metadata_lint_level(diagnostic))
             .collect()
     }
 
-    fn diagnostics(&self, db: &dyn SemanticGroup, module_id: ModuleId) -> Vec<PluginDiagnostic> {
-        let mut diags: Vec<(PluginDiagnostic, FileId)> = Vec::new();
+    /// Runs the checks of all the lint rules on the items of the module.
+    /// Returns the diagnostics, together with the main file of the module, and the linted items.
+    fn lint_module_items(
+        &self,
+        db: &dyn SemanticGroup,
+        module_id: ModuleId,
+    ) -> (Vec<(LintDiagnostic, FileId)>, Vec<ModuleItemId>) {
+        let mut diags: Vec<(LintDiagnostic, FileId)> = Vec::new();
+        let mut linted_items: Vec<ModuleItemId> = Vec::new();
         let Ok(items) = db.module_items(module_id) else {
-            return Vec::default();
+            return (diags, linted_items);
         };
+        // Which of the rules are enabled in the tool metadata does not depend on the item,
+        // so it is resolved once for all of them.
+        let lint_groups = self
+            .lint_registry
+            .lint_groups()
+            .map(|group| {
+                let lints = group.lints().map(|lint| lint.as_ref()).collect_vec();
+                let enabled_in_metadata = self.is_enabled_in_metadata(&lints);
+                (group.checker(), lints, enabled_in_metadata)
+            })
+            .collect_vec();
+        let lint_options = self.lint_options();
+        let context = CheckContext::new(&lint_options);
+        let call_pattern_rules = self
+            .lint_registry
+            .call_pattern_rules()
+            .iter()
+            .map(|rule| {
+                let lint: &dyn Lint = &**rule;
+                (rule.clone(), self.is_enabled_in_metadata(&[lint]))
+            })
+            .collect_vec();
         for item in &*items {
             let module_file = db.module_main_file(module_id).unwrap();
             let item_file = item.stable_location(db).file_id(db).lookup_intern(db);
@@ -87,37 +363,206 @@ impl AnalyzerPlugin for CairoLint {
                 continue;
             }
 
-            let checking_functions = get_all_checking_functions();
-            let mut item_diagnostics = Vec::new();
+            // The function bodies are walked only once, with the visitors of all of the checked groups.
+            let item_attributes = OnceCell::new();
+            let get_item_attributes =
+                || item_attributes.get_or_init(|| ItemLevelAttributes::new(db, item));
+            let checkers = lint_groups
+                .iter()
+                .filter(|(_, lints, enabled_in_metadata)| {
+                    self.should_check(db, lints, *enabled_in_metadata, get_item_attributes)
+                })
+                .map(|(checker, _, _)| *checker)
+                .collect_vec();
+            let checked_call_pattern_rules = call_pattern_rules
+                .iter()
+                .filter(|(rule, enabled_in_metadata)| {
+                    let lint: &dyn Lint = &**rule;
+                    self.should_check(db, &[lint], *enabled_in_metadata, get_item_attributes)
+                })
+                .map(|(rule, _)| rule.clone())
+                .collect_vec();
+            let checked_call_pattern_rules =
+                call_pattern_rules_for_item(db, item, &checked_call_pattern_rules);
+            let item_context = context.with_call_pattern_rules(&checked_call_pattern_rules);
+            let mut visitors = checkers
+                .iter()
+                .filter_map(|checker| match checker {
+                    LintChecker::FunctionBody(visitor) => Some(*visitor),
+                    LintChecker::Item(_) => None,
+                })
+                .collect_vec();
+            // The call pattern rules share a single visitor, placed after the ones of the groups.
+            if !checked_call_pattern_rules.is_empty() {
+                visitors.push(FunctionBodyVisitor {
+                    on_call: Some(check_call_patterns),
+                    ..Default::default()
+                });
+            }
+            let mut visitor_diagnostics =
+                walk_function_bodies(db, item, &visitors, &item_context).into_iter();
 
-            for checking_function in checking_functions {
-                checking_function(db, item, &mut item_diagnostics);
+            let mut item_diagnostics = Vec::new();
+            for checker in checkers {
+                match checker {
+                    LintChecker::Item(check_function) => {
+                        check_function(db, item, &item_context, &mut item_diagnostics)
+                    }
+                    LintChecker::FunctionBody(_) => {
+                        item_diagnostics.extend(visitor_diagnostics.next().unwrap_or_default())
+                    }
+                }
             }
+            // The diagnostics of the call pattern rules, if they were checked.
+            item_diagnostics.extend(visitor_diagnostics.flatten());
 
             diags.extend(item_diagnostics.into_iter().map(|diag| (diag, module_file)));
+            linted_items.push(*item);
         }
+        (diags, linted_items)
+    }
 
+    /// Applies the levels of the lint rules to the diagnostics of the module, and records the level attributes used.
+    fn resolve_lint_levels(
+        &self,
+        db: &dyn SemanticGroup,
+        diags: Vec<(LintDiagnostic, FileId)>,
+        used_attributes: &mut HashSet<AttributeLintName>,
+    ) -> Vec<PluginDiagnostic> {
         diags
             .into_iter()
-            .filter(|diag| {
-                let diagnostic = &diag.0;
-                let diagnostic_origin_module_file = &diag.1;
+            .filter(|(diagnostic, diagnostic_origin_module_file)| {
                 let is_compiler_plugin_generated_file =
-                    &diagnostic.stable_ptr.file_id(db) != diagnostic_origin_module_file;
-                let node = diagnostic.stable_ptr.lookup(db.upcast());
-                let allowed_name = get_name_for_diagnostic_message(&diagnostic.message).unwrap();
-                let default_allowed = is_lint_enabled_by_default(&diagnostic.message).unwrap();
-                let is_rule_allowed_globally = *self
-                    .tool_metadata
-                    .get(allowed_name)
-                    .unwrap_or(&default_allowed);
-                !node_has_ascendants_with_allow_name_attr(db.upcast(), node, allowed_name)
-                    && is_rule_allowed_globally
-                    && (self.include_compiler_generated_files || !is_compiler_plugin_generated_file)
+                    diagnostic.stable_ptr.file_id(db) != *diagnostic_origin_module_file;
+                self.include_compiler_generated_files || !is_compiler_plugin_generated_file
             })
-            .map(|diag| diag.0)
+            .filter_map(|(diagnostic, _)| self.apply_lint_level(db, diagnostic, used_attributes))
             .collect()
     }
+
+    /// Gets the level attributes used by the diagnostics of the submodule, and of all the submodules inside of it.
+    /// The diagnostics of the submodule are computed (or taken from the database if they already are),
+    /// which records the attributes, so every module is linted only once.
+    fn used_attributes_in_submodule(
+        &self,
+        db: &dyn SemanticGroup,
+        submodule_id: ModuleId,
+    ) -> HashSet<AttributeLintName> {
+        let _ = db.module_semantic_diagnostics(submodule_id);
+        self.used_attributes
+            .lock()
+            .unwrap()
+            .get(&submodule_id)
+            .cloned()
+            .unwrap_or_default()
+    }
+}
+
+impl AnalyzerPlugin for CairoLint {
+    fn declared_allows(&self) -> Vec<String> {
+        declared_allows(&self.lint_registry)
+    }
+
+    fn diagnostics(&self, db: &dyn SemanticGroup, module_id: ModuleId) -> Vec<PluginDiagnostic> {
+        let (diags, linted_items) = self.lint_module_items(db, module_id);
+        let mut used_attributes = HashSet::new();
+        let mut result = self.resolve_lint_levels(db, diags, &mut used_attributes);
+
+        // The attributes placed on the module and its ancestors apply to the items of the submodules,
+        // which are linted separately. Their used attributes are merged upward, module by module.
+        for item in &linted_items {
+            if let ModuleItemId::Submodule(submodule_id) = item {
+                used_attributes.extend(
+                    self.used_attributes_in_submodule(db, ModuleId::Submodule(*submodule_id)),
+                );
+            }
+        }
+
+        // The attributes are checked only after all of the other diagnostics are resolved,
+        // as only then it is known which of them were used.
+        let level_attributes = find_level_attributes(db, &linted_items);
+        let known_names = lint_and_category_names(&self.lint_registry);
+        // The old names of the renamed lint rules still work, so their attributes can be unused as well.
+        let tracked_names = known_names
+            .iter()
+            .cloned()
+            .chain(
+                self.lint_registry
+                    .deprecated_lint_names()
+                    .filter(|deprecated_lint_name| {
+                        matches!(deprecated_lint_name, DeprecatedLintName::Renamed { .. })
+                    })
+                    .map(|deprecated_lint_name| deprecated_lint_name.name().to_string()),
+            )
+            .collect_vec();
+        let attribute_diagnostics = find_unfulfilled_expectations(
+            db.upcast(),
+            &level_attributes,
+            &tracked_names,
+            &used_attributes,
+        )
+        .into_iter()
+        .chain(find_unknown_lint_names(
+            db.upcast(),
+            &level_attributes,
+            &known_names,
+            &self.lint_registry,
+        ))
+        .chain(find_deprecated_lint_names(
+            db.upcast(),
+            &level_attributes,
+            &self.lint_registry,
+        ))
+        .collect_vec();
+        result.extend(
+            attribute_diagnostics.into_iter().filter_map(|diagnostic| {
+                self.apply_lint_level(db, diagnostic, &mut used_attributes)
+            }),
+        );
+        result.extend(
+            find_unused_allows(
+                db.upcast(),
+                &level_attributes,
+                &tracked_names,
+                &used_attributes,
+            )
+            .into_iter()
+            .filter_map(|diagnostic| self.apply_lint_level(db, diagnostic, &mut HashSet::new())),
+        );
+        self.used_attributes
+            .lock()
+            .unwrap()
+            .insert(module_id, used_attributes);
+
+        // Filtering by the changed lines happens at the very end, as the diagnostics outside of them
+        // still decide whether the lint level attributes are used.
+        if let Some(changed_lines) = &self.changed_lines {
+            result.retain(|diagnostic| is_in_changed_lines(db, diagnostic, changed_lines));
+        }
+        // The tool metadata is not a part of the changed lines, so its diagnostics are always reported.
+        result.extend(self.find_deprecated_lint_names_in_metadata(db, module_id));
+        result
+    }
+}
+
+/// Checks if the diagnostic points to any of the changed lines.
+fn is_in_changed_lines(
+    db: &dyn SemanticGroup,
+    diagnostic: &PluginDiagnostic,
+    changed_lines: &ChangedLines,
+) -> bool {
+    let file_id = diagnostic.stable_ptr.file_id(db.upcast());
+    let span = diagnostic
+        .stable_ptr
+        .lookup(db.upcast())
+        .span_without_trivia(db.upcast());
+    let Some(position) = span.position_in_file(db.upcast(), file_id) else {
+        return false;
+    };
+    changed_lines.intersects(
+        Path::new(&file_id.full_path(db.upcast())),
+        position.start.line + 1..=position.end.line + 1,
+    )
 }
 
 /// Plugin with `declared_allows` matching these of [`CairoLint`] that does not emit diagnostics.
@@ -132,33 +577,437 @@ impl AnalyzerPlugin for CairoLintAllow {
     }
 
     fn declared_allows(&self) -> Vec<String> {
-        get_unique_allowed_names()
+        declared_allows(&LintRegistry::default())
+    }
+}
+
+/// Macro plugin which declares the lint level attributes (`warn`, `deny` and `expect`),
+/// so the compiler does not report them as unsupported. It does not generate any code.
+#[derive(Debug, Default)]
+pub struct CairoLintAttributes;
+
+impl MacroPlugin for CairoLintAttributes {
+    fn generate_code(
+        &self,
+        _db: &dyn SyntaxGroup,
+        _item_ast: ast::ModuleItem,
+        _metadata: &MacroPluginMetadata<'_>,
+    ) -> PluginResult {
+        PluginResult::default()
+    }
+
+    fn declared_attributes(&self) -> Vec<String> {
+        LevelAttributeKind::ALL
             .iter()
-            .map(ToString::to_string)
+            .filter(|kind| **kind != LevelAttributeKind::Allow)
+            .map(|kind| kind.name().to_string())
             .collect()
     }
 }
 
-fn node_has_ascendants_with_allow_name_attr(
+/// All of the names that can be used inside the `allow` attribute: the lint names, the lint category names,
+/// and the deprecated names of the renamed or removed lint rules.
+fn declared_allows(lint_registry: &LintRegistry) -> Vec<String> {
+    lint_and_category_names(lint_registry)
+        .into_iter()
+        .chain(
+            lint_registry
+                .deprecated_lint_names()
+                .map(|deprecated_lint_name| deprecated_lint_name.name().to_string()),
+        )
+        .collect()
+}
+
+/// The names of all of the lint rules and the lint categories.
+fn lint_and_category_names(lint_registry: &LintRegistry) -> Vec<String> {
+    lint_registry
+        .allowed_names()
+        .into_iter()
+        .chain(get_unique_category_names())
+        .map(ToString::to_string)
+        .collect()
+}
+
+/// Attributes that set the level of the lint rules inside their scope.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum LevelAttributeKind {
+    Allow,
+    Warn,
+    Deny,
+    /// Works as `allow`, but the attribute itself is reported if it didn't suppress any diagnostic.
+    Expect,
+}
+
+impl LevelAttributeKind {
+    const ALL: [LevelAttributeKind; 4] = [
+        LevelAttributeKind::Allow,
+        LevelAttributeKind::Warn,
+        LevelAttributeKind::Deny,
+        LevelAttributeKind::Expect,
+    ];
+
+    fn name(&self) -> &'static str {
+        match self {
+            LevelAttributeKind::Allow => "allow",
+            LevelAttributeKind::Warn => "warn",
+            LevelAttributeKind::Deny => "deny",
+            LevelAttributeKind::Expect => "expect",
+        }
+    }
+
+    fn level(&self) -> LintLevel {
+        match self {
+            LevelAttributeKind::Allow | LevelAttributeKind::Expect => LintLevel::Allow,
+            LevelAttributeKind::Warn => LintLevel::Warn,
+            LevelAttributeKind::Deny => LintLevel::Deny,
+        }
+    }
+}
+
+/// A single lint name used inside a level attribute.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct AttributeLintName {
+    attribute_ptr: SyntaxStablePtrId,
+    name: String,
+}
+
+/// A level attribute which applies to a certain lint rule.
+struct LevelAttribute {
+    kind: LevelAttributeKind,
+    /// The attribute together with the name under which the lint rule appears in it.
+    lint_name: AttributeLintName,
+}
+
+/// Finds the level attribute closest to the node (or placed on the node itself), which
+/// contains any of the given names as an argument.
+/// If there is more than one matching attribute on the same node, the last one is taken.
+fn find_nearest_level_attribute(
     db: &dyn SyntaxGroup,
     node: SyntaxNode,
-    allowed_name: &'static str,
-) -> bool {
+    names: &[&str],
+) -> Option<LevelAttribute> {
     for node in node.ancestors_with_self(db) {
-        if node.has_attr_with_arg(db, "allow", allowed_name) {
-            return true;
+        let attribute = LevelAttributeKind::ALL
+            .iter()
+            .flat_map(|kind| {
+                node.query_attr(db, kind.name())
+                    .into_iter()
+                    .map(move |attribute| (*kind, attribute))
+            })
+            .filter_map(|(kind, attribute)| {
+                let name = attribute_arguments(db, &attribute)
+                    .into_iter()
+                    .map(|argument| argument.as_syntax_node().get_text_without_trivia(db))
+                    .find(|argument| names.contains(&argument.as_str()))?;
+                Some((kind, attribute, name))
+            })
+            .max_by_key(|(_, attribute, _)| attribute.as_syntax_node().span(db).start);
+
+        if let Some((kind, attribute, name)) = attribute {
+            return Some(LevelAttribute {
+                kind,
+                lint_name: AttributeLintName {
+                    attribute_ptr: attribute.as_syntax_node().stable_ptr(db),
+                    name,
+                },
+            });
+        }
+    }
+    None
+}
+
+/// Gets all the arguments of the attribute, e.g. `panic` and `clone_on_copy` for `#[allow(panic, clone_on_copy)]`.
+fn attribute_arguments(db: &dyn SyntaxGroup, attribute: &ast::Attribute) -> Vec<ast::Arg> {
+    match attribute.arguments(db) {
+        ast::OptionArgListParenthesized::ArgListParenthesized(arguments) => {
+            arguments.arguments(db).elements(db)
+        }
+        ast::OptionArgListParenthesized::Empty(_) => Vec::new(),
+    }
+}
+
+/// Finds all of the level attributes placed on the items or inside of them.
+/// Only the attributes placed on the submodules themselves are found, as the items in their bodies are linted separately.
+fn find_level_attributes(
+    db: &dyn SemanticGroup,
+    items: &[ModuleItemId],
+) -> Vec<(LevelAttributeKind, ast::Attribute)> {
+    let syntax_db: &dyn SyntaxGroup = db.upcast();
+    let mut attributes = Vec::new();
+    for item in items {
+        let item_node = item.stable_location(db.upcast()).syntax_node(db.upcast());
+        let searched_node = match item {
+            ModuleItemId::Submodule(_) => ast::ItemModule::from_syntax_node(syntax_db, item_node)
+                .attributes(syntax_db)
+                .as_syntax_node(),
+            _ => item_node,
+        };
+        for node in searched_node.descendants(syntax_db) {
+            if node.kind(syntax_db) != SyntaxKind::Attribute {
+                continue;
+            }
+            let attribute = ast::Attribute::from_syntax_node(syntax_db, node);
+            let attribute_name = attribute
+                .attr(syntax_db)
+                .as_syntax_node()
+                .get_text_without_trivia(syntax_db);
+            if let Some(kind) = LevelAttributeKind::ALL
+                .into_iter()
+                .find(|kind| kind.name() == attribute_name)
+            {
+                attributes.push((kind, attribute));
+            }
+        }
+    }
+    attributes
+}
+
+/// The level attributes which apply to anything inside a single module item.
+struct ItemLevelAttributes {
+    item_node: SyntaxNode,
+    /// The names used in the attributes placed on the item, inside of it, or on any of the modules around it.
+    names: HashSet<String>,
+    /// The names used in the attributes other than `allow`, placed on the item or inside of it.
+    inner_non_allow_names: HashSet<String>,
+}
+
+impl ItemLevelAttributes {
+    fn new(db: &dyn SemanticGroup, item: &ModuleItemId) -> Self {
+        let syntax_db: &dyn SyntaxGroup = db.upcast();
+        let item_node = item.stable_location(db.upcast()).syntax_node(db.upcast());
+        let attribute_names = |attribute: &ast::Attribute| {
+            attribute_arguments(syntax_db, attribute)
+                .into_iter()
+                .map(|argument| argument.as_syntax_node().get_text_without_trivia(syntax_db))
+                .collect_vec()
+        };
+
+        let inner_attributes = find_level_attributes(db, &[*item]);
+        let inner_non_allow_names = inner_attributes
+            .iter()
+            .filter(|(kind, _)| *kind != LevelAttributeKind::Allow)
+            .flat_map(|(_, attribute)| attribute_names(attribute))
+            .collect();
+        let outer_attributes = item_node.ancestors(syntax_db).flat_map(|node| {
+            LevelAttributeKind::ALL
+                .iter()
+                .flat_map(move |kind| node.query_attr(syntax_db, kind.name()))
+        });
+        let names = inner_attributes
+            .into_iter()
+            .map(|(_, attribute)| attribute)
+            .chain(outer_attributes)
+            .flat_map(|attribute| attribute_names(&attribute))
+            .collect();
+
+        Self {
+            item_node,
+            names,
+            inner_non_allow_names,
+        }
+    }
+
+    fn names_any(&self, names: &[&str]) -> bool {
+        names.iter().any(|name| self.names.contains(*name))
+    }
+}
+
+/// Calls the `callback` for every known lint (or category) name used inside the level attributes of the given kind.
+fn for_each_known_lint_name(
+    db: &dyn SyntaxGroup,
+    level_attributes: &[(LevelAttributeKind, ast::Attribute)],
+    known_names: &[String],
+    kind: LevelAttributeKind,
+    mut callback: impl FnMut(AttributeLintName, ast::Arg),
+) {
+    for (attribute_kind, attribute) in level_attributes {
+        if *attribute_kind != kind {
+            continue;
+        }
+        let attribute_ptr = attribute.as_syntax_node().stable_ptr(db);
+        for argument in attribute_arguments(db, attribute) {
+            let name = argument.as_syntax_node().get_text_without_trivia(db);
+            if known_names.contains(&name) {
+                callback(
+                    AttributeLintName {
+                        attribute_ptr,
+                        name,
+                    },
+                    argument,
+                );
+            }
+        }
+    }
+}
+
+/// Creates diagnostics for all of the lint names in the `expect` attributes, which did not suppress any diagnostic.
+fn find_unfulfilled_expectations(
+    db: &dyn SyntaxGroup,
+    level_attributes: &[(LevelAttributeKind, ast::Attribute)],
+    known_names: &[String],
+    used_attributes: &HashSet<AttributeLintName>,
+) -> Vec<LintDiagnostic> {
+    let mut diagnostics = Vec::new();
+    for_each_known_lint_name(
+        db,
+        level_attributes,
+        known_names,
+        LevelAttributeKind::Expect,
+        |lint_name, _| {
+            if !used_attributes.contains(&lint_name) {
+                diagnostics.push(LintDiagnostic::new(
+                    &UnfulfilledLintExpectation,
+                    lint_name.attribute_ptr,
+                ));
+            }
+        },
+    );
+    diagnostics
+}
+
+/// Creates diagnostics for all of the lint names in the `allow` attributes, which did not suppress any diagnostic.
+fn find_unused_allows(
+    db: &dyn SyntaxGroup,
+    level_attributes: &[(LevelAttributeKind, ast::Attribute)],
+    known_names: &[String],
+    used_attributes: &HashSet<AttributeLintName>,
+) -> Vec<LintDiagnostic> {
+    let mut diagnostics = Vec::new();
+    for_each_known_lint_name(
+        db,
+        level_attributes,
+        known_names,
+        LevelAttributeKind::Allow,
+        |lint_name, argument| {
+            // Allowing this lint is checked only after all of the other diagnostics are reported.
+            if lint_name.name != UnusedLintAllow.allowed_name()
+                && !used_attributes.contains(&lint_name)
+            {
+                diagnostics.push(LintDiagnostic::new(
+                    &UnusedLintAllow,
+                    argument.as_syntax_node().stable_ptr(db),
+                ));
+            }
+        },
+    );
+    diagnostics
+}
+
+/// Minimal similarity of an unknown lint name to a known one, for the known name to be suggested instead.
+const LINT_NAME_SIMILARITY_THRESHOLD: f64 = 0.7;
+
+/// Creates diagnostics for all of the unknown lint names used inside the level attributes.
+/// Unknown names inside the `allow` attribute are reported only if there is a similar known name,
+/// as the other ones may be used by the compiler or other plugins.
+fn find_unknown_lint_names(
+    db: &dyn SyntaxGroup,
+    level_attributes: &[(LevelAttributeKind, ast::Attribute)],
+    known_names: &[String],
+    lint_registry: &LintRegistry,
+) -> Vec<LintDiagnostic> {
+    let mut diagnostics = Vec::new();
+    for (kind, attribute) in level_attributes {
+        for argument in attribute_arguments(db, attribute) {
+            let name = argument.as_syntax_node().get_text_without_trivia(db);
+            // The deprecated names are reported separately, see [`find_deprecated_lint_names`].
+            if known_names.contains(&name)
+                || lint_registry.find_deprecated_lint_name(&name).is_some()
+            {
+                continue;
+            }
+            let suggestion = known_names
+                .iter()
+                .map(|known_name| {
+                    (
+                        known_name,
+                        strsim::normalized_levenshtein(&name, known_name),
+                    )
+                })
+                .filter(|(_, similarity)| *similarity >= LINT_NAME_SIMILARITY_THRESHOLD)
+                .max_by(|(_, a), (_, b)| a.total_cmp(b))
+                .map(|(known_name, _)| known_name);
+            let message = match suggestion {
+                Some(suggestion) => format!(
+                    "{} Did you mean `{}`?",
+                    UnknownLintName.formatted_message(),
+                    suggestion
+                ),
+                None if *kind == LevelAttributeKind::Allow => continue,
+                None => UnknownLintName.formatted_message(),
+            };
+            diagnostics.push(
+                LintDiagnostic::new(&UnknownLintName, argument.as_syntax_node().stable_ptr(db))
+                    .with_message(message),
+            );
         }
     }
-    false
+    diagnostics
 }
 
-fn validate_cairo_lint_metadata(tool_metadata: &CairoLintToolMetadata) -> Result<()> {
-    for (name, _) in tool_metadata.iter() {
-        if !get_unique_allowed_names().contains(&name.as_str()) {
-            return Err(anyhow!(
-                "The lint '{}' specified in `Scarb.toml` is not supported by the Cairo lint.",
-                name
-            ));
+/// Creates diagnostics for all of the names of the renamed or removed lint rules used inside the level attributes.
+fn find_deprecated_lint_names(
+    db: &dyn SyntaxGroup,
+    level_attributes: &[(LevelAttributeKind, ast::Attribute)],
+    lint_registry: &LintRegistry,
+) -> Vec<LintDiagnostic> {
+    let mut diagnostics = Vec::new();
+    for (_, attribute) in level_attributes {
+        for argument in attribute_arguments(db, attribute) {
+            let name = argument.as_syntax_node().get_text_without_trivia(db);
+            let Some(deprecated_lint_name) = lint_registry.find_deprecated_lint_name(&name) else {
+                continue;
+            };
+            let lint: &'static dyn Lint = match deprecated_lint_name {
+                DeprecatedLintName::Renamed { .. } => &RenamedLintName,
+                DeprecatedLintName::Removed { .. } => &RemovedLintName,
+            };
+            diagnostics.push(
+                LintDiagnostic::new(lint, argument.as_syntax_node().stable_ptr(db))
+                    .with_message(deprecated_lint_name.message()),
+            );
+        }
+    }
+    diagnostics
+}
+
+fn validate_cairo_lint_metadata(
+    tool_metadata: &CairoLintConfig,
+    lint_registry: &LintRegistry,
+) -> Result<()> {
+    let allowed_names = lint_registry.allowed_names();
+    for (name, config) in tool_metadata.iter() {
+        // The call pattern rules are validated when they are registered.
+        if name == CALL_PATTERNS_KEY {
+            continue;
+        }
+        // The deprecated names are still accepted, so the existing configurations don't break.
+        // They are reported by the plugin instead, see [`CairoLint::find_deprecated_lint_names_in_metadata`].
+        let lint_name = match lint_registry.find_deprecated_lint_name(name) {
+            Some(DeprecatedLintName::Renamed { new_name, .. }) => Some(new_name),
+            Some(DeprecatedLintName::Removed { .. }) => None,
+            None if !allowed_names.contains(&name.as_str())
+                && LintCategory::from_name(name).is_none() =>
+            {
+                return Err(anyhow!(
+                    "The lint '{}' specified in `Scarb.toml` is not supported by the Cairo lint.",
+                    name
+                ));
+            }
+            None => Some(name.as_str()),
+        };
+        let invalid_configuration = |err| {
+            anyhow!(
+                "Invalid configuration of the lint '{}' in `Scarb.toml`: {}",
+                name,
+                err
+            )
+        };
+        config.level().map_err(invalid_configuration)?;
+        // The options of the removed lint rules are ignored, same as their levels.
+        if let (Some(lint_name), Some(options)) = (lint_name, config.options()) {
+            LintOptions::default()
+                .set(lint_name, options)
+                .map_err(invalid_configuration)?;
         }
     }
     Ok(())
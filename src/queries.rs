@@ -3,8 +3,8 @@ use std::sync::Arc;
 use cairo_lang_defs::ids::{FunctionWithBodyId, ModuleItemId};
 use cairo_lang_semantic::db::SemanticGroup;
 use cairo_lang_semantic::{
-    Arenas, Expr, ExprFunctionCall, ExprIf, ExprLogicalOperator, ExprLoop, ExprMatch, ExprWhile,
-    FunctionBody, Pattern, Statement, StatementBreak,
+    Arenas, Expr, ExprEnumVariantCtor, ExprFunctionCall, ExprIf, ExprLogicalOperator, ExprLoop,
+    ExprMatch, ExprWhile, FunctionBody, Pattern, Statement, StatementBreak,
 };
 use cairo_lang_syntax::node::ast::Expr as AstExpr;
 use cairo_lang_syntax::node::kind::SyntaxKind;
@@ -12,6 +12,8 @@ use cairo_lang_syntax::node::TypedStablePtr;
 use cairo_lang_syntax::node::TypedSyntaxNode;
 use if_chain::if_chain;
 
+use crate::context::CheckContext;
+use crate::diagnostics::LintDiagnostic;
 use crate::helper::{ASSERT_FORMATTER_NAME, ASSERT_PATH};
 
 pub fn get_all_checkable_functions(
@@ -77,112 +79,164 @@ pub fn get_all_parenthesized_expressions(
         .collect()
 }
 
-pub fn get_all_match_expressions(function_body: &Arc<FunctionBody>) -> Vec<ExprMatch> {
-    function_body
-        .arenas
-        .exprs
-        .iter()
-        .filter_map(|(_expression_id, expression)| {
-            if let Expr::Match(expr_match) = expression {
-                Some(expr_match.clone())
-            } else {
-                None
-            }
-        })
-        .collect()
-}
+/// A callback fired by [`walk_function_bodies`] for a single expression (or statement) of a function body.
+pub type VisitorCallback<T> =
+    fn(&dyn SemanticGroup, &T, &Arenas, &CheckContext, &mut Vec<LintDiagnostic>);
 
-pub fn get_all_loop_expressions(function_body: &Arc<FunctionBody>) -> Vec<ExprLoop> {
-    function_body
-        .arenas
-        .exprs
-        .iter()
-        .filter_map(|(_expression_id, expression)| {
-            if let Expr::Loop(expr_loop) = expression {
-                Some(expr_loop.clone())
-            } else {
-                None
-            }
-        })
-        .collect()
+/// Callbacks of a lint rule group, fired by [`walk_function_bodies`] for the expressions and statements
+/// of the function bodies. Only the kinds of nodes with a callback set are dispatched to the group.
+#[derive(Clone, Copy, Default)]
+pub struct FunctionBodyVisitor {
+    pub on_if: Option<VisitorCallback<ExprIf>>,
+    pub on_match: Option<VisitorCallback<ExprMatch>>,
+    pub on_call: Option<VisitorCallback<ExprFunctionCall>>,
+    /// Fired for the calls of the binary operators, e.g. `a == b` or `a & 1`, after [`Self::on_call`].
+    pub on_binary_op: Option<VisitorCallback<ExprFunctionCall>>,
+    pub on_loop: Option<VisitorCallback<ExprLoop>>,
+    pub on_while: Option<VisitorCallback<ExprWhile>>,
+    pub on_logical_op: Option<VisitorCallback<ExprLogicalOperator>>,
+    pub on_enum_variant_ctor: Option<VisitorCallback<ExprEnumVariantCtor>>,
+    pub on_break: Option<VisitorCallback<StatementBreak>>,
 }
 
-pub fn get_all_function_calls(
-    function_body: &Arc<FunctionBody>,
-) -> impl Iterator<Item = ExprFunctionCall> + '_ {
-    function_body
-        .arenas
-        .exprs
-        .iter()
-        .filter_map(|(_expression_id, expression)| {
-            if let Expr::FunctionCall(expr_func) = expression {
-                Some(expr_func.clone())
-            } else {
-                None
+impl FunctionBodyVisitor {
+    fn visit_expr(
+        &self,
+        db: &dyn SemanticGroup,
+        expression: &Expr,
+        is_binary_op: bool,
+        arenas: &Arenas,
+        context: &CheckContext,
+        diagnostics: &mut Vec<LintDiagnostic>,
+    ) {
+        match expression {
+            Expr::If(expr_if) => fire(self.on_if, db, expr_if, arenas, context, diagnostics),
+            Expr::Match(expr_match) => {
+                fire(self.on_match, db, expr_match, arenas, context, diagnostics)
             }
-        })
-}
-
-pub fn get_all_logical_operator_expressions(
-    function_body: &Arc<FunctionBody>,
-) -> Vec<ExprLogicalOperator> {
-    function_body
-        .arenas
-        .exprs
-        .iter()
-        .filter_map(|(_expression_id, expression)| {
-            if let Expr::LogicalOperator(expr_logical_operator) = expression {
-                Some(expr_logical_operator.clone())
-            } else {
-                None
+            Expr::FunctionCall(expr_func) => {
+                fire(self.on_call, db, expr_func, arenas, context, diagnostics);
+                if is_binary_op {
+                    fire(
+                        self.on_binary_op,
+                        db,
+                        expr_func,
+                        arenas,
+                        context,
+                        diagnostics,
+                    );
+                }
             }
-        })
-        .collect()
+            Expr::Loop(expr_loop) => {
+                fire(self.on_loop, db, expr_loop, arenas, context, diagnostics)
+            }
+            Expr::While(expr_while) => {
+                fire(self.on_while, db, expr_while, arenas, context, diagnostics)
+            }
+            Expr::LogicalOperator(expr_logical_operator) => fire(
+                self.on_logical_op,
+                db,
+                expr_logical_operator,
+                arenas,
+                context,
+                diagnostics,
+            ),
+            Expr::EnumVariantCtor(expr_enum_variant_ctor) => fire(
+                self.on_enum_variant_ctor,
+                db,
+                expr_enum_variant_ctor,
+                arenas,
+                context,
+                diagnostics,
+            ),
+            _ => {}
+        }
+    }
+
+    fn visit_statement(
+        &self,
+        db: &dyn SemanticGroup,
+        statement: &Statement,
+        arenas: &Arenas,
+        context: &CheckContext,
+        diagnostics: &mut Vec<LintDiagnostic>,
+    ) {
+        if let Statement::Break(statement_break) = statement {
+            fire(
+                self.on_break,
+                db,
+                statement_break,
+                arenas,
+                context,
+                diagnostics,
+            );
+        }
+    }
 }
 
-pub fn get_all_if_expressions(function_body: &Arc<FunctionBody>) -> Vec<ExprIf> {
-    function_body
-        .arenas
-        .exprs
-        .iter()
-        .filter_map(|(_expression_id, expression)| {
-            if let Expr::If(expr_if) = expression {
-                Some(expr_if.clone())
-            } else {
-                None
-            }
-        })
-        .collect()
+fn fire<T>(
+    callback: Option<VisitorCallback<T>>,
+    db: &dyn SemanticGroup,
+    node: &T,
+    arenas: &Arenas,
+    context: &CheckContext,
+    diagnostics: &mut Vec<LintDiagnostic>,
+) {
+    if let Some(callback) = callback {
+        callback(db, node, arenas, context, diagnostics);
+    }
 }
 
-pub fn get_all_while_expressions(function_body: &Arc<FunctionBody>) -> Vec<ExprWhile> {
-    function_body
-        .arenas
-        .exprs
+/// Walks every checkable function body of the item once, dispatching each expression and statement
+/// to the callbacks of all of the visitors.
+/// The diagnostics are returned separately for every visitor, in the order of the visitors.
+pub fn walk_function_bodies(
+    db: &dyn SemanticGroup,
+    item: &ModuleItemId,
+    visitors: &[FunctionBodyVisitor],
+    context: &CheckContext,
+) -> Vec<Vec<LintDiagnostic>> {
+    let mut diagnostics: Vec<Vec<LintDiagnostic>> = visitors.iter().map(|_| Vec::new()).collect();
+    if visitors.is_empty() {
+        return diagnostics;
+    }
+    let visits_binary_ops = visitors
         .iter()
-        .filter_map(|(_expression_id, expression)| {
-            if let Expr::While(expr_while) = expression {
-                Some(expr_while.clone())
-            } else {
-                None
+        .any(|visitor| visitor.on_binary_op.is_some());
+
+    for function_body in get_all_function_bodies(db, item) {
+        let arenas = &function_body.arenas;
+        for (_expression_id, expression) in arenas.exprs.iter() {
+            // Looking up the syntax of the call is not free, so it is done once for all of the visitors.
+            let is_binary_op = match expression {
+                Expr::FunctionCall(expr_func) => {
+                    visits_binary_ops && is_binary_operator_call(db, expr_func)
+                }
+                _ => false,
+            };
+            for (visitor, visitor_diagnostics) in visitors.iter().zip(diagnostics.iter_mut()) {
+                visitor.visit_expr(
+                    db,
+                    expression,
+                    is_binary_op,
+                    arenas,
+                    context,
+                    visitor_diagnostics,
+                );
             }
-        })
-        .collect()
+        }
+        for (_statement_id, statement) in arenas.statements.iter() {
+            for (visitor, visitor_diagnostics) in visitors.iter().zip(diagnostics.iter_mut()) {
+                visitor.visit_statement(db, statement, arenas, context, visitor_diagnostics);
+            }
+        }
+    }
+    diagnostics
 }
 
-pub fn get_all_break_statements(function_body: &Arc<FunctionBody>) -> Vec<StatementBreak> {
-    function_body
-        .arenas
-        .statements
-        .iter()
-        .filter_map(|(_statement_id, statement)| {
-            if let Statement::Break(statement_break) = statement {
-                Some(statement_break.clone())
-            } else {
-                None
-            }
-        })
-        .collect()
+/// Checks if the function call comes from a binary operator, e.g. `a + b`, rather than from an explicit call.
+fn is_binary_operator_call(db: &dyn SemanticGroup, expr_func: &ExprFunctionCall) -> bool {
+    matches!(expr_func.stable_ptr.lookup(db.upcast()), AstExpr::Binary(_))
 }
 
 /// This function checks if the given `if` expression is an assert macro call.
@@ -0,0 +1,364 @@
+//! # SARIF output for Cairo Lint
+//!
+//! This module exports the diagnostics of the Cairo lint in the [SARIF 2.1.0] format, consumed by
+//! the code scanning dashboards. The rules of the report are built from all the lint rules of the
+//! lint registry, including the custom ones, and every diagnostic of the Cairo lint plugin is mapped
+//! to a result, together with the fix suggested for it.
+//!
+//! [SARIF 2.1.0]: https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html
+
+use std::collections::HashMap;
+use std::path::Path;
+
+use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_semantic::diagnostic::SemanticDiagnosticKind;
+use cairo_lang_semantic::SemanticDiagnostic;
+use serde::Serialize;
+
+use crate::config::LintLevel;
+use crate::context::{get_all_lint_rules, Lint, LintDocs, LintRegistry};
+use crate::diagnostics::{JsonDiagnostic, JsonNote, JsonSpan, JsonSuggestion};
+use crate::explain::LintExplanation;
+use crate::CAIRO_LINT_TOOL_NAME;
+
+const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+const SARIF_VERSION: &str = "2.1.0";
+const CAIRO_LINT_INFORMATION_URI: &str = "https://docs.swmansion.com/cairo-lint/";
+const LINT_DOCS_BASE_URI: &str = "https://docs.swmansion.com/cairo-lint/docs/lints/";
+
+#[derive(Debug, Serialize)]
+pub struct SarifLog {
+    #[serde(rename = "$schema")]
+    pub schema: String,
+    pub version: String,
+    pub runs: Vec<SarifRun>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct SarifRun {
+    pub tool: SarifTool,
+    pub results: Vec<SarifResult>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct SarifTool {
+    pub driver: SarifToolComponent,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SarifToolComponent {
+    pub name: String,
+    pub version: String,
+    pub information_uri: String,
+    pub rules: Vec<SarifRule>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SarifRule {
+    pub id: String,
+    pub name: String,
+    pub short_description: SarifMessage,
+    /// The description of the lint rule from its documentation, if the rule is documented.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub full_description: Option<SarifMessage>,
+    /// The full documentation of the lint rule, if the rule is documented.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub help: Option<SarifMultiformatMessage>,
+    /// The page of the lint rule on the documentation website, only for the predefined rules.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub help_uri: Option<String>,
+    pub default_configuration: SarifRuleConfiguration,
+    pub properties: SarifRuleProperties,
+}
+
+#[derive(Debug, Serialize)]
+pub struct SarifRuleConfiguration {
+    pub enabled: bool,
+    pub level: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct SarifRuleProperties {
+    pub category: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SarifResult {
+    pub rule_id: String,
+    pub rule_index: usize,
+    pub level: String,
+    /// The message of the diagnostic, followed by its `note:` and `help:` lines.
+    pub message: SarifMessage,
+    pub locations: Vec<SarifLocation>,
+    /// The labeled locations of the other relevant pieces of code.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub related_locations: Vec<SarifLocation>,
+    pub fixes: Vec<SarifFix>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct SarifMessage {
+    pub text: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct SarifMultiformatMessage {
+    pub text: String,
+    pub markdown: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SarifLocation {
+    pub physical_location: SarifPhysicalLocation,
+    /// The label of the location, only for the related locations.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<SarifMessage>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SarifPhysicalLocation {
+    pub artifact_location: SarifArtifactLocation,
+    pub region: SarifRegion,
+}
+
+#[derive(Debug, Serialize)]
+pub struct SarifArtifactLocation {
+    pub uri: String,
+}
+
+/// Region of the file. Lines and columns are 1-based.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SarifRegion {
+    pub start_line: usize,
+    pub start_column: usize,
+    pub end_line: usize,
+    pub end_column: usize,
+    pub byte_offset: usize,
+    pub byte_length: usize,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SarifFix {
+    pub description: SarifMessage,
+    pub artifact_changes: Vec<SarifArtifactChange>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SarifArtifactChange {
+    pub artifact_location: SarifArtifactLocation,
+    pub replacements: Vec<SarifReplacement>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SarifReplacement {
+    pub deleted_region: SarifRegion,
+    pub inserted_content: SarifMessage,
+}
+
+impl SarifLog {
+    /// Creates the SARIF report with a single run of the Cairo lint.
+    /// The diagnostics which do not come from the rules of the lint registry are skipped.
+    pub fn new(
+        diagnostics: &[SemanticDiagnostic],
+        db: &(dyn SemanticGroup + 'static),
+        lint_registry: &LintRegistry,
+    ) -> Self {
+        let rules = lint_registry
+            .lints()
+            .map(|lint| SarifRule {
+                id: lint.id().to_string(),
+                name: lint.allowed_name().to_string(),
+                short_description: SarifMessage {
+                    text: lint.diagnostic_message().to_string(),
+                },
+                full_description: documented(lint).map(|docs| SarifMessage {
+                    text: docs.description.to_string(),
+                }),
+                help: documented(lint).map(|docs| SarifMultiformatMessage {
+                    text: docs.description.to_string(),
+                    markdown: LintExplanation::new(lint).docs_markdown(),
+                }),
+                help_uri: get_all_lint_rules()
+                    .any(|predefined_lint| predefined_lint.id() == lint.id())
+                    .then(|| format!("{LINT_DOCS_BASE_URI}{}.html", lint.allowed_name())),
+                default_configuration: SarifRuleConfiguration {
+                    enabled: lint.default_level() != LintLevel::Allow,
+                    level: match lint.default_level() {
+                        LintLevel::Deny | LintLevel::Forbid => "error",
+                        LintLevel::Allow | LintLevel::Warn => "warning",
+                    }
+                    .to_string(),
+                },
+                properties: SarifRuleProperties {
+                    category: lint.category().name().to_string(),
+                },
+            })
+            .collect::<Vec<_>>();
+        let rule_indices: HashMap<String, usize> = rules
+            .iter()
+            .enumerate()
+            .map(|(index, rule)| (rule.id.clone(), index))
+            .collect();
+
+        let results = diagnostics
+            .iter()
+            .filter(|diagnostic| {
+                matches!(diagnostic.kind, SemanticDiagnosticKind::PluginDiagnostic(_))
+            })
+            .map(|diagnostic| JsonDiagnostic::new(diagnostic, db, lint_registry))
+            .filter_map(|diagnostic| {
+                let rule_id = diagnostic.code.clone()?;
+                // The labels become the related locations, the other notes are a part of the message.
+                let (labels, notes): (Vec<&JsonNote>, Vec<&JsonNote>) = diagnostic
+                    .notes
+                    .iter()
+                    .partition(|note| note.span.is_some());
+                let mut text = diagnostic.message.clone();
+                for note in notes {
+                    text.push_str(&format!("\n{}: {}", note.kind, note.message));
+                }
+                Some(SarifResult {
+                    rule_index: *rule_indices.get(&rule_id)?,
+                    rule_id,
+                    level: diagnostic.severity.clone(),
+                    message: SarifMessage { text },
+                    locations: vec![SarifLocation::new(&diagnostic.file, &diagnostic.span, None)],
+                    related_locations: labels
+                        .into_iter()
+                        .filter_map(|note| {
+                            Some(SarifLocation::new(
+                                &diagnostic.file,
+                                note.span.as_ref()?,
+                                Some(&note.message),
+                            ))
+                        })
+                        .collect(),
+                    fixes: diagnostic
+                        .suggestions
+                        .iter()
+                        .map(|suggestion| SarifFix::new(suggestion, &diagnostic.file))
+                        .collect(),
+                })
+            })
+            .collect();
+
+        Self {
+            schema: SARIF_SCHEMA.to_string(),
+            version: SARIF_VERSION.to_string(),
+            runs: vec![SarifRun {
+                tool: SarifTool {
+                    driver: SarifToolComponent {
+                        name: CAIRO_LINT_TOOL_NAME.to_string(),
+                        version: env!("CARGO_PKG_VERSION").to_string(),
+                        information_uri: CAIRO_LINT_INFORMATION_URI.to_string(),
+                        rules,
+                    },
+                },
+                results,
+            }],
+        }
+    }
+}
+
+impl SarifArtifactLocation {
+    /// Absolute paths are turned into the `file` URIs, relative ones are left as they are.
+    fn new(path: &str) -> Self {
+        let uri = path.replace('\\', "/");
+        let uri = if !Path::new(path).is_absolute() {
+            uri
+        } else if uri.starts_with('/') {
+            format!("file://{uri}")
+        } else {
+            // Windows paths start with the drive letter.
+            format!("file:///{uri}")
+        };
+        Self { uri }
+    }
+}
+
+impl SarifLocation {
+    fn new(file: &str, span: &JsonSpan, label: Option<&str>) -> Self {
+        Self {
+            physical_location: SarifPhysicalLocation {
+                artifact_location: SarifArtifactLocation::new(file),
+                region: SarifRegion::new(span),
+            },
+            message: label.map(|label| SarifMessage {
+                text: label.to_string(),
+            }),
+        }
+    }
+}
+
+impl SarifRegion {
+    fn new(span: &JsonSpan) -> Self {
+        Self {
+            start_line: span.line_start,
+            start_column: span.column_start,
+            end_line: span.line_end,
+            end_column: span.column_end,
+            byte_offset: span.byte_start,
+            byte_length: span.byte_end - span.byte_start,
+        }
+    }
+}
+
+impl SarifFix {
+    fn new(suggestion: &JsonSuggestion, file: &str) -> Self {
+        Self {
+            description: SarifMessage {
+                text: suggestion.title.clone(),
+            },
+            artifact_changes: vec![SarifArtifactChange {
+                artifact_location: SarifArtifactLocation::new(file),
+                replacements: suggestion
+                    .edits
+                    .iter()
+                    .map(|edit| SarifReplacement {
+                        deleted_region: SarifRegion::new(&edit.span),
+                        inserted_content: SarifMessage {
+                            text: edit.replacement.clone(),
+                        },
+                    })
+                    .collect(),
+            }],
+        }
+    }
+}
+
+/// Formats the diagnostics as a SARIF 2.1.0 report.
+///
+/// See [`SarifLog::new`] for the details.
+pub fn format_diagnostics_as_sarif(
+    diagnostics: &[SemanticDiagnostic],
+    db: &(dyn SemanticGroup + 'static),
+) -> String {
+    format_diagnostics_as_sarif_with_lint_registry(diagnostics, db, &LintRegistry::default())
+}
+
+/// Formats the diagnostics as a SARIF 2.1.0 report, taking the custom lint rules from the registry into account.
+///
+/// See [`SarifLog::new`] for the details.
+pub fn format_diagnostics_as_sarif_with_lint_registry(
+    diagnostics: &[SemanticDiagnostic],
+    db: &(dyn SemanticGroup + 'static),
+    lint_registry: &LintRegistry,
+) -> String {
+    // Serializing a struct with string keys cannot fail.
+    serde_json::to_string_pretty(&SarifLog::new(diagnostics, db, lint_registry)).unwrap()
+}
+
+/// The documentation of the lint rule, or `None` if the rule is not documented.
+fn documented(lint: &dyn Lint) -> Option<LintDocs> {
+    let docs = lint.docs();
+    (!docs.description.is_empty()).then_some(docs)
+}
@@ -0,0 +1,196 @@
+use std::path::Path;
+
+use cairo_lang_defs::ids::{LanguageElementId, ModuleItemId};
+use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lint::baseline::Baseline;
+use cairo_lint::context::{
+    CairoLintKind, CheckContext, Lint, LintCategory, LintRegistry, LintRuleGroup,
+};
+use cairo_lint::diagnostics::LintDiagnostic;
+use cairo_lint::plugin::CairoLint;
+
+use crate::helpers::{
+    get_cairo_lint_tool_metadata_with_all_lints_enabled, get_diags, get_diags_and_lint_registry,
+    setup_lint_db_with_plugin, tool_metadata,
+};
+
+const CLONE_ON_COPY: &str = r#"
+fn main() {
+    let a: felt252 = 'hello';
+    let _b = a.clone();
+}
+"#;
+
+const SHIFTED_CLONE_ON_COPY: &str = r#"
+fn foo() {}
+
+fn main() {
+    let a: felt252 = 'hello';
+
+    let _b = a.clone();
+}
+"#;
+
+const NEW_CLONE_ON_COPY: &str = r#"
+fn main() {
+    let a: felt252 = 'hello';
+    let _b = a.clone();
+}
+
+fn foo() {
+    let a: felt252 = 'hello';
+    let _b = a.clone();
+}
+"#;
+
+const FIXED_CLONE_ON_COPY: &str = r#"
+fn main() {
+    let a: felt252 = 'hello';
+    let _b = a;
+}
+"#;
+
+const FORBIDDEN_FUNCTION: &str = r#"
+fn forbidden() {}
+"#;
+
+const SHIFTED_FORBIDDEN_FUNCTION: &str = r#"
+fn main() {}
+
+fn forbidden() {}
+"#;
+
+struct ForbiddenFunction;
+
+impl Lint for ForbiddenFunction {
+    fn allowed_name(&self) -> &'static str {
+        "forbidden_functions"
+    }
+
+    fn id(&self) -> &'static str {
+        "FF0001"
+    }
+
+    fn diagnostic_message(&self) -> &'static str {
+        "Function named `forbidden` found."
+    }
+
+    fn kind(&self) -> CairoLintKind {
+        CairoLintKind::Custom
+    }
+
+    fn category(&self) -> LintCategory {
+        LintCategory::Correctness
+    }
+}
+
+fn check_forbidden_functions(
+    db: &dyn SemanticGroup,
+    item: &ModuleItemId,
+    _context: &CheckContext,
+    diagnostics: &mut Vec<LintDiagnostic>,
+) {
+    if let ModuleItemId::FreeFunction(function_id) = item {
+        if function_id.name(db.upcast()) == "forbidden" {
+            diagnostics.push(LintDiagnostic::new(
+                &ForbiddenFunction,
+                function_id.untyped_stable_ptr(db.upcast()),
+            ));
+        }
+    }
+}
+
+fn custom_lint_baseline_and_comparison(code: &str, changed_code: &str) -> (Baseline, usize, usize) {
+    let mut lint_registry = LintRegistry::default();
+    lint_registry
+        .register(LintRuleGroup::new(
+            vec![Box::new(ForbiddenFunction)],
+            check_forbidden_functions,
+        ))
+        .unwrap();
+    let plugin = CairoLint::new(true, tool_metadata(&[])).with_lint_registry(lint_registry.clone());
+    let (mut db, crate_id) = setup_lint_db_with_plugin(code, plugin);
+    let diags = get_diags(crate_id, &mut db);
+    let baseline = Baseline::new(&db, &diags, Path::new(""), &lint_registry);
+
+    let plugin = CairoLint::new(true, tool_metadata(&[])).with_lint_registry(lint_registry.clone());
+    let (mut db, crate_id) = setup_lint_db_with_plugin(changed_code, plugin);
+    let diags = get_diags(crate_id, &mut db);
+    let comparison = baseline.compare(&db, diags, Path::new(""), &lint_registry);
+    (
+        baseline,
+        comparison.new_diagnostics.len(),
+        comparison.stale_entries.len(),
+    )
+}
+
+fn baseline(code: &str) -> Baseline {
+    let (db, diags, lint_registry) =
+        get_diags_and_lint_registry(code, get_cairo_lint_tool_metadata_with_all_lints_enabled());
+    Baseline::new(&db, &diags, Path::new(""), &lint_registry)
+}
+
+fn compare(baseline: &Baseline, code: &str) -> (Vec<String>, usize) {
+    let (db, diags, lint_registry) =
+        get_diags_and_lint_registry(code, get_cairo_lint_tool_metadata_with_all_lints_enabled());
+    let comparison = baseline.compare(&db, diags, Path::new(""), &lint_registry);
+    let new_diagnostics = comparison
+        .new_diagnostics
+        .iter()
+        .map(|diag| cairo_lint::diagnostics::format_diagnostic(diag, &db))
+        .collect();
+    (new_diagnostics, comparison.stale_entries.len())
+}
+
+#[test]
+fn baseline_entries() {
+    let baseline = baseline(CLONE_ON_COPY);
+    assert_eq!(baseline.entries.len(), 1);
+    assert_eq!(baseline.entries[0].lint, "clone_on_copy");
+    assert_eq!(baseline.entries[0].file, "lib.cairo");
+}
+
+#[test]
+fn baseline_suppresses_existing_findings() {
+    let baseline = baseline(CLONE_ON_COPY);
+    assert_eq!(compare(&baseline, CLONE_ON_COPY), (vec![], 0));
+}
+
+#[test]
+fn baseline_survives_line_shifts() {
+    let baseline = baseline(CLONE_ON_COPY);
+    assert_eq!(compare(&baseline, SHIFTED_CLONE_ON_COPY), (vec![], 0));
+}
+
+#[test]
+fn new_findings_are_reported() {
+    let baseline = baseline(CLONE_ON_COPY);
+    let (new_diagnostics, stale_entries) = compare(&baseline, NEW_CLONE_ON_COPY);
+    assert_eq!(new_diagnostics.len(), 1);
+    assert!(new_diagnostics[0].contains("lib.cairo:9:14"));
+    assert_eq!(stale_entries, 0);
+}
+
+#[test]
+fn fixed_findings_are_stale() {
+    let baseline = baseline(CLONE_ON_COPY);
+    assert_eq!(compare(&baseline, FIXED_CLONE_ON_COPY), (vec![], 1));
+}
+
+#[test]
+fn baseline_file_roundtrip() {
+    let baseline = baseline(CLONE_ON_COPY);
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("cairo-lint-baseline.json");
+    baseline.save(&path).unwrap();
+    assert_eq!(Baseline::load(&path).unwrap(), baseline);
+}
+
+#[test]
+fn baseline_records_custom_lints() {
+    let (baseline, new_diagnostics, stale_entries) =
+        custom_lint_baseline_and_comparison(FORBIDDEN_FUNCTION, SHIFTED_FORBIDDEN_FUNCTION);
+    assert_eq!(baseline.entries.len(), 1);
+    assert_eq!(baseline.entries[0].lint, "forbidden_functions");
+    assert_eq!((new_diagnostics, stale_entries), (0, 0));
+}
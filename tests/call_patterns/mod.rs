@@ -0,0 +1,260 @@
+use cairo_lang_diagnostics::{DiagnosticEntry, Severity};
+use cairo_lang_semantic::diagnostic::SemanticDiagnosticKind;
+use cairo_lint::config::{CallPatternConfig, LintConfig, LintLevel};
+use cairo_lint::context::{Lint, LintRegistry};
+use cairo_lint::lints::call_patterns::CallPatternRule;
+use cairo_lint::plugin::{cairo_lint_plugin_suite, CairoLint};
+use cairo_lint::CairoLintConfig;
+
+use crate::helpers::{get_diags, setup_lint_db_with_plugin, tool_metadata};
+
+const UNSAFE_HELPER_MESSAGE: &str = "Calling `unsafe_helper` is forbidden.";
+const FORMATTED_UNSAFE_HELPER_MESSAGE: &str =
+    "[no_unsafe_helper] Calling `unsafe_helper` is forbidden.";
+
+const UNSAFE_HELPER_CALLS: &str = r#"
+fn unsafe_helper() {}
+
+fn main() {
+    unsafe_helper();
+}
+
+mod upgrades {
+    fn upgrade() {
+        super::unsafe_helper();
+    }
+}
+"#;
+
+const ALLOWED_UNSAFE_HELPER_CALL: &str = r#"
+fn unsafe_helper() {}
+
+#[allow(no_unsafe_helper)]
+fn main() {
+    unsafe_helper();
+}
+"#;
+
+const HELPERS_MODULE_CALLS: &str = r#"
+mod helpers {
+    pub fn first() {}
+    pub fn second() {}
+}
+
+fn main() {
+    helpers::first();
+    helpers::second();
+}
+"#;
+
+fn unsafe_helper_rule() -> CallPatternConfig {
+    CallPatternConfig {
+        name: "no_unsafe_helper".to_string(),
+        function: "test::unsafe_helper".to_string(),
+        message: UNSAFE_HELPER_MESSAGE.to_string(),
+        level: None,
+        category: None,
+        modules: Vec::new(),
+        excluded_modules: Vec::new(),
+    }
+}
+
+fn call_pattern_diagnostics(
+    code: &str,
+    rule: CallPatternConfig,
+    tool_metadata: CairoLintConfig,
+) -> Vec<(String, Severity)> {
+    let mut lint_registry = LintRegistry::default();
+    lint_registry.register_call_pattern(rule).unwrap();
+    let plugin = CairoLint::new(true, tool_metadata).with_lint_registry(lint_registry);
+    let (mut db, crate_id) = setup_lint_db_with_plugin(code, plugin);
+    get_diags(crate_id, &mut db)
+        .into_iter()
+        .map(|diag| match &diag.kind {
+            SemanticDiagnosticKind::PluginDiagnostic(plugin_diag) => {
+                (plugin_diag.message.clone(), diag.severity())
+            }
+            kind => panic!("Unexpected diagnostic: {kind:?}"),
+        })
+        .collect()
+}
+
+#[test]
+fn call_pattern_reports_all_calls() {
+    assert_eq!(
+        call_pattern_diagnostics(
+            UNSAFE_HELPER_CALLS,
+            unsafe_helper_rule(),
+            tool_metadata(&[])
+        ),
+        vec![
+            (
+                FORMATTED_UNSAFE_HELPER_MESSAGE.to_string(),
+                Severity::Warning
+            ),
+            (
+                FORMATTED_UNSAFE_HELPER_MESSAGE.to_string(),
+                Severity::Warning
+            ),
+        ]
+    );
+}
+
+#[test]
+fn call_pattern_excluded_modules() {
+    let rule = CallPatternConfig {
+        excluded_modules: vec!["test::upgrades".to_string()],
+        ..unsafe_helper_rule()
+    };
+    assert_eq!(
+        call_pattern_diagnostics(UNSAFE_HELPER_CALLS, rule, tool_metadata(&[])),
+        vec![(
+            FORMATTED_UNSAFE_HELPER_MESSAGE.to_string(),
+            Severity::Warning
+        )]
+    );
+}
+
+#[test]
+fn call_pattern_modules() {
+    let rule = CallPatternConfig {
+        modules: vec!["test::upgrades".to_string()],
+        ..unsafe_helper_rule()
+    };
+    assert_eq!(
+        call_pattern_diagnostics(UNSAFE_HELPER_CALLS, rule, tool_metadata(&[])),
+        vec![(
+            FORMATTED_UNSAFE_HELPER_MESSAGE.to_string(),
+            Severity::Warning
+        )]
+    );
+}
+
+#[test]
+fn call_pattern_wildcard() {
+    let rule = CallPatternConfig {
+        function: "test::helpers::*".to_string(),
+        ..unsafe_helper_rule()
+    };
+    assert_eq!(
+        call_pattern_diagnostics(HELPERS_MODULE_CALLS, rule, tool_metadata(&[])).len(),
+        2
+    );
+}
+
+#[test]
+fn call_pattern_can_be_allowed() {
+    assert!(call_pattern_diagnostics(
+        ALLOWED_UNSAFE_HELPER_CALL,
+        unsafe_helper_rule(),
+        tool_metadata(&[])
+    )
+    .is_empty());
+}
+
+#[test]
+fn call_pattern_level() {
+    let rule = CallPatternConfig {
+        level: Some(LintLevel::Deny.into()),
+        excluded_modules: vec!["test::upgrades".to_string()],
+        ..unsafe_helper_rule()
+    };
+    assert_eq!(
+        call_pattern_diagnostics(UNSAFE_HELPER_CALLS, rule.clone(), tool_metadata(&[])),
+        vec![(FORMATTED_UNSAFE_HELPER_MESSAGE.to_string(), Severity::Error)]
+    );
+    assert!(call_pattern_diagnostics(
+        UNSAFE_HELPER_CALLS,
+        rule,
+        tool_metadata(&[("no_unsafe_helper", LintConfig::Enabled(false))])
+    )
+    .is_empty());
+}
+
+#[test]
+fn call_pattern_invalid_config() {
+    let mut lint_registry = LintRegistry::default();
+    let err = lint_registry
+        .register_call_pattern(CallPatternConfig {
+            name: "No unsafe helper".to_string(),
+            ..unsafe_helper_rule()
+        })
+        .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Invalid name of the call pattern rule 'No unsafe helper'. Expected a snake_case identifier."
+    );
+    let err = lint_registry
+        .register_call_pattern(CallPatternConfig {
+            name: "panic".to_string(),
+            ..unsafe_helper_rule()
+        })
+        .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "The lint 'panic' clashes with the already registered lint 'panic'."
+    );
+}
+
+#[test]
+fn call_patterns_from_tool_metadata() {
+    let tool_metadata: CairoLintConfig = serde_json::from_str(
+        r#"{
+            "call_patterns": [
+                {
+                    "name": "no_unsafe_helper",
+                    "function": "test::unsafe_helper",
+                    "message": "Calling `unsafe_helper` is forbidden.",
+                    "level": "deny",
+                    "excluded_modules": ["test::upgrades"]
+                }
+            ],
+            "no_unsafe_helper": "warn"
+        }"#,
+    )
+    .unwrap();
+    assert!(cairo_lint_plugin_suite(tool_metadata.clone()).is_ok());
+
+    let mut lint_registry = LintRegistry::default();
+    lint_registry
+        .register_call_patterns(&tool_metadata)
+        .unwrap();
+    let plugin = CairoLint::new(true, tool_metadata).with_lint_registry(lint_registry);
+    let (mut db, crate_id) = setup_lint_db_with_plugin(UNSAFE_HELPER_CALLS, plugin);
+    let diags = get_diags(crate_id, &mut db);
+    assert_eq!(diags.len(), 1);
+    assert_eq!(diags[0].severity(), Severity::Warning);
+}
+
+#[test]
+fn invalid_call_patterns_in_tool_metadata() {
+    let tool_metadata: CairoLintConfig = serde_json::from_str(
+        r#"{
+            "call_patterns": [
+                {
+                    "name": "no_unsafe_helper",
+                    "function": "test::unsafe_helper",
+                    "message": "Calling `unsafe_helper` is forbidden.",
+                    "level": "sometimes"
+                }
+            ]
+        }"#,
+    )
+    .unwrap();
+    assert_eq!(
+        cairo_lint_plugin_suite(tool_metadata).unwrap_err().to_string(),
+        "Invalid call pattern rules in `Scarb.toml`: Invalid level of the call pattern rule 'no_unsafe_helper': \
+         Unknown lint level 'sometimes'. Expected one of: allow, warn, deny, forbid."
+    );
+}
+
+#[test]
+fn recreated_call_pattern_rule_reuses_strings() {
+    let first = CallPatternRule::new(unsafe_helper_rule()).unwrap();
+    let second = CallPatternRule::new(unsafe_helper_rule()).unwrap();
+    assert!(std::ptr::eq(first.allowed_name(), second.allowed_name()));
+    assert!(std::ptr::eq(
+        first.diagnostic_message(),
+        second.diagnostic_message()
+    ));
+}
@@ -0,0 +1,120 @@
+use std::path::Path;
+
+use cairo_lint::changed_lines::ChangedLines;
+use cairo_lint::diagnostics::format_diagnostic;
+use cairo_lint::plugin::CairoLint;
+
+use crate::helpers::{
+    get_cairo_lint_tool_metadata_with_all_lints_enabled, get_diags, setup_lint_db_with_plugin,
+};
+
+const CLONE_ON_COPY: &str = r#"
+fn main() {
+    let a: felt252 = 'hello';
+    let _b = a.clone();
+    let _c = a.clone();
+}
+"#;
+
+const CLONE_ON_COPY_DIFF: &str = r#"diff --git a/src/lib.cairo b/src/lib.cairo
+index 1111111..2222222 100644
+--- a/src/lib.cairo
++++ b/src/lib.cairo
+@@ -2,4 +2,5 @@ fn main() {
+ fn main() {
+     let a: felt252 = 'hello';
+     let _b = a.clone();
++    let _c = a.clone();
+ }
+"#;
+
+const MULTI_HUNK_DIFF: &str = r#"--- a/src/lib.cairo
++++ b/src/lib.cairo
+@@ -1,3 +1,4 @@
++use core::num;
+ fn main() {
+-    let a = 1;
++    let a = 2;
+     let b = 3;
+@@ -10,2 +11,4 @@ fn foo() {
+     let c = 4;
++    let d = 5;
++    let e = 6;
+ }
+--- /dev/null
++++ b/src/new.cairo
+@@ -0,0 +1,2 @@
++fn bar() {
++}
+--- a/src/removed.cairo
++++ /dev/null
+@@ -1 +0,0 @@
+-fn baz() {}
+"#;
+
+fn changed_lines_diagnostics(changed_lines: ChangedLines) -> Vec<String> {
+    let plugin = CairoLint::new(true, get_cairo_lint_tool_metadata_with_all_lints_enabled())
+        .with_changed_lines(changed_lines);
+    let (mut db, crate_id) = setup_lint_db_with_plugin(CLONE_ON_COPY, plugin);
+    get_diags(crate_id, &mut db)
+        .iter()
+        .map(|diag| format_diagnostic(diag, &db))
+        .collect()
+}
+
+#[test]
+fn parse_unified_diff() {
+    let changed_lines = ChangedLines::from_unified_diff(MULTI_HUNK_DIFF).unwrap();
+    let lib = Path::new("/project/src/lib.cairo");
+    assert!(changed_lines.intersects(lib, 1..=1));
+    assert!(changed_lines.intersects(lib, 3..=3));
+    assert!(!changed_lines.intersects(lib, 2..=2));
+    assert!(!changed_lines.intersects(lib, 4..=11));
+    assert!(changed_lines.intersects(lib, 12..=12));
+    assert!(changed_lines.intersects(lib, 13..=20));
+    assert!(!changed_lines.intersects(lib, 14..=20));
+    assert!(changed_lines.intersects(Path::new("/project/src/new.cairo"), 2..=2));
+    assert!(!changed_lines.intersects(Path::new("/project/src/removed.cairo"), 1..=1));
+    assert!(!changed_lines.intersects(Path::new("/project/lib.cairo"), 1..=1));
+}
+
+#[test]
+fn invalid_unified_diff() {
+    let err = ChangedLines::from_unified_diff(
+        "--- a/lib.cairo\n+++ b/lib.cairo\n@@ -1,2 +1,2 @@\n fn main() {\n",
+    )
+    .unwrap_err();
+    assert_eq!(err.to_string(), "Unexpected end of the diff inside a hunk");
+}
+
+#[test]
+fn diagnostics_in_changed_lines() {
+    let mut changed_lines = ChangedLines::default();
+    changed_lines.add_lines("lib.cairo", 5..=5);
+    let diags = changed_lines_diagnostics(changed_lines);
+    assert_eq!(diags.len(), 1);
+    assert!(diags[0].contains("lib.cairo:5:14"));
+}
+
+#[test]
+fn diagnostics_in_changed_file() {
+    let mut changed_lines = ChangedLines::default();
+    changed_lines.add_file("lib.cairo");
+    assert_eq!(changed_lines_diagnostics(changed_lines).len(), 2);
+}
+
+#[test]
+fn diagnostics_in_unchanged_file() {
+    let mut changed_lines = ChangedLines::default();
+    changed_lines.add_file("other.cairo");
+    assert!(changed_lines_diagnostics(changed_lines).is_empty());
+}
+
+#[test]
+fn diagnostics_in_lines_changed_by_diff() {
+    // The paths in the diff are relative to the project root, but the test file is at its root.
+    let diff = CLONE_ON_COPY_DIFF.replace("src/lib.cairo", "lib.cairo");
+    let diags = changed_lines_diagnostics(ChangedLines::from_unified_diff(&diff).unwrap());
+    assert_eq!(diags.len(), 1);
+    assert!(diags[0].contains("lib.cairo:5:14"));
+}
@@ -188,7 +188,7 @@ fn main() {
 #[test]
 fn clone_numeric_type_diagnostic() {
     test_lint_diagnostics!(CLONE_NUMERIC_TYPE, @r"
-    Plugin diagnostic: using `clone` on type which implements `Copy` trait
+    Plugin diagnostic: using `clone` on type `@@@core::integer::u32` which implements `Copy` trait; use `*b` instead
      --> lib.cairo:5:13
         let c = b.clone();
                 ^^^^^^^^^
@@ -198,7 +198,7 @@ fn clone_numeric_type_diagnostic() {
 #[test]
 fn clone_felt252_diagnostic() {
     test_lint_diagnostics!(CLONE_FELT252, @r"
-    Plugin diagnostic: using `clone` on type which implements `Copy` trait
+    Plugin diagnostic: using `clone` on type `core::felt252` which implements `Copy` trait; use `a` instead
      --> lib.cairo:4:13
         let b = a.clone();
                 ^^^^^^^^^
@@ -208,7 +208,7 @@ fn clone_felt252_diagnostic() {
 #[test]
 fn clone_struct_diagnostic() {
     test_lint_diagnostics!(CLONE_STRUCT, @r"
-    Plugin diagnostic: using `clone` on type which implements `Copy` trait
+    Plugin diagnostic: using `clone` on type `test::Point` which implements `Copy` trait; use `p1` instead
      --> lib.cairo:10:14
         let p2 = p1.clone();
                  ^^^^^^^^^^
@@ -224,7 +224,7 @@ fn clone_non_copy_struct_diagnostic() {
 #[test]
 fn clone_tuple_diagnostic() {
     test_lint_diagnostics!(CLONE_TUPLE, @r"
-    Plugin diagnostic: using `clone` on type which implements `Copy` trait
+    Plugin diagnostic: using `clone` on type `(core::integer::u32, core::felt252)` which implements `Copy` trait; use `t` instead
      --> lib.cairo:4:19
         let t_clone = t.clone();
                       ^^^^^^^^^
@@ -234,7 +234,7 @@ fn clone_tuple_diagnostic() {
 #[test]
 fn clone_array_diagnostic() {
     test_lint_diagnostics!(CLONE_ARRAY, @r"
-    Plugin diagnostic: using `clone` on type which implements `Copy` trait
+    Plugin diagnostic: using `clone` on type `[core::integer::u32; 3]` which implements `Copy` trait; use `arr` instead
      --> lib.cairo:4:21
         let arr_clone = arr.clone();
                         ^^^^^^^^^^^
@@ -244,15 +244,15 @@ fn clone_array_diagnostic() {
 #[test]
 fn clone_in_impl_diagnostic() {
     test_lint_diagnostics!(CLONE_IN_IMPL_AND_TRAIT, @r"
-    Plugin diagnostic: using `clone` on type which implements `Copy` trait
+    Plugin diagnostic: using `clone` on type `test::Point` which implements `Copy` trait; use `*self` instead
      --> lib.cairo:10:34
             let new_point_in_trait = self.clone();
                                      ^^^^^^^^^^^^
-    Plugin diagnostic: using `clone` on type which implements `Copy` trait
+    Plugin diagnostic: using `clone` on type `test::Point` which implements `Copy` trait; use `*self` instead
      --> lib.cairo:19:25
             let new_point = self.clone();
                             ^^^^^^^^^^^^
-    Plugin diagnostic: using `clone` on type which implements `Copy` trait
+    Plugin diagnostic: using `clone` on type `@core::integer::u32` which implements `Copy` trait; use `*dx` instead
      --> lib.cairo:20:25
             let _dx_clone = dx.clone();
                             ^^^^^^^^^^
@@ -262,7 +262,7 @@ fn clone_in_impl_diagnostic() {
 #[test]
 fn clone_on_function_diagnostic() {
     test_lint_diagnostics!(CLONE_ON_FUNCTION, @r"
-    Plugin diagnostic: using `clone` on type which implements `Copy` trait
+    Plugin diagnostic: using `clone` on type `core::integer::u32` which implements `Copy` trait; use `some_function()` instead
      --> lib.cairo:7:13
         let b = some_function().clone();
                 ^^^^^^^^^^^^^^^^^^^^^^^
@@ -282,7 +282,7 @@ fn allow_clone_array_diagnostics() {
 #[test]
 fn clone_on_block_diagnostic() {
     test_lint_diagnostics!(CLONE_ON_BLOCK, @r"
-    Plugin diagnostic: using `clone` on type which implements `Copy` trait
+    Plugin diagnostic: using `clone` on type `[core::integer::u32; 3]` which implements `Copy` trait; use `{ let arr: [u32; 3] = [1, 2, 3]; arr }` instead
      --> lib.cairo:3:21-6:13
           let arr_clone = {
      _____________________^
@@ -295,7 +295,7 @@ fn clone_on_block_diagnostic() {
 #[test]
 fn clone_with_snapshot_diagnostic() {
     test_lint_diagnostics!(CLONE_WITH_SNAPSHOT, @r"
-    Plugin diagnostic: using `clone` on type which implements `Copy` trait
+    Plugin diagnostic: using `clone` on type `test::Point` which implements `Copy` trait; use `*point` instead
      --> lib.cairo:9:24
         let cloned_point = point.clone();
                            ^^^^^^^^^^^^^
@@ -305,7 +305,7 @@ fn clone_with_snapshot_diagnostic() {
 #[test]
 fn clone_from_path_diagnostic() {
     test_lint_diagnostics!(CLONE_FROM_PATH, @r"
-    Plugin diagnostic: using `clone` on type which implements `Copy` trait
+    Plugin diagnostic: using `clone` on type `core::integer::u32` which implements `Copy` trait; use `a` instead
      --> lib.cairo:4:13
         let b = Clone::clone(@a);
                 ^^^^^^^^^^^^^^^^
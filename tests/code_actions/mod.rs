@@ -0,0 +1,202 @@
+use cairo_lang_diagnostics::DiagnosticEntry;
+use cairo_lang_semantic::diagnostic::SemanticDiagnosticKind;
+use cairo_lint::code_actions::{
+    get_code_actions_for_diagnostic, get_code_actions_for_span, CodeAction, CodeActionKind,
+    Position, Range, TextEdit,
+};
+use cairo_lint::context::LintRegistry;
+use cairo_lint::get_fixed_file_content;
+
+use crate::helpers::{
+    get_cairo_lint_tool_metadata_with_all_lints_enabled, get_diags_with_tool_metadata,
+};
+
+const DOUBLE_PARENS_TWICE: &str = r#"
+fn main() {
+    let _a = ((0_u32));
+    let _b = ((1_u32));
+}
+"#;
+
+const DOUBLE_PARENS_IN_MODULE: &str = r#"
+mod inner {
+    fn main() {
+        let _a = ((0_u32));
+    }
+}
+"#;
+
+const UNKNOWN_LINT_NAME: &str = r#"
+#[deny(panc)]
+fn main() {
+    let _a = 0_u32;
+}
+"#;
+
+const DOUBLE_PARENS_AFTER_NON_ASCII: &str = r#"
+fn main() {
+    let _s = "ł🦀"; let _a = ((0_u32));
+}
+"#;
+
+fn range(line: usize, start: usize, end: usize) -> Range {
+    Range {
+        start: Position {
+            line,
+            character: start,
+        },
+        end: Position {
+            line,
+            character: end,
+        },
+    }
+}
+
+fn edit(range: Range, new_text: &str) -> TextEdit {
+    TextEdit {
+        range,
+        new_text: new_text.to_string(),
+    }
+}
+
+#[test]
+fn code_actions_for_lint_diagnostic() {
+    let (db, diags) = get_diags_with_tool_metadata(
+        DOUBLE_PARENS_TWICE,
+        get_cairo_lint_tool_metadata_with_all_lints_enabled(),
+    );
+    assert_eq!(diags.len(), 2);
+    let file_id = diags[0].location(&db).file_id;
+    let actions = get_code_actions_for_diagnostic(&db, &diags[0], &diags, &LintRegistry::default());
+    assert_eq!(
+        actions,
+        vec![
+            CodeAction {
+                title: "Remove the double parentheses".to_string(),
+                kind: CodeActionKind::QuickFix,
+                is_preferred: true,
+                file_id,
+                edits: vec![edit(range(2, 13, 22), "0_u32")],
+            },
+            CodeAction {
+                title: "Allow `double_parens` for this item".to_string(),
+                kind: CodeActionKind::QuickFix,
+                is_preferred: false,
+                file_id,
+                edits: vec![edit(range(1, 0, 0), "#[allow(double_parens)]\n")],
+            },
+            CodeAction {
+                title: "Fix all `double_parens` issues in the file".to_string(),
+                kind: CodeActionKind::SourceFixAll,
+                is_preferred: false,
+                file_id,
+                edits: vec![
+                    edit(range(2, 13, 22), "0_u32"),
+                    edit(range(3, 13, 22), "1_u32"),
+                ],
+            },
+        ]
+    );
+}
+
+#[test]
+fn code_actions_for_cursor_position() {
+    let (db, diags) = get_diags_with_tool_metadata(
+        DOUBLE_PARENS_TWICE,
+        get_cairo_lint_tool_metadata_with_all_lints_enabled(),
+    );
+    let location = diags[1].location(&db);
+    let mut cursor = location.span;
+    cursor.end = cursor.start;
+    let actions = get_code_actions_for_span(
+        &db,
+        location.file_id,
+        cursor,
+        &diags,
+        &LintRegistry::default(),
+    );
+    let titles = actions
+        .iter()
+        .map(|action| action.title.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        titles,
+        vec![
+            "Remove the double parentheses",
+            "Allow `double_parens` for this item",
+            "Fix all `double_parens` issues in the file",
+        ]
+    );
+    assert_eq!(actions[0].edits, vec![edit(range(3, 13, 22), "1_u32")]);
+}
+
+#[test]
+fn code_action_positions_are_in_utf16() {
+    let (db, diags) = get_diags_with_tool_metadata(
+        DOUBLE_PARENS_AFTER_NON_ASCII,
+        get_cairo_lint_tool_metadata_with_all_lints_enabled(),
+    );
+    // The string is not ASCII, so the compiler reports it as well.
+    let diagnostic = diags
+        .iter()
+        .find(|diag| matches!(diag.kind, SemanticDiagnosticKind::PluginDiagnostic(_)))
+        .unwrap();
+    let file_id = diagnostic.location(&db).file_id;
+    let actions =
+        get_code_actions_for_diagnostic(&db, diagnostic, &diags, &LintRegistry::default());
+    // `ł` is a single UTF-16 code unit (two bytes) and `🦀` is two of them (four bytes).
+    assert_eq!(actions[0].edits, vec![edit(range(2, 29, 38), "0_u32")]);
+
+    let fixed = get_fixed_file_content(
+        file_id,
+        vec![actions[0].to_suggestion(&db).unwrap()],
+        &db,
+        false,
+    )
+    .unwrap();
+    assert_eq!(
+        fixed,
+        DOUBLE_PARENS_AFTER_NON_ASCII.replace("((0_u32))", "0_u32")
+    );
+}
+
+#[test]
+fn allow_action_keeps_item_indentation() {
+    let (db, diags) = get_diags_with_tool_metadata(
+        DOUBLE_PARENS_IN_MODULE,
+        get_cairo_lint_tool_metadata_with_all_lints_enabled(),
+    );
+    let file_id = diags[0].location(&db).file_id;
+    let actions = get_code_actions_for_diagnostic(&db, &diags[0], &diags, &LintRegistry::default());
+    let allow_action = actions
+        .iter()
+        .find(|action| action.title == "Allow `double_parens` for this item")
+        .unwrap();
+    let suggestion = allow_action.to_suggestion(&db).unwrap();
+    let fixed = get_fixed_file_content(file_id, vec![suggestion], &db, false).unwrap();
+    assert_eq!(
+        fixed,
+        r#"
+mod inner {
+    #[allow(double_parens)]
+    fn main() {
+        let _a = ((0_u32));
+    }
+}
+"#
+    );
+}
+
+#[test]
+fn lint_without_fixer_has_only_allow_action() {
+    let (db, diags) = get_diags_with_tool_metadata(
+        UNKNOWN_LINT_NAME,
+        get_cairo_lint_tool_metadata_with_all_lints_enabled(),
+    );
+    let actions = get_code_actions_for_diagnostic(&db, &diags[0], &diags, &LintRegistry::default());
+    let titles = actions
+        .iter()
+        .map(|action| action.title.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(titles, vec!["Allow `unknown_lints` for this item"]);
+}
@@ -0,0 +1,440 @@
+use cairo_lang_defs::ids::{LanguageElementId, ModuleItemId, NamedLanguageElementId};
+use cairo_lang_diagnostics::Severity;
+use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_semantic::diagnostic::SemanticDiagnosticKind;
+use cairo_lang_semantic::{Arenas, ExprFunctionCall};
+use cairo_lang_syntax::node::{SyntaxNode, TypedStablePtr};
+use cairo_lint::context::{
+    fill_message_template, find_lint_by_diagnostic_message, CairoLintKind, CheckContext, Lint,
+    LintCategory, LintRegistry, LintRuleGroup,
+};
+use cairo_lint::diagnostics::{JsonDiagnostic, LintDiagnostic};
+use cairo_lint::fixes::Suggestion;
+use cairo_lint::get_fixes_with_lint_registry;
+use cairo_lint::plugin::{
+    cairo_lint_plugin_suite, cairo_lint_plugin_suite_with_lint_registry, CairoLint,
+};
+use cairo_lint::sarif::format_diagnostics_as_sarif_with_lint_registry;
+use cairo_lint::FunctionBodyVisitor;
+
+use crate::helpers::{get_diags, setup_lint_db_with_plugin, tool_metadata};
+
+const FORBIDDEN_FUNCTION_MESSAGE: &str = "Function named `forbidden` found.";
+
+struct ForbiddenFunction;
+
+impl Lint for ForbiddenFunction {
+    fn allowed_name(&self) -> &'static str {
+        "forbidden_functions"
+    }
+
+    fn id(&self) -> &'static str {
+        "FF0001"
+    }
+
+    fn diagnostic_message(&self) -> &'static str {
+        FORBIDDEN_FUNCTION_MESSAGE
+    }
+
+    fn kind(&self) -> CairoLintKind {
+        CairoLintKind::Custom
+    }
+
+    fn category(&self) -> LintCategory {
+        LintCategory::Correctness
+    }
+
+    fn has_fixer(&self) -> bool {
+        true
+    }
+
+    fn fix(&self, db: &dyn SemanticGroup, node: SyntaxNode) -> Option<Suggestion> {
+        let replacement = node
+            .get_text(db.upcast())
+            .replacen("fn forbidden", "fn allowed", 1);
+        Some(Suggestion::replace_node(
+            db.upcast(),
+            "Rename the function",
+            node,
+            replacement,
+        ))
+    }
+}
+
+fn check_forbidden_functions(
+    db: &dyn SemanticGroup,
+    item: &ModuleItemId,
+    _context: &CheckContext,
+    diagnostics: &mut Vec<LintDiagnostic>,
+) {
+    if let ModuleItemId::FreeFunction(function_id) = item {
+        if function_id.name(db.upcast()) == "forbidden" {
+            diagnostics.push(LintDiagnostic::new(
+                &ForbiddenFunction,
+                function_id.untyped_stable_ptr(db.upcast()),
+            ));
+        }
+    }
+}
+
+const FORBIDDEN_CALL_MESSAGE: &str = "Call of the function named `forbidden` found.";
+
+struct ForbiddenCall;
+
+impl Lint for ForbiddenCall {
+    fn allowed_name(&self) -> &'static str {
+        "forbidden_calls"
+    }
+
+    fn id(&self) -> &'static str {
+        "FF0002"
+    }
+
+    fn diagnostic_message(&self) -> &'static str {
+        FORBIDDEN_CALL_MESSAGE
+    }
+
+    fn kind(&self) -> CairoLintKind {
+        CairoLintKind::Custom
+    }
+
+    fn category(&self) -> LintCategory {
+        LintCategory::Correctness
+    }
+}
+
+fn check_forbidden_call(
+    db: &dyn SemanticGroup,
+    expr: &ExprFunctionCall,
+    _arenas: &Arenas,
+    _context: &CheckContext,
+    diagnostics: &mut Vec<LintDiagnostic>,
+) {
+    if expr.function.name(db) == "forbidden" {
+        diagnostics.push(LintDiagnostic::new(
+            &ForbiddenCall,
+            expr.stable_ptr.untyped(),
+        ));
+    }
+}
+
+/// Reports the functions named `also_forbidden`, with the same message as [`ForbiddenFunction`].
+struct AlsoForbiddenFunction;
+
+impl Lint for AlsoForbiddenFunction {
+    fn allowed_name(&self) -> &'static str {
+        "also_forbidden_functions"
+    }
+
+    fn id(&self) -> &'static str {
+        "FF0003"
+    }
+
+    fn diagnostic_message(&self) -> &'static str {
+        FORBIDDEN_FUNCTION_MESSAGE
+    }
+
+    fn kind(&self) -> CairoLintKind {
+        CairoLintKind::Custom
+    }
+
+    fn category(&self) -> LintCategory {
+        LintCategory::Style
+    }
+}
+
+fn check_also_forbidden_functions(
+    db: &dyn SemanticGroup,
+    item: &ModuleItemId,
+    _context: &CheckContext,
+    diagnostics: &mut Vec<LintDiagnostic>,
+) {
+    if let ModuleItemId::FreeFunction(function_id) = item {
+        if function_id.name(db.upcast()) == "also_forbidden" {
+            diagnostics.push(LintDiagnostic::new(
+                &AlsoForbiddenFunction,
+                function_id.untyped_stable_ptr(db.upcast()),
+            ));
+        }
+    }
+}
+
+fn lint_registry() -> LintRegistry {
+    let mut lint_registry = LintRegistry::default();
+    lint_registry
+        .register(LintRuleGroup::new(
+            vec![Box::new(ForbiddenFunction)],
+            check_forbidden_functions,
+        ))
+        .unwrap();
+    lint_registry
+}
+
+const FORBIDDEN_FUNCTION: &str = r#"
+fn forbidden() {}
+"#;
+
+const ALLOWED_FORBIDDEN_FUNCTION: &str = r#"
+#[allow(forbidden_functions)]
+fn forbidden() {}
+"#;
+
+const DENIED_FORBIDDEN_FUNCTION: &str = r#"
+#[deny(forbidden_functions)]
+fn forbidden() {}
+"#;
+
+fn custom_lint_diagnostics(code: &str) -> Vec<(String, Severity)> {
+    let plugin = CairoLint::new(true, tool_metadata(&[])).with_lint_registry(lint_registry());
+    let (mut db, crate_id) = setup_lint_db_with_plugin(code, plugin);
+    get_diags(crate_id, &mut db)
+        .into_iter()
+        .map(|diag| match diag.kind {
+            SemanticDiagnosticKind::PluginDiagnostic(diag) => (diag.message, diag.severity),
+            kind => panic!("Unexpected diagnostic: {kind:?}"),
+        })
+        .collect()
+}
+
+#[test]
+fn custom_lint_diagnostics_are_reported() {
+    assert_eq!(
+        custom_lint_diagnostics(FORBIDDEN_FUNCTION),
+        vec![(FORBIDDEN_FUNCTION_MESSAGE.to_string(), Severity::Warning)]
+    );
+}
+
+const FORBIDDEN_CALL: &str = r#"
+fn forbidden() {}
+
+fn main() {
+    forbidden();
+}
+"#;
+
+#[test]
+fn custom_visitor_lint_diagnostics_are_reported() {
+    let mut lint_registry = lint_registry();
+    lint_registry
+        .register(LintRuleGroup::with_visitor(
+            vec![Box::new(ForbiddenCall)],
+            FunctionBodyVisitor {
+                on_call: Some(check_forbidden_call),
+                ..Default::default()
+            },
+        ))
+        .unwrap();
+    let plugin = CairoLint::new(true, tool_metadata(&[])).with_lint_registry(lint_registry);
+    let (mut db, crate_id) = setup_lint_db_with_plugin(FORBIDDEN_CALL, plugin);
+    let messages = get_diags(crate_id, &mut db)
+        .into_iter()
+        .map(|diag| match diag.kind {
+            SemanticDiagnosticKind::PluginDiagnostic(diag) => diag.message,
+            kind => panic!("Unexpected diagnostic: {kind:?}"),
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        messages,
+        vec![
+            FORBIDDEN_FUNCTION_MESSAGE.to_string(),
+            "Call of the function named `forbidden` found.".to_string(),
+        ]
+    );
+}
+
+#[test]
+fn custom_lint_can_be_allowed() {
+    assert!(custom_lint_diagnostics(ALLOWED_FORBIDDEN_FUNCTION).is_empty());
+}
+
+#[test]
+fn custom_lint_can_be_denied() {
+    assert_eq!(
+        custom_lint_diagnostics(DENIED_FORBIDDEN_FUNCTION),
+        vec![(FORBIDDEN_FUNCTION_MESSAGE.to_string(), Severity::Error)]
+    );
+}
+
+#[test]
+fn custom_lint_fix() {
+    let lint_registry = lint_registry();
+    let plugin = CairoLint::new(true, tool_metadata(&[])).with_lint_registry(lint_registry.clone());
+    let (mut db, crate_id) = setup_lint_db_with_plugin(FORBIDDEN_FUNCTION, plugin);
+    let diags = get_diags(crate_id, &mut db);
+
+    let fixes = get_fixes_with_lint_registry(&db, diags, &lint_registry);
+    let suggestions = fixes.into_values().flatten().collect::<Vec<_>>();
+    assert_eq!(suggestions.len(), 1);
+    assert_eq!(suggestions[0].title, "Rename the function");
+    let mut code = FORBIDDEN_FUNCTION.to_string();
+    suggestions[0].apply(&mut code);
+    assert_eq!(code, "\nfn allowed() {}\n");
+}
+
+#[test]
+fn custom_lint_as_json() {
+    let lint_registry = lint_registry();
+    let plugin = CairoLint::new(true, tool_metadata(&[])).with_lint_registry(lint_registry.clone());
+    let (mut db, crate_id) = setup_lint_db_with_plugin(FORBIDDEN_FUNCTION, plugin);
+    let diags = get_diags(crate_id, &mut db);
+
+    let json_diagnostic = JsonDiagnostic::new(&diags[0], &db, &lint_registry);
+    assert_eq!(json_diagnostic.code.as_deref(), Some("FF0001"));
+    assert_eq!(json_diagnostic.lint.as_deref(), Some("forbidden_functions"));
+    assert_eq!(json_diagnostic.category.as_deref(), Some("correctness"));
+    assert_eq!(json_diagnostic.message, FORBIDDEN_FUNCTION_MESSAGE);
+    assert_eq!(json_diagnostic.suggestions.len(), 1);
+    assert_eq!(json_diagnostic.suggestions[0].title, "Rename the function");
+}
+
+#[test]
+fn custom_lint_in_sarif_report() {
+    let lint_registry = lint_registry();
+    let plugin = CairoLint::new(true, tool_metadata(&[])).with_lint_registry(lint_registry.clone());
+    let (mut db, crate_id) = setup_lint_db_with_plugin(FORBIDDEN_FUNCTION, plugin);
+    let diags = get_diags(crate_id, &mut db);
+
+    let report: serde_json::Value = serde_json::from_str(
+        &format_diagnostics_as_sarif_with_lint_registry(&diags, &db, &lint_registry),
+    )
+    .unwrap();
+    let run = &report["runs"][0];
+    let rule = run["tool"]["driver"]["rules"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|rule| rule["id"] == "FF0001")
+        .unwrap();
+    assert_eq!(rule["name"], "forbidden_functions");
+    // The custom rule is not documented, and it has no page on the documentation website.
+    assert!(rule.get("fullDescription").is_none());
+    assert!(rule.get("help").is_none());
+    assert!(rule.get("helpUri").is_none());
+
+    let results = run["results"].as_array().unwrap();
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0]["ruleId"], "FF0001");
+    assert_eq!(results[0]["message"]["text"], FORBIDDEN_FUNCTION_MESSAGE);
+}
+
+#[test]
+fn custom_lint_metadata_validation() {
+    let metadata = tool_metadata(&[("forbidden_functions", false.into())]);
+    assert!(cairo_lint_plugin_suite_with_lint_registry(metadata.clone(), lint_registry()).is_ok());
+    assert_eq!(
+        cairo_lint_plugin_suite(metadata).unwrap_err().to_string(),
+        "The lint 'forbidden_functions' specified in `Scarb.toml` is not supported by the Cairo lint."
+    );
+}
+
+#[test]
+fn custom_lint_name_clash() {
+    let mut lint_registry = lint_registry();
+    let err = lint_registry
+        .register(LintRuleGroup::new(
+            vec![Box::new(ForbiddenFunction)],
+            check_forbidden_functions,
+        ))
+        .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "The lint 'forbidden_functions' clashes with the already registered lint 'forbidden_functions'."
+    );
+}
+
+#[test]
+fn custom_lint_name_clash_in_group() {
+    let mut lint_registry = LintRegistry::default();
+    let err = lint_registry
+        .register(LintRuleGroup::new(
+            vec![Box::new(ForbiddenFunction), Box::new(ForbiddenFunction)],
+            check_forbidden_functions,
+        ))
+        .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "The lint 'forbidden_functions' clashes with the lint 'forbidden_functions' of the same group."
+    );
+    assert!(lint_registry
+        .lints()
+        .all(|lint| lint.allowed_name() != "forbidden_functions"));
+}
+
+#[test]
+fn message_template_is_filled_with_arguments() {
+    assert_eq!(
+        fill_message_template(
+            "use `{replacement}` instead of `{expr}`",
+            &[("expr", "a.clone()"), ("replacement", "a")]
+        ),
+        "use `a` instead of `a.clone()`"
+    );
+    assert_eq!(
+        fill_message_template(
+            "Consider using else if instead of else { if ... }",
+            &[("if", "x")]
+        ),
+        "Consider using else if instead of else { if ... }"
+    );
+    assert_eq!(
+        ForbiddenFunction.formatted_message_with_args(&[("name", "forbidden")]),
+        FORBIDDEN_FUNCTION_MESSAGE
+    );
+}
+
+#[test]
+fn predefined_lint_is_found_by_filled_message_template() {
+    let message = "using `clone` on type `u32` which implements `Copy` trait; use `a` instead";
+    assert_eq!(
+        find_lint_by_diagnostic_message(message).map(|lint| lint.allowed_name()),
+        Some("clone_on_copy")
+    );
+    assert!(find_lint_by_diagnostic_message("using `clone` on a value").is_none());
+    // The custom lint rules are known only to the registry, which finds them by the id of the diagnostic.
+    assert!(find_lint_by_diagnostic_message(FORBIDDEN_FUNCTION_MESSAGE).is_none());
+}
+
+const FORBIDDEN_AND_ALSO_FORBIDDEN_FUNCTIONS: &str = r#"
+fn forbidden() {}
+
+fn also_forbidden() {}
+"#;
+
+#[test]
+fn lints_with_the_same_message_are_told_apart() {
+    let mut lint_registry = lint_registry();
+    lint_registry
+        .register(LintRuleGroup::new(
+            vec![Box::new(AlsoForbiddenFunction)],
+            check_also_forbidden_functions,
+        ))
+        .unwrap();
+    let plugin = CairoLint::new(true, tool_metadata(&[])).with_lint_registry(lint_registry.clone());
+    let (mut db, crate_id) =
+        setup_lint_db_with_plugin(FORBIDDEN_AND_ALSO_FORBIDDEN_FUNCTIONS, plugin);
+    let diags = get_diags(crate_id, &mut db);
+
+    let lints = diags
+        .iter()
+        .map(|diag| {
+            let json_diagnostic = JsonDiagnostic::new(diag, &db, &lint_registry);
+            (json_diagnostic.code, json_diagnostic.message)
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        lints,
+        vec![
+            (
+                Some("FF0001".to_string()),
+                FORBIDDEN_FUNCTION_MESSAGE.to_string()
+            ),
+            (
+                Some("FF0003".to_string()),
+                FORBIDDEN_FUNCTION_MESSAGE.to_string()
+            ),
+        ]
+    );
+    // Only the rule which reported the diagnostic has a fix.
+    let fixes = get_fixes_with_lint_registry(&db, diags, &lint_registry);
+    assert_eq!(fixes.into_values().flatten().count(), 1);
+}
@@ -0,0 +1,259 @@
+use cairo_lang_diagnostics::Severity;
+use cairo_lang_semantic::diagnostic::SemanticDiagnosticKind;
+use cairo_lint::config::LintConfig;
+use cairo_lint::context::{DeprecatedLintName, LintRegistry};
+use cairo_lint::diagnostics::format_diagnostic_with_lint_registry;
+use cairo_lint::get_fixes_with_lint_registry;
+use cairo_lint::plugin::{cairo_lint_plugin_suite_with_lint_registry, CairoLint};
+use cairo_lint::CairoLintConfig;
+
+use crate::helpers::{get_diags, setup_lint_db_with_plugin, tool_metadata};
+
+const RENAMED_DOUBLE_PARENS_MESSAGE: &str =
+    "The lint `double_parentheses` has been renamed to `double_parens`.";
+const DOUBLE_PARENS_MESSAGE: &str = "unnecessary double parentheses found. Consider removing them.";
+
+const ALLOWED_BY_OLD_NAME: &str = r#"
+#[allow(double_parentheses)]
+fn main() {
+    let _a = ((0_u32));
+}
+"#;
+
+const DENIED_BY_OLD_NAME: &str = r#"
+#[deny(double_parentheses)]
+fn main() {
+    let _a = ((0_u32));
+}
+"#;
+
+const REMOVED_LINT_NAME: &str = r#"
+#[allow(unsafe_panics)]
+fn main() {
+    let _a = 0_u32;
+}
+"#;
+
+const DOUBLE_PARENS: &str = r#"
+fn main() {
+    let _a = ((0_u32));
+}
+"#;
+
+const ALLOWED_RENAMED_LINTS: &str = r#"
+#[allow(renamed_lints)]
+fn main() {}
+"#;
+
+fn lint_registry() -> LintRegistry {
+    let mut lint_registry = LintRegistry::default();
+    lint_registry
+        .register_deprecated_lint_name(DeprecatedLintName::Renamed {
+            old_name: "double_parentheses",
+            new_name: "double_parens",
+        })
+        .unwrap();
+    lint_registry
+        .register_deprecated_lint_name(DeprecatedLintName::Removed {
+            name: "unsafe_panics",
+            reason: "use `panic` instead.",
+        })
+        .unwrap();
+    lint_registry
+}
+
+fn diagnostics(code: &str, metadata: CairoLintConfig) -> Vec<(String, Severity)> {
+    let plugin = CairoLint::new(true, metadata).with_lint_registry(lint_registry());
+    let (mut db, crate_id) = setup_lint_db_with_plugin(code, plugin);
+    get_diags(crate_id, &mut db)
+        .into_iter()
+        .map(|diag| match diag.kind {
+            SemanticDiagnosticKind::PluginDiagnostic(diag) => (diag.message, diag.severity),
+            kind => panic!("Unexpected diagnostic: {kind:?}"),
+        })
+        .collect()
+}
+
+#[test]
+fn old_name_allows_renamed_lint() {
+    assert_eq!(
+        diagnostics(ALLOWED_BY_OLD_NAME, tool_metadata(&[])),
+        vec![(RENAMED_DOUBLE_PARENS_MESSAGE.to_string(), Severity::Warning)]
+    );
+}
+
+#[test]
+fn old_name_denies_renamed_lint() {
+    assert_eq!(
+        diagnostics(DENIED_BY_OLD_NAME, tool_metadata(&[])),
+        vec![
+            (DOUBLE_PARENS_MESSAGE.to_string(), Severity::Error),
+            (RENAMED_DOUBLE_PARENS_MESSAGE.to_string(), Severity::Warning),
+        ]
+    );
+}
+
+#[test]
+fn renamed_lint_warning_can_be_allowed() {
+    assert!(diagnostics(
+        ALLOWED_BY_OLD_NAME,
+        tool_metadata(&[("renamed_lints", false.into())])
+    )
+    .is_empty());
+}
+
+#[test]
+fn renamed_lint_fix() {
+    let lint_registry = lint_registry();
+    let plugin = CairoLint::new(true, tool_metadata(&[])).with_lint_registry(lint_registry.clone());
+    let (mut db, crate_id) = setup_lint_db_with_plugin(ALLOWED_BY_OLD_NAME, plugin);
+    let diags = get_diags(crate_id, &mut db);
+
+    let fixes = get_fixes_with_lint_registry(&db, diags, &lint_registry);
+    let suggestions = fixes.into_values().flatten().collect::<Vec<_>>();
+    assert_eq!(suggestions.len(), 1);
+    assert_eq!(suggestions[0].title, "Rename to `double_parens`");
+    let mut code = ALLOWED_BY_OLD_NAME.to_string();
+    suggestions[0].apply(&mut code);
+    assert_eq!(
+        code,
+        ALLOWED_BY_OLD_NAME.replace("double_parentheses", "double_parens")
+    );
+}
+
+#[test]
+fn removed_lint_name_is_tolerated() {
+    assert_eq!(
+        diagnostics(REMOVED_LINT_NAME, tool_metadata(&[])),
+        vec![(
+            "The lint `unsafe_panics` has been removed: use `panic` instead.".to_string(),
+            Severity::Warning
+        )]
+    );
+}
+
+#[test]
+fn old_name_configures_renamed_lint_in_metadata() {
+    assert_eq!(
+        diagnostics(DOUBLE_PARENS, tool_metadata(&[])),
+        vec![(DOUBLE_PARENS_MESSAGE.to_string(), Severity::Warning)]
+    );
+    assert_eq!(
+        diagnostics(
+            DOUBLE_PARENS,
+            tool_metadata(&[("double_parentheses", false.into())])
+        ),
+        vec![(RENAMED_DOUBLE_PARENS_MESSAGE.to_string(), Severity::Warning)]
+    );
+}
+
+#[test]
+fn deprecated_names_in_metadata_are_reported() {
+    assert_eq!(
+        diagnostics(
+            DOUBLE_PARENS,
+            tool_metadata(&[
+                ("double_parentheses", LintConfig::Level("deny".to_string())),
+                ("unsafe_panics", true.into()),
+            ])
+        ),
+        vec![
+            (DOUBLE_PARENS_MESSAGE.to_string(), Severity::Error),
+            (RENAMED_DOUBLE_PARENS_MESSAGE.to_string(), Severity::Warning),
+            (
+                "The lint `unsafe_panics` has been removed: use `panic` instead.".to_string(),
+                Severity::Warning
+            ),
+        ]
+    );
+    assert!(diagnostics(
+        DOUBLE_PARENS,
+        tool_metadata(&[
+            ("double_parentheses", false.into()),
+            ("renamed_lints", false.into()),
+        ])
+    )
+    .is_empty());
+}
+
+#[test]
+fn deprecated_names_pass_metadata_validation() {
+    let metadata = tool_metadata(&[
+        ("double_parentheses", false.into()),
+        ("unsafe_panics", true.into()),
+    ]);
+    assert!(cairo_lint_plugin_suite_with_lint_registry(metadata, lint_registry()).is_ok());
+}
+
+#[test]
+fn deprecated_lint_name_registration_errors() {
+    let mut lint_registry = lint_registry();
+    let err = lint_registry
+        .register_deprecated_lint_name(DeprecatedLintName::Removed {
+            name: "panic",
+            reason: "",
+        })
+        .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "The deprecated lint name 'panic' is still used by a lint rule or a category."
+    );
+    let err = lint_registry
+        .register_deprecated_lint_name(DeprecatedLintName::Removed {
+            name: "unsafe_panics",
+            reason: "",
+        })
+        .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "The lint name 'unsafe_panics' is already deprecated."
+    );
+    let err = lint_registry
+        .register_deprecated_lint_name(DeprecatedLintName::Renamed {
+            old_name: "double_parenthesis",
+            new_name: "double_parenz",
+        })
+        .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "The lint 'double_parenthesis' is renamed to the unknown lint 'double_parenz'."
+    );
+}
+
+#[test]
+fn deprecated_name_in_metadata_has_help() {
+    let lint_registry = lint_registry();
+    let plugin = CairoLint::new(true, tool_metadata(&[("double_parentheses", false.into())]))
+        .with_lint_registry(lint_registry.clone());
+    let (mut db, crate_id) = setup_lint_db_with_plugin(DOUBLE_PARENS, plugin);
+    let diags = get_diags(crate_id, &mut db);
+    assert_eq!(diags.len(), 1);
+    assert_eq!(
+        format_diagnostic_with_lint_registry(&diags[0], &db, &lint_registry)
+            .lines()
+            .last(),
+        Some("help: rename it to `double_parens` in the `[tool.cairo-lint]` table of `Scarb.toml`")
+    );
+}
+
+#[test]
+fn deprecated_name_in_metadata_ignores_attributes() {
+    assert_eq!(
+        diagnostics(
+            ALLOWED_RENAMED_LINTS,
+            tool_metadata(&[
+                ("double_parentheses", false.into()),
+                ("unused_lint_allows", false.into()),
+            ])
+        ),
+        vec![(RENAMED_DOUBLE_PARENS_MESSAGE.to_string(), Severity::Warning)]
+    );
+}
+
+#[test]
+fn deprecated_name_in_metadata_is_reported_for_empty_crate() {
+    assert_eq!(
+        diagnostics("", tool_metadata(&[("double_parentheses", false.into())])),
+        vec![(RENAMED_DOUBLE_PARENS_MESSAGE.to_string(), Severity::Warning)]
+    );
+}
@@ -54,6 +54,15 @@ enum Cake {
 }
 "#;
 
+const ENUM_WITH_SUFFIXED_NAME_IN_COMMENT: &str = r#"
+enum Cake {
+   /// Unlike the HummingbirdCake, it has cherries.
+   BlackForestCake,
+   HummingbirdCake,
+   BattenbergCake,
+}
+"#;
+
 const ENUM_SINGLE: &str = r#"
 enum Cake {
    BlackForest,
@@ -103,6 +112,8 @@ fn enum_with_suffixed_name_diagnostics() {
     | ...
     | }
     |_^
+    note: all of the variants end with `Cake`
+    help: remove the shared words from the names of the variants
     ");
 }
 
@@ -116,6 +127,8 @@ fn enum_with_prefixed_name_diagnostics() {
     | ...
     | }
     |_^
+    note: all of the variants start with `Cake`
+    help: remove the shared words from the names of the variants
     ")
 }
 
@@ -129,6 +142,8 @@ fn enum_with_suffix_diagnostics() {
     | ...
     | }
     |_^
+    note: all of the variants end with `Forest`
+    help: remove the shared words from the names of the variants
     ")
 }
 
@@ -142,6 +157,8 @@ fn enum_with_prefix_diagnostics() {
     | ...
     | }
     |_^
+    note: all of the variants start with `Forest`
+    help: remove the shared words from the names of the variants
     ")
 }
 
@@ -155,6 +172,9 @@ fn enum_with_prefixes_and_suffixes_diagnostic() {
     | ...
     | }
     |_^
+    note: all of the variants start with `DoubleCake`
+    note: all of the variants end with `ForestGreen`
+    help: remove the shared words from the names of the variants
     ")
 }
 #[test]
@@ -202,6 +222,18 @@ fn enum_with_suffixed_name_fixer() {
     ");
 }
 
+#[test]
+fn enum_with_suffixed_name_in_comment_fixer() {
+    test_lint_fixer!(ENUM_WITH_SUFFIXED_NAME_IN_COMMENT, @r"
+    enum Cake {
+       /// Unlike the HummingbirdCake, it has cherries.
+       BlackForest,
+       Hummingbird,
+       Battenberg,
+    }
+    ");
+}
+
 #[test]
 fn enum_with_prefixed_name_fixer() {
     test_lint_fixer!(ENUM_WITH_PREFIXED_NAME, @r"
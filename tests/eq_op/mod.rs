@@ -62,6 +62,10 @@ fn simple_eq_op_diagnostics() {
      --> lib.cairo:3:5
         a == a
         ^^^^^^
+    note: this operand is identical to the left one
+     --> lib.cairo:3:10
+        a == a
+             ^
     ");
 }
 
@@ -81,6 +85,10 @@ fn simple_neq_op_diagnostics() {
      --> lib.cairo:3:5
         a != a
         ^^^^^^
+    note: this operand is identical to the left one
+     --> lib.cairo:3:10
+        a != a
+             ^
     ");
 }
 
@@ -100,6 +108,10 @@ fn simple_lt_op_diagnostics() {
      --> lib.cairo:3:5
         a < a
         ^^^^^
+    note: this operand is identical to the left one
+     --> lib.cairo:3:9
+        a < a
+            ^
     ");
 }
 
@@ -119,6 +131,10 @@ fn simple_gt_op_diagnostics() {
      --> lib.cairo:3:5
         a > a
         ^^^^^
+    note: this operand is identical to the left one
+     --> lib.cairo:3:9
+        a > a
+            ^
     ");
 }
 
@@ -138,6 +154,10 @@ fn simple_bitwise_op_diagnostics() {
      --> lib.cairo:3:5
         a & a
         ^^^^^
+    note: this operand is identical to the left one
+     --> lib.cairo:3:9
+        a & a
+            ^
     ");
 }
 
@@ -173,6 +193,10 @@ fn simple_sub_op_diagnostics() {
      --> lib.cairo:3:5
         a - a
         ^^^^^
+    note: this operand is identical to the left one
+     --> lib.cairo:3:9
+        a - a
+            ^
     ");
 }
 
@@ -192,6 +216,10 @@ fn simple_divide_op_diagnostics() {
      --> lib.cairo:3:5
         a / a
         ^^^^^
+    note: this operand is identical to the left one
+     --> lib.cairo:3:9
+        a / a
+            ^
     ");
 }
 
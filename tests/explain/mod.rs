@@ -0,0 +1,105 @@
+use cairo_lint::config::LintLevel;
+use cairo_lint::context::{get_all_lint_rules, LintCategory, LintRegistry};
+use cairo_lint::explain::explain_lint;
+
+#[test]
+fn explain_lint_by_name() {
+    let explanation = explain_lint(&LintRegistry::default(), "manual_ok_or").unwrap();
+    assert_eq!(explanation.id, "CL0020");
+    assert_eq!(explanation.category, LintCategory::Style);
+    assert_eq!(explanation.default_level, LintLevel::Warn);
+    assert!(explanation.fixable);
+    insta::assert_snapshot!(explanation.to_markdown(), @r#"
+    # manual_ok_or (CL0020)
+
+    Category: **style**
+
+    Default level: **warn**
+
+    Fixable: **yes**
+
+    ## What it does
+
+    Checks for manual implementations of ok_or.
+
+    ## Example
+
+    ```cairo
+    fn main() {
+        let foo: Option<i32> = Option::None;
+        let _foo = match foo {
+            Option::Some(v) => Result::Ok(v),
+            Option::None => Result::Err('this is an err'),
+        };
+    }
+    ```
+
+    Can be rewritten as:
+
+    ```cairo
+    fn main() {
+        let foo: Option<i32> = Option::None;
+        let _foo = foo.ok_or('this is an err');
+    }
+    ```
+    "#);
+}
+
+#[test]
+fn explain_lint_by_id() {
+    let explanation = explain_lint(&LintRegistry::default(), "CL0003").unwrap();
+    assert_eq!(explanation.name, "double_parens");
+}
+
+#[test]
+fn explain_lint_with_rationale() {
+    let explanation = explain_lint(&LintRegistry::default(), "div_eq_op").unwrap();
+    insta::assert_snapshot!(explanation.docs_markdown(), @r"
+    ## What it does
+
+    Checks for division with identical operands.
+
+    ## Why is this bad
+
+    The result is always 1, so the entire expression can be replaced with it.
+
+    ## Example
+
+    ```cairo
+    fn foo(a: u256) -> u256 {
+        a / a
+    }
+    ```
+
+    Can be rewritten as:
+
+    ```cairo
+    fn foo(a: u256) -> u256 {
+        1
+    }
+    ```
+    ");
+}
+
+#[test]
+fn explain_unknown_lint() {
+    let err = explain_lint(&LintRegistry::default(), "manual_ok_orr").unwrap_err();
+    assert_eq!(err.to_string(), "Unknown lint 'manual_ok_orr'.");
+}
+
+#[test]
+fn all_lints_are_documented() {
+    for lint in get_all_lint_rules() {
+        let docs = lint.docs();
+        assert!(
+            !docs.description.is_empty(),
+            "{} has no description",
+            lint.allowed_name()
+        );
+        assert!(
+            docs.bad_example.is_some(),
+            "{} has no example",
+            lint.allowed_name()
+        );
+    }
+}
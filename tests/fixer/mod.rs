@@ -0,0 +1,154 @@
+use cairo_lang_defs::db::DefsGroup;
+use cairo_lang_defs::ids::ModuleId;
+use cairo_lang_filesystem::db::FilesGroup;
+use cairo_lang_filesystem::span::TextOffset;
+use cairo_lint::fixer::{fix_crates, unified_diff, verify_fixes, FixerConfig};
+use cairo_lint::fixes::{Edit, Suggestion};
+use cairo_lint::{get_file_fixes_diff, get_fixed_file_content, get_fixes};
+
+use crate::helpers::{
+    get_cairo_lint_tool_metadata_with_all_lints_enabled, get_diags, get_diags_with_tool_metadata,
+    setup_lint_db,
+};
+
+const DOUBLE_PARENS: &str = r#"
+fn main() {
+    let _a = ((0_u32));
+}
+"#;
+
+const FIXED_DOUBLE_PARENS: &str = r#"
+fn main() {
+    let _a = 0_u32;
+}
+"#;
+
+const COLLAPSIBLE_IF_LETS: &str = r#"
+fn main() {
+    let x = Some(Some(42));
+    if let Some(inner) = x {
+        if let Some(value) = inner {
+            println!("The value is: {}", value);
+        }
+    }
+}
+"#;
+
+const DOUBLE_PARENS_DIFF: &str = "--- lib.cairo
++++ lib.cairo
+@@ -1,4 +1,4 @@
+ \n fn main() {
+-    let _a = ((0_u32));
++    let _a = 0_u32;
+ }
+";
+
+#[test]
+fn fixed_file_content_is_returned_in_memory() {
+    let (db, diags) = get_diags_with_tool_metadata(
+        DOUBLE_PARENS,
+        get_cairo_lint_tool_metadata_with_all_lints_enabled(),
+    );
+    let (file_id, fixes) = get_fixes(&db, diags).into_iter().next().unwrap();
+
+    let fixed = get_fixed_file_content(file_id, fixes.clone(), &db, false).unwrap();
+    assert_eq!(fixed, FIXED_DOUBLE_PARENS);
+    let diff = get_file_fixes_diff(file_id, fixes, &db, false).unwrap();
+    assert_eq!(diff, DOUBLE_PARENS_DIFF);
+
+    // The file itself is left untouched.
+    assert_eq!(db.file_content(file_id).unwrap().as_ref(), DOUBLE_PARENS);
+}
+
+#[test]
+fn fixer_outcome_diffs_and_revert() {
+    let (mut db, crate_id) = setup_lint_db(
+        DOUBLE_PARENS,
+        get_cairo_lint_tool_metadata_with_all_lints_enabled(),
+    );
+    get_diags(crate_id, &mut db);
+    let file_id = db.module_main_file(ModuleId::CrateRoot(crate_id)).unwrap();
+
+    let outcome = fix_crates(&mut db, &[crate_id], &FixerConfig::default());
+    assert_eq!(outcome.applied_fixes, 1);
+    assert_eq!(outcome.files[&file_id], FIXED_DOUBLE_PARENS);
+    assert_eq!(outcome.original_files[&file_id], DOUBLE_PARENS);
+    assert_eq!(outcome.diffs(&db)[&file_id], DOUBLE_PARENS_DIFF);
+
+    outcome.revert(&mut db);
+    assert_eq!(db.file_content(file_id).unwrap().as_ref(), DOUBLE_PARENS);
+}
+
+#[test]
+fn unified_diff_of_unchanged_file_is_empty() {
+    assert_eq!(unified_diff("lib.cairo", DOUBLE_PARENS, DOUBLE_PARENS), "");
+}
+
+#[test]
+fn fix_breaking_the_code_is_rejected() {
+    let (mut db, crate_id) = setup_lint_db(
+        DOUBLE_PARENS,
+        get_cairo_lint_tool_metadata_with_all_lints_enabled(),
+    );
+    let diags = get_diags(crate_id, &mut db);
+    let file_id = db.module_main_file(ModuleId::CrateRoot(crate_id)).unwrap();
+    let mut fixes = get_fixes(&db, diags);
+    let broken_fix = Suggestion::new(
+        "Break the code",
+        vec![Edit::insert(TextOffset::default(), "let")],
+    );
+    fixes.get_mut(&file_id).unwrap().push(broken_fix.clone());
+
+    let (verified_fixes, rejected_fixes) = verify_fixes(&mut db, &[crate_id], fixes);
+    // Only the fix breaking the code is rejected, the other one is still applied.
+    assert_eq!(verified_fixes[&file_id].len(), 1);
+    assert_eq!(
+        verified_fixes[&file_id][0].title,
+        "Remove the double parentheses"
+    );
+    assert_eq!(rejected_fixes.len(), 1);
+    assert_eq!(rejected_fixes[0].suggestion, broken_fix);
+    assert!(!rejected_fixes[0].reason.is_empty());
+
+    // The original content is restored after the verification, without overriding the file.
+    assert_eq!(db.file_content(file_id).unwrap().as_ref(), DOUBLE_PARENS);
+    assert!(!db.file_overrides().contains_key(&file_id));
+}
+
+#[test]
+fn fixes_passing_the_verification() {
+    let (mut db, crate_id) = setup_lint_db(
+        DOUBLE_PARENS,
+        get_cairo_lint_tool_metadata_with_all_lints_enabled(),
+    );
+    let diags = get_diags(crate_id, &mut db);
+    let file_id = db.module_main_file(ModuleId::CrateRoot(crate_id)).unwrap();
+    let fixes = get_fixes(&db, diags);
+
+    let (verified_fixes, rejected_fixes) = verify_fixes(&mut db, &[crate_id], fixes.clone());
+    assert_eq!(verified_fixes, fixes);
+    assert!(rejected_fixes.is_empty());
+    assert!(!db.file_overrides().contains_key(&file_id));
+}
+
+#[test]
+fn fix_with_syntax_errors_is_rejected() {
+    let (mut db, crate_id) = setup_lint_db(
+        COLLAPSIBLE_IF_LETS,
+        get_cairo_lint_tool_metadata_with_all_lints_enabled(),
+    );
+    get_diags(crate_id, &mut db);
+    let file_id = db.module_main_file(ModuleId::CrateRoot(crate_id)).unwrap();
+
+    // The `if let` conditions cannot be joined with `&&`, so the fixed code does not parse.
+    let outcome = fix_crates(&mut db, &[crate_id], &FixerConfig::default());
+    assert_eq!(outcome.applied_fixes, 0);
+    assert!(outcome.files.is_empty());
+    assert_eq!(outcome.rejected_fixes.len(), 1);
+    assert_eq!(outcome.rejected_fixes[0].file_id, file_id);
+    assert!(!outcome.rejected_fixes[0].reason.is_empty());
+    assert_eq!(
+        db.file_content(file_id).unwrap().as_ref(),
+        COLLAPSIBLE_IF_LETS
+    );
+}
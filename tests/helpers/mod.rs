@@ -1,19 +1,29 @@
 use std::path::PathBuf;
+use std::sync::Arc;
 
 use cairo_lang_compiler::db::RootDatabase;
 use cairo_lang_defs::{db::DefsGroup, ids::ModuleId};
+use cairo_lang_diagnostics::{DiagnosticEntry, Severity};
 use cairo_lang_filesystem::{
     db::{init_dev_corelib, FilesGroup},
     ids::{CrateId, FileLongId},
 };
+use cairo_lang_semantic::diagnostic::SemanticDiagnosticKind;
+use cairo_lang_semantic::inline_macros::get_default_plugin_suite;
+use cairo_lang_semantic::plugin::PluginSuite;
+use cairo_lang_semantic::test_utils::setup_test_crate_ex;
 use cairo_lang_semantic::{db::SemanticGroup, SemanticDiagnostic};
+use cairo_lang_test_plugin::test_plugin_suite;
 use cairo_lang_utils::LookupIntern;
-use cairo_lint::{context::get_unique_allowed_names, CairoLintToolMetadata};
+use cairo_lint::context::{get_unique_allowed_names, LintRegistry};
+use cairo_lint::plugin::{CairoLint, CairoLintAttributes};
+use cairo_lint::{config::LintConfig, CairoLintConfig};
 use scarb::find_scarb_managed_core;
 
 mod scarb;
 
-pub fn get_diags(crate_id: CrateId, db: &mut RootDatabase) -> Vec<SemanticDiagnostic> {
+/// Sets up the corelib, either from the `CORELIB_PATH` env or the one managed by Scarb.
+pub fn init_corelib(db: &mut RootDatabase) {
     if let Ok(path) = std::env::var("CORELIB_PATH") {
         init_dev_corelib(db, PathBuf::from(path));
     } else if let Some(path) = find_scarb_managed_core() {
@@ -21,6 +31,10 @@ pub fn get_diags(crate_id: CrateId, db: &mut RootDatabase) -> Vec<SemanticDiagno
     } else {
         panic!("Missing corelib path. CORELIB_PATH env or Scarb managed corelib is required.");
     }
+}
+
+pub fn get_diags(crate_id: CrateId, db: &mut RootDatabase) -> Vec<SemanticDiagnostic> {
+    init_corelib(db);
     let mut diagnostics = Vec::new();
     let module_file = db.module_main_file(ModuleId::CrateRoot(crate_id)).unwrap();
     if db.file_content(module_file).is_none() {
@@ -44,11 +58,78 @@ pub fn get_diags(crate_id: CrateId, db: &mut RootDatabase) -> Vec<SemanticDiagno
         .collect::<Vec<_>>()
 }
 
-pub fn get_cairo_lint_tool_metadata_with_all_lints_enabled() -> CairoLintToolMetadata {
+/// Creates a database with the Cairo lint plugin configured with the given tool metadata,
+/// and sets up a test crate with the code.
+pub fn setup_lint_db(code: &str, tool_metadata: CairoLintConfig) -> (RootDatabase, CrateId) {
+    setup_lint_db_with_plugin(code, CairoLint::new(true, tool_metadata))
+}
+
+/// Creates a database with the given Cairo lint plugin, and sets up a test crate with the code.
+pub fn setup_lint_db_with_plugin(code: &str, plugin: CairoLint) -> (RootDatabase, CrateId) {
+    let mut testing_suite = PluginSuite::default();
+    testing_suite.add_analyzer_plugin_ex(Arc::new(plugin));
+    testing_suite.add_plugin::<CairoLintAttributes>();
+    let db = RootDatabase::builder()
+        .with_default_plugin_suite(get_default_plugin_suite())
+        .with_default_plugin_suite(test_plugin_suite())
+        .with_default_plugin_suite(testing_suite)
+        .build()
+        .unwrap();
+    let crate_id = setup_test_crate_ex(&db, code, Some(crate::CRATE_CONFIG), None);
+    (db, crate_id)
+}
+
+/// Runs the analysis of the code with the Cairo lint plugin configured with the given tool metadata.
+pub fn get_diags_with_tool_metadata(
+    code: &str,
+    tool_metadata: CairoLintConfig,
+) -> (RootDatabase, Vec<SemanticDiagnostic>) {
+    let (mut db, crate_id) = setup_lint_db(code, tool_metadata);
+    let diags = get_diags(crate_id, &mut db);
+    (db, diags)
+}
+
+/// Runs the analysis of the code like [`get_diags_with_tool_metadata`], and returns the lint registry of
+/// the plugin as well, which keeps the details of the diagnostics, e.g. their notes.
+pub fn get_diags_and_lint_registry(
+    code: &str,
+    tool_metadata: CairoLintConfig,
+) -> (RootDatabase, Vec<SemanticDiagnostic>, LintRegistry) {
+    let plugin = CairoLint::new(true, tool_metadata);
+    let lint_registry = plugin.lint_registry().clone();
+    let (mut db, crate_id) = setup_lint_db_with_plugin(code, plugin);
+    let diags = get_diags(crate_id, &mut db);
+    (db, diags, lint_registry)
+}
+
+/// Gets the messages and severities of the Cairo lint diagnostics, sorted by the message.
+pub fn plugin_diagnostics(code: &str, tool_metadata: CairoLintConfig) -> Vec<(String, Severity)> {
+    let (_db, diags) = get_diags_with_tool_metadata(code, tool_metadata);
+    let mut diags: Vec<_> = diags
+        .iter()
+        .filter_map(|diag| match &diag.kind {
+            SemanticDiagnosticKind::PluginDiagnostic(plugin_diag) => {
+                Some((plugin_diag.message.clone(), diag.severity()))
+            }
+            _ => None,
+        })
+        .collect();
+    diags.sort_by(|a, b| a.0.cmp(&b.0));
+    diags
+}
+
+pub fn tool_metadata(entries: &[(&str, LintConfig)]) -> CairoLintConfig {
+    entries
+        .iter()
+        .map(|(name, config)| (name.to_string(), config.clone()))
+        .collect()
+}
+
+pub fn get_cairo_lint_tool_metadata_with_all_lints_enabled() -> CairoLintConfig {
     let names = get_unique_allowed_names();
     names
         .into_iter()
-        .map(|name| (name.to_string(), true))
+        .map(|name| (name.to_string(), LintConfig::Enabled(true)))
         .collect()
 }
 
@@ -66,20 +147,21 @@ macro_rules! test_lint_fixer {
     test_lint_fixer!(expected_value, @$expected_fix, $is_nested)
   }};
   ($before:ident, @$expected_fix:literal, $is_nested:literal) => {{
-    use ::cairo_lang_utils::Upcast;
     let mut code = String::from($before);
+    let plugin = ::cairo_lint::plugin::CairoLint::new(true, $crate::helpers::get_cairo_lint_tool_metadata_with_all_lints_enabled());
+    // The registry of the plugin knows which lint rule reported each of the diagnostics.
+    let lint_registry = plugin.lint_registry().clone();
     let mut testing_suite = ::cairo_lang_semantic::plugin::PluginSuite::default();
-    testing_suite.add_analyzer_plugin_ex(::std::sync::Arc::new(::cairo_lint::plugin::CairoLint::new(true, $crate::helpers::get_cairo_lint_tool_metadata_with_all_lints_enabled())));
+    testing_suite.add_analyzer_plugin_ex(::std::sync::Arc::new(plugin));
+    testing_suite.add_plugin::<::cairo_lint::plugin::CairoLintAttributes>();
     let mut db = ::cairo_lang_compiler::db::RootDatabase::builder()
       .with_default_plugin_suite(::cairo_lang_semantic::inline_macros::get_default_plugin_suite())
       .with_default_plugin_suite(::cairo_lang_test_plugin::test_plugin_suite())
       .with_default_plugin_suite(testing_suite)
       .build()
       .unwrap();
-    let diags = $crate::helpers::get_diags(
-      ::cairo_lang_semantic::test_utils::setup_test_crate_ex(&db, $before, Some($crate::CRATE_CONFIG), None),
-      &mut db,
-    );
+    let crate_id = ::cairo_lang_semantic::test_utils::setup_test_crate_ex(&db, $before, Some($crate::CRATE_CONFIG), None);
+    let diags = $crate::helpers::get_diags(crate_id, &mut db);
     let semantic_diags: Vec<_> = diags.clone();
     let unused_imports: ::std::collections::HashMap<::cairo_lang_filesystem::ids::FileId, ::std::collections::HashMap<::cairo_lang_syntax::node::SyntaxNode, ::cairo_lint::fixes::ImportFix>> =
       ::cairo_lint::fixes::collect_unused_imports(&db, &semantic_diags);
@@ -91,22 +173,27 @@ macro_rules! test_lint_fixer {
     };
     for diag in diags.iter() {
       if !matches!(diag.kind, ::cairo_lang_semantic::diagnostic::SemanticDiagnosticKind::UnusedImport(_)) {
-        if let Some((fix_node, fix)) = ::cairo_lint::fixes::fix_semantic_diagnostic(&db, &diag) {
-          let span = fix_node.span(db.upcast());
-          fixes.push(::cairo_lint::fixes::Fix {
-            span,
-            suggestion: fix,
-          });
+        if let Some(suggestion) = ::cairo_lint::fixes::fix_semantic_diagnostic_with_lint_registry(&db, &diag, &lint_registry) {
+          fixes.push(suggestion);
         }
       }
     }
-    fixes.sort_by_key(|v| std::cmp::Reverse(v.span.start));
+    let mut edits: Vec<::cairo_lint::fixes::Edit> = fixes.into_iter().flat_map(|fix| fix.edits).collect();
+    edits.sort_by_key(|edit| std::cmp::Reverse(edit.span.start));
     if !$is_nested {
-      for fix in fixes.iter() {
-        code.replace_range(fix.span.to_str_range(), &fix.suggestion);
+      for edit in edits.iter() {
+        code.replace_range(edit.span.to_str_range(), &edit.replacement);
       }
     } else {
-      code = "Contains nested diagnostics can't fix it".to_string();
+      // Nested diagnostics are fixed iteratively, one level at a time.
+      let config = ::cairo_lint::fixer::FixerConfig {
+        unsafe_fixes: true,
+        lint_registry,
+        ..Default::default()
+      };
+      let outcome = ::cairo_lint::fixer::fix_crates(&mut db, &[crate_id], &config);
+      let file_id = ::cairo_lang_defs::db::DefsGroup::module_main_file(&db, ::cairo_lang_defs::ids::ModuleId::CrateRoot(crate_id)).unwrap();
+      code = outcome.files.get(&file_id).cloned().unwrap_or(code);
     }
       ::insta::assert_snapshot!(code, @$expected_fix);
   }};
@@ -119,8 +206,12 @@ macro_rules! test_lint_diagnostics {
     test_lint_diagnostics!(expected_value, @$expected_diagnostics)
   }};
   ($before:ident, @$expected_diagnostics:literal) => {{
+    let plugin = ::cairo_lint::plugin::CairoLint::new(true, $crate::helpers::get_cairo_lint_tool_metadata_with_all_lints_enabled());
+    // The registry of the plugin keeps the notes of the diagnostics.
+    let lint_registry = plugin.lint_registry().clone();
     let mut testing_suite = ::cairo_lang_semantic::plugin::PluginSuite::default();
-    testing_suite.add_analyzer_plugin_ex(::std::sync::Arc::new(::cairo_lint::plugin::CairoLint::new(true, $crate::helpers::get_cairo_lint_tool_metadata_with_all_lints_enabled())));
+    testing_suite.add_analyzer_plugin_ex(::std::sync::Arc::new(plugin));
+    testing_suite.add_plugin::<::cairo_lint::plugin::CairoLintAttributes>();
     let mut db = ::cairo_lang_compiler::db::RootDatabase::builder()
       .with_default_plugin_suite(::cairo_lang_semantic::inline_macros::get_default_plugin_suite())
       .with_default_plugin_suite(::cairo_lang_test_plugin::test_plugin_suite())
@@ -133,7 +224,7 @@ macro_rules! test_lint_diagnostics {
     );
     let formatted_diags = diags
       .into_iter()
-      .map(|diag| ::cairo_lint::diagnostics::format_diagnostic(&diag, &db))
+      .map(|diag| ::cairo_lint::diagnostics::format_diagnostic_with_lint_registry(&diag, &db, &lint_registry))
       .collect::<String>()
       .trim()
       .to_string();
@@ -180,6 +180,10 @@ fn same_condition_with_else_diagnostics() {
     | ...
     |     }
     |_____^
+    note: the same condition is checked here
+     --> lib.cairo:7:15
+        } else if a == b {
+                  ^^^^^^
     ");
 }
 
@@ -208,6 +212,10 @@ fn same_condition_with_else_with_comment_diagnostics() {
     | ...
     |     }
     |_____^
+    note: the same condition is checked here
+     --> lib.cairo:8:15
+        } else if a == b {
+                  ^^^^^^
     ");
 }
 
@@ -238,6 +246,10 @@ fn same_condition_with_boolean_diagnostics() {
     | ...
     |     }
     |_____^
+    note: the same condition is checked here
+     --> lib.cairo:7:15
+        } else if condition {
+                  ^^^^^^^^^
     ");
 }
 
@@ -266,6 +278,10 @@ fn same_condition_with_felt252_diagnostics() {
     | ...
     |     }
     |_____^
+    note: the same condition is checked here
+     --> lib.cairo:8:15
+        } else if str1 == str2 {
+                  ^^^^^^^^^^^^
     ");
 }
 
@@ -295,6 +311,10 @@ fn same_condition_with_struct_diagnostics() {
     | ...
     |     }
     |_____^
+    note: the same condition is checked here
+     --> lib.cairo:13:15
+        } else if p1.x == p2.x && p1.y == p2.y {
+                  ^^^^^^^^^^^^^^^^^^^^^^^^^^^^
     ");
 }
 
@@ -329,6 +349,10 @@ fn same_condition_with_multiple_if_else_diagnostics() {
     | ...
     |     } 
     |_____^
+    note: the same condition is checked here
+     --> lib.cairo:15:13
+        else if str1 == str2 {
+                ^^^^^^^^^^^^
     Plugin diagnostic: Consecutive `if` with the same condition found.
      --> lib.cairo:10:12-17:5
           } else if str1 == str2 {
@@ -336,6 +360,10 @@ fn same_condition_with_multiple_if_else_diagnostics() {
     | ...
     |     } 
     |_____^
+    note: the same condition is checked here
+     --> lib.cairo:12:15
+        } else if str1 == str2 {
+                  ^^^^^^^^^^^^
     Plugin diagnostic: Consecutive `if` with the same condition found.
      --> lib.cairo:8:12-17:5
           } else if str1 == str2 {
@@ -343,6 +371,10 @@ fn same_condition_with_multiple_if_else_diagnostics() {
     | ...
     |     } 
     |_____^
+    note: the same condition is checked here
+     --> lib.cairo:10:15
+        } else if str1 == str2 {
+                  ^^^^^^^^^^^^
     Plugin diagnostic: Consecutive `if` with the same condition found.
      --> lib.cairo:6:5-17:5
           if str1 == str2 {
@@ -350,6 +382,10 @@ fn same_condition_with_multiple_if_else_diagnostics() {
     | ...
     |     } 
     |_____^
+    note: the same condition is checked here
+     --> lib.cairo:8:15
+        } else if str1 == str2 {
+                  ^^^^^^^^^^^^
     ");
 }
 
@@ -410,6 +446,10 @@ fn combined_conditions_with_different_if_diagnostics() {
     | ...
     |     }
     |_____^
+    note: the same condition is checked here
+     --> lib.cairo:9:15
+        } else if x == z {
+                  ^^^^^^
     ");
 }
 
@@ -442,6 +482,10 @@ fn if_with_functions_diagnostics() {
     | ...
     |     }
     |_____^
+    note: the same condition is checked here
+     --> lib.cairo:10:15
+        } else if foo() { 
+                  ^^^^^
     ");
 }
 
@@ -473,6 +517,10 @@ fn greater_lesser_comparison_diagnostics() {
     | ...
     |     }
     |_____^
+    note: the same condition is checked here
+     --> lib.cairo:7:15
+        } else if a > 3 {
+                  ^^^^^
     ");
 }
 
@@ -501,6 +549,10 @@ fn same_conditions_with_literals_and_vars_diagnostics() {
     | ...
     |     }
     |_____^
+    note: the same condition is checked here
+     --> lib.cairo:7:15
+        } else if a == 3 {
+                  ^^^^^^
     ");
 }
 
@@ -529,6 +581,10 @@ fn same_conditions_with_literals_diagnostics() {
     | ...
     |     }
     |_____^
+    note: the same condition is checked here
+     --> lib.cairo:6:15
+        } else if 2 == 3 {
+                  ^^^^^^
     ");
 }
 
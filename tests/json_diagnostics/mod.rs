@@ -0,0 +1,193 @@
+use cairo_lang_semantic::diagnostic::SemanticDiagnosticKind;
+use cairo_lint::diagnostics::{
+    format_diagnostics_as_json_lines, format_diagnostics_as_json_lines_with_lint_registry,
+    JsonDiagnostic, JsonEdit, JsonNote, JsonSpan, JsonSuggestion, LintNoteKind,
+};
+use cairo_lint::fixes::Applicability;
+
+use crate::helpers::{
+    get_cairo_lint_tool_metadata_with_all_lints_enabled, get_diags_and_lint_registry,
+    get_diags_with_tool_metadata,
+};
+
+const DOUBLE_PARENS: &str = r#"
+fn main() {
+    let _a = ((0_u32));
+}
+"#;
+
+const UNKNOWN_LINT_NAME: &str = r#"
+#[deny(panc)]
+fn main() {
+    let _a = 0_u32;
+}
+"#;
+
+const ENUM_WITH_SUFFIX: &str = r#"
+enum Wood {
+    BlackForest,
+    WhiteForest,
+}
+"#;
+
+const EQUAL_OPERANDS: &str = r#"
+fn main() {
+    let a = 1_u32;
+    let _b = a == a;
+}
+"#;
+
+fn parse_json_lines(json_lines: &str) -> Vec<JsonDiagnostic> {
+    json_lines
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect()
+}
+
+#[test]
+fn double_parens_as_json() {
+    let (db, diags) = get_diags_with_tool_metadata(
+        DOUBLE_PARENS,
+        get_cairo_lint_tool_metadata_with_all_lints_enabled(),
+    );
+    let json_lines = format_diagnostics_as_json_lines(&diags, &db);
+    let span = JsonSpan {
+        byte_start: 26,
+        byte_end: 35,
+        line_start: 3,
+        column_start: 14,
+        line_end: 3,
+        column_end: 23,
+    };
+    assert_eq!(
+        parse_json_lines(&json_lines),
+        vec![JsonDiagnostic {
+            code: Some("CL0003".to_string()),
+            lint: Some("double_parens".to_string()),
+            category: Some("style".to_string()),
+            severity: "warning".to_string(),
+            message: "unnecessary double parentheses found. Consider removing them.".to_string(),
+            notes: vec![],
+            file: "lib.cairo".to_string(),
+            span: span.clone(),
+            snippet: "((0_u32))".to_string(),
+            suggestions: vec![JsonSuggestion {
+                title: "Remove the double parentheses".to_string(),
+                applicability: Applicability::MachineApplicable,
+                edits: vec![JsonEdit {
+                    span,
+                    replacement: "0_u32".to_string(),
+                }],
+            }],
+        }]
+    );
+}
+
+#[test]
+fn json_lines_schema() {
+    let (db, diags) = get_diags_with_tool_metadata(
+        DOUBLE_PARENS,
+        get_cairo_lint_tool_metadata_with_all_lints_enabled(),
+    );
+    let json_lines = format_diagnostics_as_json_lines(&diags, &db);
+    let value: serde_json::Value = serde_json::from_str(json_lines.trim_end()).unwrap();
+    assert_eq!(value["lint"], "double_parens");
+    assert_eq!(value["span"]["line_start"], 3);
+    assert_eq!(
+        value["suggestions"][0]["applicability"],
+        "machine_applicable"
+    );
+}
+
+#[test]
+fn lint_with_detailed_message_as_json() {
+    let (db, diags) = get_diags_with_tool_metadata(
+        UNKNOWN_LINT_NAME,
+        get_cairo_lint_tool_metadata_with_all_lints_enabled(),
+    );
+    let json_diagnostics = parse_json_lines(&format_diagnostics_as_json_lines(&diags, &db));
+    assert_eq!(json_diagnostics.len(), 1);
+    assert_eq!(json_diagnostics[0].lint.as_deref(), Some("unknown_lints"));
+    assert_eq!(
+        json_diagnostics[0].message,
+        "Unknown lint name. Did you mean `panic`?"
+    );
+    assert_eq!(json_diagnostics[0].snippet, "panc");
+    assert!(json_diagnostics[0].suggestions.is_empty());
+}
+
+/// The notes are kept by the lint registry of the plugin, so the diagnostics are formatted with it.
+fn json_diagnostics_with_notes(code: &str) -> Vec<JsonDiagnostic> {
+    let (db, diags, lint_registry) =
+        get_diags_and_lint_registry(code, get_cairo_lint_tool_metadata_with_all_lints_enabled());
+    parse_json_lines(&format_diagnostics_as_json_lines_with_lint_registry(
+        &diags,
+        &db,
+        &lint_registry,
+    ))
+}
+
+#[test]
+fn lint_notes_as_json() {
+    let json_diagnostics = json_diagnostics_with_notes(ENUM_WITH_SUFFIX);
+    assert_eq!(json_diagnostics.len(), 1);
+    assert_eq!(
+        json_diagnostics[0].message,
+        "All enum variants are prefixed or suffixed by the same characters."
+    );
+    assert_eq!(
+        json_diagnostics[0].notes,
+        vec![
+            JsonNote {
+                kind: LintNoteKind::Note,
+                message: "all of the variants end with `Forest`".to_string(),
+                span: None,
+            },
+            JsonNote {
+                kind: LintNoteKind::Help,
+                message: "remove the shared words from the names of the variants".to_string(),
+                span: None,
+            },
+        ]
+    );
+}
+
+#[test]
+fn lint_labels_as_json() {
+    let json_diagnostics = json_diagnostics_with_notes(EQUAL_OPERANDS);
+    assert_eq!(json_diagnostics.len(), 1);
+    assert_eq!(json_diagnostics[0].lint.as_deref(), Some("eq_comp_op"));
+    assert_eq!(
+        json_diagnostics[0].notes,
+        vec![JsonNote {
+            kind: LintNoteKind::Note,
+            message: "this operand is identical to the left one".to_string(),
+            span: Some(JsonSpan {
+                byte_start: 50,
+                byte_end: 51,
+                line_start: 4,
+                column_start: 19,
+                line_end: 4,
+                column_end: 20,
+            }),
+        }]
+    );
+}
+
+#[test]
+fn lint_notes_are_not_part_of_the_message() {
+    let (db, diags) = get_diags_with_tool_metadata(
+        ENUM_WITH_SUFFIX,
+        get_cairo_lint_tool_metadata_with_all_lints_enabled(),
+    );
+    let SemanticDiagnosticKind::PluginDiagnostic(plugin_diag) = &diags[0].kind else {
+        panic!("Expected a plugin diagnostic");
+    };
+    assert_eq!(
+        plugin_diag.message,
+        "All enum variants are prefixed or suffixed by the same characters."
+    );
+    // Without the lint registry of the plugin the notes are unknown.
+    let json_diagnostics = parse_json_lines(&format_diagnostics_as_json_lines(&diags, &db));
+    assert!(json_diagnostics[0].notes.is_empty());
+}
@@ -0,0 +1,263 @@
+use cairo_lang_diagnostics::Severity;
+use cairo_lint::config::LintLevel;
+
+use crate::helpers::{plugin_diagnostics, tool_metadata};
+use crate::test_lint_diagnostics;
+
+const DENY_PANIC: &str = r#"
+#[deny(panic)]
+fn main() {
+    panic!("panic");
+}
+"#;
+
+const WARN_CLONE_ON_COPY: &str = r#"
+#[warn(clone_on_copy)]
+fn main() {
+    let a: felt252 = 'hello';
+    let _b = a.clone();
+}
+"#;
+
+const NEAREST_ATTRIBUTE: &str = r#"
+#[allow(clone_on_copy)]
+mod inner {
+    #[deny(clone_on_copy)]
+    fn main() {
+        let a: felt252 = 'hello';
+        let _b = a.clone();
+    }
+}
+"#;
+
+const DENY_CATEGORY: &str = r#"
+#[deny(complexity)]
+fn main() {
+    let a: felt252 = 'hello';
+    let _b = a.clone();
+}
+"#;
+
+const FULFILLED_EXPECTATION: &str = r#"
+#[expect(clone_on_copy)]
+fn main() {
+    let a: felt252 = 'hello';
+    let _b = a.clone();
+}
+"#;
+
+const UNFULFILLED_EXPECTATION: &str = r#"
+#[expect(clone_on_copy)]
+fn main() {
+    let a: felt252 = 'hello';
+    let _b = a;
+}
+"#;
+
+const PARTIALLY_FULFILLED_EXPECTATION: &str = r#"
+#[expect(clone_on_copy, panic)]
+fn main() {
+    let a: felt252 = 'hello';
+    let _b = a.clone();
+}
+"#;
+
+const FULFILLED_EXPECTATION_ON_MODULE: &str = r#"
+#[expect(clone_on_copy)]
+mod inner {
+    mod nested {
+        fn main() {
+            let a: felt252 = 'hello';
+            let _b = a.clone();
+        }
+    }
+}
+"#;
+
+const UNFULFILLED_EXPECTATION_ON_MODULE: &str = r#"
+#[expect(clone_on_copy)]
+mod inner {
+    fn main() {
+        let a: felt252 = 'hello';
+        let _b = a;
+    }
+}
+"#;
+
+const CLONE_ON_COPY_MESSAGE: &str =
+    "using `clone` on type `core::felt252` which implements `Copy` trait; use `a` instead";
+const PANIC_MESSAGE: &str = "Leaving `panic!` in the code is discouraged.";
+const UNFULFILLED_EXPECTATION_MESSAGE: &str =
+    "This lint expectation is unfulfilled. Consider removing the `expect` attribute.";
+
+#[test]
+fn deny_attribute_enables_disabled_lint() {
+    let diags = plugin_diagnostics(DENY_PANIC, tool_metadata(&[]));
+    assert_eq!(diags, vec![(PANIC_MESSAGE.to_string(), Severity::Error)]);
+}
+
+#[test]
+fn warn_attribute_overrides_configured_level() {
+    let diags = plugin_diagnostics(
+        WARN_CLONE_ON_COPY,
+        tool_metadata(&[("clone_on_copy", LintLevel::Deny.into())]),
+    );
+    assert_eq!(
+        diags,
+        vec![(CLONE_ON_COPY_MESSAGE.to_string(), Severity::Warning)]
+    );
+}
+
+#[test]
+fn forbidden_lint_ignores_warn_attribute() {
+    let diags = plugin_diagnostics(
+        WARN_CLONE_ON_COPY,
+        tool_metadata(&[("clone_on_copy", LintLevel::Forbid.into())]),
+    );
+    assert_eq!(
+        diags,
+        vec![(CLONE_ON_COPY_MESSAGE.to_string(), Severity::Error)]
+    );
+}
+
+#[test]
+fn nearest_attribute_takes_precedence() {
+    let diags = plugin_diagnostics(NEAREST_ATTRIBUTE, tool_metadata(&[]));
+    assert_eq!(
+        diags,
+        vec![(CLONE_ON_COPY_MESSAGE.to_string(), Severity::Error)]
+    );
+}
+
+#[test]
+fn deny_attribute_with_category() {
+    let diags = plugin_diagnostics(DENY_CATEGORY, tool_metadata(&[]));
+    assert_eq!(
+        diags,
+        vec![(CLONE_ON_COPY_MESSAGE.to_string(), Severity::Error)]
+    );
+}
+
+#[test]
+fn fulfilled_expectation_diagnostics() {
+    test_lint_diagnostics!(FULFILLED_EXPECTATION, @r"
+    ");
+}
+
+#[test]
+fn unfulfilled_expectation_diagnostics() {
+    test_lint_diagnostics!(UNFULFILLED_EXPECTATION, @r"
+    Plugin diagnostic: This lint expectation is unfulfilled. Consider removing the `expect` attribute.
+     --> lib.cairo:2:1
+    #[expect(clone_on_copy)]
+    ^^^^^^^^^^^^^^^^^^^^^^^^
+    ");
+}
+
+#[test]
+fn fulfilled_expectation_on_module() {
+    let diags = plugin_diagnostics(FULFILLED_EXPECTATION_ON_MODULE, tool_metadata(&[]));
+    assert!(diags.is_empty());
+}
+
+#[test]
+fn unfulfilled_expectation_on_module() {
+    let diags = plugin_diagnostics(UNFULFILLED_EXPECTATION_ON_MODULE, tool_metadata(&[]));
+    assert_eq!(
+        diags,
+        vec![(
+            UNFULFILLED_EXPECTATION_MESSAGE.to_string(),
+            Severity::Warning
+        )]
+    );
+}
+
+#[test]
+fn partially_fulfilled_expectation() {
+    let diags = plugin_diagnostics(PARTIALLY_FULFILLED_EXPECTATION, tool_metadata(&[]));
+    assert_eq!(
+        diags,
+        vec![(
+            UNFULFILLED_EXPECTATION_MESSAGE.to_string(),
+            Severity::Warning
+        )]
+    );
+}
+
+const UNUSED_ALLOW: &str = r#"
+#[allow(clone_on_copy)]
+fn main() {
+    let a: felt252 = 'hello';
+    let _b = a;
+}
+"#;
+
+const UNKNOWN_LINT_NAMES: &str = r#"
+#[allow(clone_on_cpy, some_compiler_lint)]
+#[deny(panc, not_a_lint)]
+fn main() {
+    let a: felt252 = 'hello';
+    let _b = a;
+}
+"#;
+
+const UNUSED_ALLOW_MESSAGE: &str = "This lint is not suppressed anywhere in the scope of the `allow` attribute. Consider removing it from the attribute.";
+
+#[test]
+fn unused_allow_diagnostics() {
+    test_lint_diagnostics!(UNUSED_ALLOW, @r"
+    Plugin diagnostic: This lint is not suppressed anywhere in the scope of the `allow` attribute. Consider removing it from the attribute.
+     --> lib.cairo:2:9
+    #[allow(clone_on_copy)]
+            ^^^^^^^^^^^^^
+    ");
+}
+
+#[test]
+fn unused_allow_is_disabled_by_default() {
+    let diags = plugin_diagnostics(UNUSED_ALLOW, tool_metadata(&[]));
+    assert!(diags.is_empty());
+}
+
+#[test]
+fn unused_allow_enabled() {
+    let diags = plugin_diagnostics(
+        UNUSED_ALLOW,
+        tool_metadata(&[("unused_lint_allows", true.into())]),
+    );
+    assert_eq!(
+        diags,
+        vec![(UNUSED_ALLOW_MESSAGE.to_string(), Severity::Warning)]
+    );
+}
+
+#[test]
+fn used_allow_is_not_reported() {
+    let diags = plugin_diagnostics(
+        FULFILLED_EXPECTATION.replace("expect", "allow").as_str(),
+        tool_metadata(&[("unused_lint_allows", true.into())]),
+    );
+    assert!(diags.is_empty());
+}
+
+#[test]
+fn unknown_lint_names() {
+    let diags = plugin_diagnostics(
+        UNKNOWN_LINT_NAMES,
+        tool_metadata(&[("unknown_lints", true.into())]),
+    );
+    assert_eq!(
+        diags,
+        vec![
+            ("Unknown lint name.".to_string(), Severity::Warning),
+            (
+                "Unknown lint name. Did you mean `clone_on_copy`?".to_string(),
+                Severity::Warning
+            ),
+            (
+                "Unknown lint name. Did you mean `panic`?".to_string(),
+                Severity::Warning
+            ),
+        ]
+    );
+}
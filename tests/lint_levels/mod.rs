@@ -0,0 +1,214 @@
+use cairo_lang_diagnostics::Severity;
+use cairo_lint::config::{LintConfig, LintLevel};
+use cairo_lint::plugin::cairo_lint_plugin_suite;
+use cairo_lint::CairoLintToolMetadata;
+
+use crate::helpers::{plugin_diagnostics, tool_metadata as metadata};
+
+const CLONE_AND_PANIC: &str = r#"
+fn main() {
+    let a: felt252 = 'hello';
+    let _b = a.clone();
+    panic!("panic");
+}
+"#;
+
+const ALLOWED_CLONE: &str = r#"
+#[allow(clone_on_copy)]
+fn main() {
+    let a: felt252 = 'hello';
+    let _b = a.clone();
+}
+"#;
+
+const WARNED_CLONE: &str = r#"
+#[warn(clone_on_copy)]
+fn main() {
+    let a: felt252 = 'hello';
+    let _b = a.clone();
+}
+"#;
+
+const WARNED_PANIC: &str = r#"
+#[warn(panic)]
+fn main() {
+    panic!("panic");
+}
+"#;
+
+const CLONE_ON_COPY_MESSAGE: &str =
+    "using `clone` on type `core::felt252` which implements `Copy` trait; use `a` instead";
+const PANIC_MESSAGE: &str = "Leaving `panic!` in the code is discouraged.";
+
+#[test]
+fn default_levels() {
+    let diags = plugin_diagnostics(CLONE_AND_PANIC, metadata(&[]));
+    assert_eq!(
+        diags,
+        vec![(CLONE_ON_COPY_MESSAGE.to_string(), Severity::Warning)]
+    );
+}
+
+#[test]
+fn legacy_tool_metadata() {
+    let tool_metadata = CairoLintToolMetadata::from([
+        ("clone_on_copy".to_string(), false),
+        ("panic".to_string(), true),
+    ]);
+    assert!(cairo_lint_plugin_suite(tool_metadata.clone()).is_ok());
+    let diags = plugin_diagnostics(CLONE_AND_PANIC, tool_metadata.into());
+    assert_eq!(diags, vec![(PANIC_MESSAGE.to_string(), Severity::Warning)]);
+}
+
+#[test]
+fn boolean_levels() {
+    let diags = plugin_diagnostics(
+        CLONE_AND_PANIC,
+        metadata(&[("clone_on_copy", false.into()), ("panic", true.into())]),
+    );
+    assert_eq!(diags, vec![(PANIC_MESSAGE.to_string(), Severity::Warning)]);
+}
+
+#[test]
+fn deny_level() {
+    let diags = plugin_diagnostics(
+        CLONE_AND_PANIC,
+        metadata(&[
+            ("clone_on_copy", LintLevel::Deny.into()),
+            ("panic", LintLevel::Warn.into()),
+        ]),
+    );
+    assert_eq!(
+        diags,
+        vec![
+            (PANIC_MESSAGE.to_string(), Severity::Warning),
+            (CLONE_ON_COPY_MESSAGE.to_string(), Severity::Error),
+        ]
+    );
+}
+
+#[test]
+fn allow_level() {
+    let diags = plugin_diagnostics(
+        CLONE_AND_PANIC,
+        metadata(&[("clone_on_copy", LintLevel::Allow.into())]),
+    );
+    assert!(diags.is_empty());
+}
+
+#[test]
+fn deny_level_suppressed_by_allow_attribute() {
+    let diags = plugin_diagnostics(
+        ALLOWED_CLONE,
+        metadata(&[("clone_on_copy", LintLevel::Deny.into())]),
+    );
+    assert!(diags.is_empty());
+}
+
+#[test]
+fn forbid_level_ignores_allow_attribute() {
+    let diags = plugin_diagnostics(
+        ALLOWED_CLONE,
+        metadata(&[("clone_on_copy", LintLevel::Forbid.into())]),
+    );
+    assert_eq!(
+        diags,
+        vec![(CLONE_ON_COPY_MESSAGE.to_string(), Severity::Error)]
+    );
+}
+
+#[test]
+fn disabled_lint_enabled_by_warn_attribute() {
+    let diags = plugin_diagnostics(WARNED_CLONE, metadata(&[("clone_on_copy", false.into())]));
+    assert_eq!(
+        diags,
+        vec![(CLONE_ON_COPY_MESSAGE.to_string(), Severity::Warning)]
+    );
+}
+
+#[test]
+fn allow_attribute_of_disabled_lint_is_used() {
+    let diags = plugin_diagnostics(
+        ALLOWED_CLONE,
+        metadata(&[
+            ("clone_on_copy", false.into()),
+            ("unused_lint_allows", true.into()),
+        ]),
+    );
+    assert!(diags.is_empty());
+}
+
+#[test]
+fn disabled_item_lint_enabled_by_warn_attribute() {
+    let diags = plugin_diagnostics(WARNED_PANIC, metadata(&[]));
+    assert_eq!(diags, vec![(PANIC_MESSAGE.to_string(), Severity::Warning)]);
+}
+
+#[test]
+fn disabled_lints_are_not_reported_without_attributes() {
+    let diags = plugin_diagnostics(
+        CLONE_AND_PANIC,
+        metadata(&[("clone_on_copy", false.into()), ("panic", false.into())]),
+    );
+    assert!(diags.is_empty());
+}
+
+#[test]
+fn unknown_level_is_rejected() {
+    let result = cairo_lint_plugin_suite(metadata(&[(
+        "clone_on_copy",
+        LintConfig::Level("error".to_string()),
+    )]));
+    let err = result.unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Invalid configuration of the lint 'clone_on_copy' in `Scarb.toml`: Unknown lint level 'error'. Expected one of: allow, warn, deny, forbid."
+    );
+}
+
+const ALLOWED_CLONE_BY_CATEGORY: &str = r#"
+#[allow(complexity)]
+fn main() {
+    let a: felt252 = 'hello';
+    let _b = a.clone();
+}
+"#;
+
+#[test]
+fn category_level() {
+    let diags = plugin_diagnostics(
+        CLONE_AND_PANIC,
+        metadata(&[("complexity", false.into()), ("pedantic", true.into())]),
+    );
+    assert_eq!(diags, vec![(PANIC_MESSAGE.to_string(), Severity::Warning)]);
+}
+
+#[test]
+fn lint_level_overrides_category_level() {
+    let diags = plugin_diagnostics(
+        CLONE_AND_PANIC,
+        metadata(&[
+            ("complexity", LintLevel::Allow.into()),
+            ("clone_on_copy", LintLevel::Deny.into()),
+        ]),
+    );
+    assert_eq!(
+        diags,
+        vec![(CLONE_ON_COPY_MESSAGE.to_string(), Severity::Error)]
+    );
+}
+
+#[test]
+fn category_suppressed_by_allow_attribute() {
+    let diags = plugin_diagnostics(ALLOWED_CLONE_BY_CATEGORY, metadata(&[]));
+    assert!(diags.is_empty());
+}
+
+#[test]
+fn category_names_are_valid_metadata() {
+    assert!(cairo_lint_plugin_suite(metadata(&[
+        ("perf", true.into()),
+        ("pedantic", LintLevel::Deny.into()),
+    ]))
+    .is_ok());
+}
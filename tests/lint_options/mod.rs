@@ -0,0 +1,236 @@
+use cairo_lint::config::{
+    ComparisonOperator, EnumVariantNamesOptions, LintConfig, LintLevel, LintOptions,
+};
+use cairo_lint::context::find_lint_by_diagnostic_message;
+use cairo_lint::plugin::{cairo_lint_plugin_suite, CairoLint};
+use cairo_lint::{get_fixed_file_content, get_fixes};
+use serde_json::json;
+
+use crate::helpers::{get_diags_with_tool_metadata, plugin_diagnostics, tool_metadata as metadata};
+
+const ENUM_WITH_TWO_VARIANTS: &str = r#"
+enum Cake {
+    BlackForestCake,
+    HummingbirdCake,
+}
+"#;
+
+const ENUM_WITH_PREFIX_AND_SUFFIX: &str = r#"
+enum Cake {
+    DoubleBlackForestCake,
+    DoubleHummingbirdCake,
+}
+"#;
+
+const PANIC_WITH_FELT: &str = r#"
+fn main() {
+    panic!("panic");
+}
+
+fn foo() {
+    core::panic_with_felt252('panic');
+}
+"#;
+
+const WHILE_COMPARISONS: &str = r#"
+fn main() {
+    let mut a = 1_u32;
+    while a <= 10 {
+        a += 1;
+    }
+    while a < 20 {
+        a += 1;
+    }
+}
+"#;
+
+fn options(value: serde_json::Value) -> LintConfig {
+    serde_json::from_value(value).unwrap()
+}
+
+/// The messages of the diagnostics emitted by the lint rule with the given id.
+fn lint_messages(code: &str, entries: &[(&str, LintConfig)], id: &str) -> Vec<String> {
+    plugin_diagnostics(code, metadata(entries))
+        .into_iter()
+        .map(|(message, _)| message)
+        .filter(|message| {
+            find_lint_by_diagnostic_message(message).is_some_and(|lint| lint.id() == id)
+        })
+        .collect()
+}
+
+#[test]
+fn options_table_is_parsed() {
+    let config = options(json!({ "level": "warn", "min_variants": 3 }));
+    assert_eq!(config.level().unwrap(), Some(LintLevel::Warn));
+    let mut lint_options = LintOptions::default();
+    lint_options
+        .set("enum_variant_names", config.options().unwrap())
+        .unwrap();
+    assert_eq!(
+        lint_options.enum_variant_names,
+        EnumVariantNamesOptions {
+            min_variants: 3,
+            ..Default::default()
+        }
+    );
+}
+
+#[test]
+fn options_table_without_level_keeps_the_level() {
+    let config = options(json!({ "min_variants": 3 }));
+    assert_eq!(config.level().unwrap(), None);
+    let plugin = CairoLint::new(false, metadata(&[("enum_variant_names", config)]));
+    assert_eq!(plugin.lint_options().enum_variant_names.min_variants, 3);
+    assert!(lint_messages(
+        ENUM_WITH_TWO_VARIANTS,
+        &[("enum_variant_names", options(json!({ "min_variants": 2 })))],
+        "CL0042"
+    )
+    .is_empty());
+}
+
+#[test]
+fn enum_variant_names_min_variants() {
+    let reported = lint_messages(
+        ENUM_WITH_TWO_VARIANTS,
+        &[("enum_variant_names", true.into())],
+        "CL0042",
+    );
+    assert_eq!(reported.len(), 1);
+    let reported = lint_messages(
+        ENUM_WITH_TWO_VARIANTS,
+        &[(
+            "enum_variant_names",
+            options(json!({ "level": "warn", "min_variants": 3 })),
+        )],
+        "CL0042",
+    );
+    assert!(reported.is_empty());
+}
+
+#[test]
+fn enum_variant_names_without_suffix_check() {
+    let reported = lint_messages(
+        ENUM_WITH_TWO_VARIANTS,
+        &[(
+            "enum_variant_names",
+            options(json!({ "level": "warn", "check_suffix": false })),
+        )],
+        "CL0042",
+    );
+    assert!(reported.is_empty());
+}
+
+#[test]
+fn enum_variant_names_fix_without_suffix_check() {
+    let (db, diags) = get_diags_with_tool_metadata(
+        ENUM_WITH_PREFIX_AND_SUFFIX,
+        metadata(&[(
+            "enum_variant_names",
+            options(json!({ "level": "warn", "check_suffix": false })),
+        )]),
+    );
+    let (file_id, fixes) = get_fixes(&db, diags).into_iter().next().unwrap();
+    assert_eq!(
+        get_fixed_file_content(file_id, fixes, &db, true).unwrap(),
+        ENUM_WITH_PREFIX_AND_SUFFIX.replace("Double", "")
+    );
+}
+
+#[test]
+fn panic_functions() {
+    let reported = lint_messages(PANIC_WITH_FELT, &[("panic", true.into())], "CL0018");
+    assert_eq!(
+        reported,
+        vec!["Leaving `panic!` in the code is discouraged."]
+    );
+    let reported = lint_messages(
+        PANIC_WITH_FELT,
+        &[(
+            "panic",
+            options(json!({ "level": "warn", "functions": ["core::panic_with_felt252"] })),
+        )],
+        "CL0018",
+    );
+    assert_eq!(
+        reported,
+        vec!["Leaving `panic_with_felt252` in the code is discouraged."]
+    );
+}
+
+#[test]
+fn inefficient_while_comp_operators() {
+    let reported = lint_messages(
+        WHILE_COMPARISONS,
+        &[("inefficient_while_comp", true.into())],
+        "CL0040",
+    );
+    assert_eq!(reported.len(), 2);
+    let reported = lint_messages(
+        WHILE_COMPARISONS,
+        &[(
+            "inefficient_while_comp",
+            options(json!({ "level": "warn", "operators": ["<"] })),
+        )],
+        "CL0040",
+    );
+    assert_eq!(
+        reported,
+        vec!["using `<` in the exit condition is inefficient. Consider switching to `!=` or using ArrayTrait::multi_pop_front."]
+    );
+}
+
+#[test]
+fn default_options() {
+    let lint_options = LintOptions::default();
+    assert_eq!(lint_options.enum_variant_names.min_variants, 2);
+    assert_eq!(
+        lint_options.inefficient_while_comp.operators,
+        ComparisonOperator::ALL.to_vec()
+    );
+    assert_eq!(
+        lint_options.panic.functions,
+        vec!["core::panics::panic", "core::panics::panic_with_byte_array"]
+    );
+}
+
+#[test]
+fn level_only_table_is_accepted() {
+    let config = options(json!({ "level": "deny" }));
+    assert_eq!(config.options(), None);
+    assert!(cairo_lint_plugin_suite(metadata(&[("bool_comparison", config)])).is_ok());
+}
+
+#[test]
+fn invalid_options_are_rejected() {
+    let err = cairo_lint_plugin_suite(metadata(&[(
+        "enum_variant_names",
+        options(json!({ "min_variant": 3 })),
+    )]))
+    .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Invalid configuration of the lint 'enum_variant_names' in `Scarb.toml`: unknown field `min_variant`, expected one of `min_variants`, `check_prefix`, `check_suffix`"
+    );
+
+    let err = cairo_lint_plugin_suite(metadata(&[(
+        "inefficient_while_comp",
+        options(json!({ "operators": ["=="] })),
+    )]))
+    .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Invalid configuration of the lint 'inefficient_while_comp' in `Scarb.toml`: unknown variant `==`, expected one of `<`, `<=`, `>`, `>=`"
+    );
+
+    let err = cairo_lint_plugin_suite(metadata(&[(
+        "bool_comparison",
+        options(json!({ "level": "deny", "strict": true })),
+    )]))
+    .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Invalid configuration of the lint 'bool_comparison' in `Scarb.toml`: The lint does not accept any options."
+    );
+}
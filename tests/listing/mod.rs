@@ -0,0 +1,82 @@
+use cairo_lint::config::CallPatternConfig;
+use cairo_lint::context::{get_unique_allowed_names, CairoLintKind, LintRegistry};
+use cairo_lint::listing::{format_lint_list_as_json, list_lints};
+
+#[test]
+fn lists_all_built_in_lints_sorted_by_name() {
+    let entries = list_lints(&LintRegistry::default());
+    let names = entries.iter().map(|entry| entry.name).collect::<Vec<_>>();
+    let mut expected_names = get_unique_allowed_names();
+    expected_names.sort();
+    assert_eq!(names, expected_names);
+}
+
+#[test]
+fn lint_entry_metadata() {
+    let entries = list_lints(&LintRegistry::default());
+    let entry = entries
+        .iter()
+        .find(|entry| entry.name == "manual_ok_or")
+        .unwrap();
+    assert_eq!(entry.id, "CL0020");
+    assert_eq!(entry.kind, CairoLintKind::ManualOkOr);
+    assert_eq!(entry.category, "style");
+    assert!(entry.enabled_by_default);
+    assert_eq!(entry.default_level, "warn");
+    assert!(entry.fixable);
+    assert_eq!(
+        entry.message,
+        "Manual match for Option<T> detected. Consider using ok_or instead"
+    );
+    assert_eq!(
+        entry.docs.description,
+        "Checks for manual implementations of ok_or."
+    );
+}
+
+#[test]
+fn lists_call_pattern_rules() {
+    let mut lint_registry = LintRegistry::default();
+    lint_registry
+        .register_call_pattern(CallPatternConfig {
+            name: "no_unsafe_helper".to_string(),
+            function: "test::unsafe_helper".to_string(),
+            message: "Calling `unsafe_helper` is forbidden.".to_string(),
+            level: None,
+            category: None,
+            modules: Vec::new(),
+            excluded_modules: Vec::new(),
+        })
+        .unwrap();
+    let entries = list_lints(&lint_registry);
+    let entry = entries
+        .iter()
+        .find(|entry| entry.name == "no_unsafe_helper")
+        .unwrap();
+    assert_eq!(entry.kind, CairoLintKind::CallPattern);
+    assert!(!entry.fixable);
+    assert_eq!(
+        entry.docs.description,
+        "Calling `unsafe_helper` is forbidden."
+    );
+}
+
+#[test]
+fn lint_list_as_json() {
+    let json = format_lint_list_as_json(&LintRegistry::default());
+    let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+    let entry = value
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|entry| entry["name"] == "panic")
+        .unwrap();
+    assert_eq!(entry["id"], "CL0018");
+    assert_eq!(entry["kind"], "Panic");
+    assert_eq!(entry["category"], "pedantic");
+    assert_eq!(entry["enabled_by_default"], false);
+    assert_eq!(entry["default_level"], "allow");
+    assert_eq!(entry["fixable"], false);
+    assert_eq!(entry["docs"]["description"], "Checks for panic usages.");
+    assert_eq!(entry["docs"]["rationale"], serde_json::Value::Null);
+}
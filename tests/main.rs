@@ -1,7 +1,13 @@
+mod baseline;
 mod bitwise_for_parity_check;
 mod bool_comparison;
 mod breaks;
+mod call_patterns;
+mod changed_lines;
 mod clone_on_copy;
+mod code_actions;
+mod custom_lints;
+mod deprecated_lint_names;
 mod double_comparison;
 mod double_parens;
 mod duplicate_underscore_args;
@@ -9,16 +15,26 @@ mod empty_enum_brackets_variant;
 mod enum_variant_names;
 mod eq_op;
 mod erasing_operations;
+mod explain;
+mod fixer;
 mod helpers;
 mod ifs;
 mod int_operations;
+mod json_diagnostics;
+mod lint_attributes;
+mod lint_levels;
+mod lint_options;
+mod listing;
 mod loops;
 mod manual;
 mod panic;
+mod parallel;
 mod performance;
 mod redundant_brackets_in_enum_call;
 mod redundant_op;
+mod sarif;
 mod single_match;
+mod suggestions;
 mod unused_imports;
 mod unused_variables;
 
@@ -206,7 +206,7 @@ fn main() {
 #[test]
 fn test_basic_manual_assert_diagnostics() {
     test_lint_diagnostics!(TEST_BASIC_MANUAL_ASSERT, @r#"
-    Plugin diagnostic: Leaving `panic` in the code is discouraged.
+    Plugin diagnostic: Leaving `panic!` in the code is discouraged.
      --> lib.cairo:5:9
             panic!("a shouldn't be equal to 5");
             ^^^^^
@@ -233,7 +233,7 @@ fn test_basic_manual_assert_fixer() {
 #[test]
 fn test_basic_manual_assert_allowed_diagnostics() {
     test_lint_diagnostics!(TEST_BASIC_MANUAL_ASSERT_ALLOWED, @r#"
-    Plugin diagnostic: Leaving `panic` in the code is discouraged.
+    Plugin diagnostic: Leaving `panic!` in the code is discouraged.
      --> lib.cairo:6:9
             panic!("a shouldn't be equal to 5");
             ^^^^^
@@ -256,7 +256,7 @@ fn test_basic_manual_assert_allowed_fixer() {
 #[test]
 fn test_basic_manual_assert_with_tail_diagnostics() {
     test_lint_diagnostics!(TEST_MANUAL_ASSERT_WITH_TAIL, @r#"
-    Plugin diagnostic: Leaving `panic` in the code is discouraged.
+    Plugin diagnostic: Leaving `panic!` in the code is discouraged.
      --> lib.cairo:5:9
             panic!("a shouldn't be equal to 5")
             ^^^^^
@@ -283,7 +283,7 @@ fn test_basic_manual_assert_with_tail_fixer() {
 #[test]
 fn test_basic_manual_assert_with_tail_allowed_diagnostics() {
     test_lint_diagnostics!(TEST_MANUAL_ASSERT_WITH_TAIL_ALLOWED, @r#"
-    Plugin diagnostic: Leaving `panic` in the code is discouraged.
+    Plugin diagnostic: Leaving `panic!` in the code is discouraged.
      --> lib.cairo:6:9
             panic!("a shouldn't be equal to 5")
             ^^^^^
@@ -306,7 +306,7 @@ fn test_basic_manual_assert_with_tail_allowed_fixer() {
 #[test]
 fn test_basic_manual_assert_with_other_exprs_diagnostics() {
     test_lint_diagnostics!(TEST_MANUAL_ASSERT_WITH_OTHER_EXPRS, @r#"
-    Plugin diagnostic: Leaving `panic` in the code is discouraged.
+    Plugin diagnostic: Leaving `panic!` in the code is discouraged.
      --> lib.cairo:6:9
             panic!("a shouldn't be equal to 5");
             ^^^^^
@@ -330,7 +330,7 @@ fn test_basic_manual_assert_with_other_exprs_fixer() {
 #[test]
 fn test_basic_manual_assert_with_other_exprs_and_tail_diagnostics() {
     test_lint_diagnostics!(TEST_MANUAL_ASSERT_WITH_OTHER_EXPRS_AND_TAIL, @r#"
-    Plugin diagnostic: Leaving `panic` in the code is discouraged.
+    Plugin diagnostic: Leaving `panic!` in the code is discouraged.
      --> lib.cairo:6:9
             panic!("a shouldn't be equal to 5")
             ^^^^^
@@ -353,7 +353,7 @@ fn test_basic_manual_assert_with_other_exprs_and_tail_fixer() {
 #[test]
 fn test_manual_assert_with_multiple_panic_args_diagnostics() {
     test_lint_diagnostics!(TEST_MANUAL_ASSERT_WITH_MULTIPLE_PANIC_ARGS, @r#"
-    Plugin diagnostic: Leaving `panic` in the code is discouraged.
+    Plugin diagnostic: Leaving `panic!` in the code is discouraged.
      --> lib.cairo:5:9
             panic!("a shouldn't be equal to {}", a);
             ^^^^^
@@ -380,7 +380,7 @@ fn test_manual_assert_with_multiple_panic_args_fixer() {
 #[test]
 fn test_manual_assert_with_multiple_panic_args_allowed_diagnostics() {
     test_lint_diagnostics!(TEST_MANUAL_ASSERT_WITH_MULTIPLE_PANIC_ARGS_ALLOWED, @r#"
-    Plugin diagnostic: Leaving `panic` in the code is discouraged.
+    Plugin diagnostic: Leaving `panic!` in the code is discouraged.
      --> lib.cairo:6:9
             panic!("a shouldn't be equal to {}", a);
             ^^^^^
@@ -403,7 +403,7 @@ fn test_manual_assert_with_multiple_panic_args_allowed_fixer() {
 #[test]
 fn test_manual_assert_with_multiple_panic_args_and_tail_diagnostics() {
     test_lint_diagnostics!(TEST_MANUAL_ASSERT_WITH_MULTIPLE_PANIC_ARGS_AND_TAIL, @r#"
-    Plugin diagnostic: Leaving `panic` in the code is discouraged.
+    Plugin diagnostic: Leaving `panic!` in the code is discouraged.
      --> lib.cairo:5:9
             panic!("a shouldn't be equal to {}", a)
             ^^^^^
@@ -430,7 +430,7 @@ fn test_manual_assert_with_multiple_panic_args_and_tail_fixer() {
 #[test]
 fn test_manual_assert_with_multiple_panic_args_and_tail_allowed_diagnostics() {
     test_lint_diagnostics!(TEST_MANUAL_ASSERT_WITH_MULTIPLE_PANIC_ARGS_AND_TAIL_ALLOWED, @r#"
-    Plugin diagnostic: Leaving `panic` in the code is discouraged.
+    Plugin diagnostic: Leaving `panic!` in the code is discouraged.
      --> lib.cairo:6:9
             panic!("a shouldn't be equal to {}", a)
             ^^^^^
@@ -453,7 +453,7 @@ fn test_manual_assert_with_multiple_panic_args_and_tail_allowed_fixer() {
 #[test]
 fn test_manual_assert_with_more_than_one_statements_diagnostics() {
     test_lint_diagnostics!(TEST_MANUAL_ASSERT_WITH_MORE_THAN_ONE_STATEMENTS, @r#"
-  Plugin diagnostic: Leaving `panic` in the code is discouraged.
+  Plugin diagnostic: Leaving `panic!` in the code is discouraged.
    --> lib.cairo:5:9
           panic!("a shouldn't be equal to 5");
           ^^^^^
@@ -480,7 +480,7 @@ fn test_manual_assert_with_more_than_one_statements_fixer() {
 #[test]
 fn test_manual_assert_with_more_than_one_statements_before_panic_diagnostics() {
     test_lint_diagnostics!(TEST_MANUAL_ASSERT_WITH_MORE_THAN_ONE_STATEMENTS_BEFORE_PANIC, @r#"
-    Plugin diagnostic: Leaving `panic` in the code is discouraged.
+    Plugin diagnostic: Leaving `panic!` in the code is discouraged.
      --> lib.cairo:6:9
             panic!("a shouldn't be equal to 5");
             ^^^^^
@@ -503,7 +503,7 @@ fn test_manual_assert_with_more_than_one_statements_before_panic_fixer() {
 #[test]
 fn test_manual_assert_with_else_block_diagnostics() {
     test_lint_diagnostics!(TEST_MANUAL_ASSERT_WITH_ELSE_BLOCK, @r#"
-    Plugin diagnostic: Leaving `panic` in the code is discouraged.
+    Plugin diagnostic: Leaving `panic!` in the code is discouraged.
      --> lib.cairo:5:9
             panic!("a shouldn't be equal to 5");
             ^^^^^
@@ -531,7 +531,7 @@ fn test_manual_assert_with_else_block_fixer() {
 #[test]
 fn test_manual_assert_within_else_block_diagnostics() {
     test_lint_diagnostics!(TEST_MANUAL_ASSERT_WITHIN_ELSE_BLOCK, @r#"
-    Plugin diagnostic: Leaving `panic` in the code is discouraged.
+    Plugin diagnostic: Leaving `panic!` in the code is discouraged.
      --> lib.cairo:7:9
             panic!("a should be equal to 5");
             ^^^^^
@@ -559,7 +559,7 @@ fn test_manual_assert_within_else_block_fixer() {
 #[test]
 fn test_manual_assert_with_else_block_multiple_statements_diagnostics() {
     test_lint_diagnostics!(TEST_MANUAL_ASSERT_WITH_ELSE_BLOCK_MULTIPLE_STATEMENTS, @r#"
-    Plugin diagnostic: Leaving `panic` in the code is discouraged.
+    Plugin diagnostic: Leaving `panic!` in the code is discouraged.
      --> lib.cairo:5:9
             panic!("a shouldn't be equal to 5");
             ^^^^^
@@ -589,7 +589,7 @@ fn test_manual_assert_with_else_block_multiple_statements_fixer() {
 #[test]
 fn test_manual_assert_within_else_block_statements_diagnostics() {
     test_lint_diagnostics!(TEST_MANUAL_ASSERT_WITHIN_ELSE_BLOCK_STATEMENTS, @r#"
-    Plugin diagnostic: Leaving `panic` in the code is discouraged.
+    Plugin diagnostic: Leaving `panic!` in the code is discouraged.
      --> lib.cairo:9:9
             panic!("a should be equal to 5");
             ^^^^^
@@ -619,7 +619,7 @@ fn test_manual_assert_within_else_block_statements_fixer() {
 #[test]
 fn test_manual_assert_with_panic_in_if_block_diagnostics() {
     test_lint_diagnostics!(TEST_MANUAL_ASSERT_WITH_PANIC_IN_IF_BLOCK, @r#"
-    Plugin diagnostic: Leaving `panic` in the code is discouraged.
+    Plugin diagnostic: Leaving `panic!` in the code is discouraged.
      --> lib.cairo:5:9
             panic!("a shouldn't be 5");
             ^^^^^
@@ -651,7 +651,7 @@ fn test_manual_assert_with_panic_in_if_block_fixer() {
 #[test]
 fn test_manual_assert_with_panic_in_else_if_block_diagnostics() {
     test_lint_diagnostics!(TEST_MANUAL_ASSERT_WITH_PANIC_IN_ELSE_IF_BLOCK, @r#"
-    Plugin diagnostic: Leaving `panic` in the code is discouraged.
+    Plugin diagnostic: Leaving `panic!` in the code is discouraged.
      --> lib.cairo:7:9
             panic!("a shouldn't be greater than 5");
             ^^^^^
@@ -683,7 +683,7 @@ fn test_manual_assert_with_panic_in_else_if_block_fixer() {
 #[test]
 fn test_manual_assert_with_panic_in_else_block_diagnostics() {
     test_lint_diagnostics!(TEST_MANUAL_ASSERT_WITH_PANIC_IN_ELSE_BLOCK, @r#"
-    Plugin diagnostic: Leaving `panic` in the code is discouraged.
+    Plugin diagnostic: Leaving `panic!` in the code is discouraged.
      --> lib.cairo:9:9
             panic!("a shouldn't be less than 5");
             ^^^^^
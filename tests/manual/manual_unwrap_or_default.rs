@@ -264,7 +264,7 @@ fn main() {
 #[test]
 fn manual_unwrap_or_default_for_if_let_with_default_diagnostics() {
     test_lint_diagnostics!(MANUAL_UNWRAP_OR_DEFAULT_FOR_IF_LET_WITH_DEFAULT, @r"
-    Plugin diagnostic: This can be done in one call with `.unwrap_or_default()`
+    Plugin diagnostic: This can be done in one call with `a.unwrap_or_default()`
      --> lib.cairo:5:3-9:3
         if let Option::Some(v) = a {
      ___^
@@ -288,7 +288,7 @@ fn manual_unwrap_or_default_for_if_let_with_default_fixer() {
 #[test]
 fn manual_unwrap_or_default_for_if_let_with_empty_string_diagnostics() {
     test_lint_diagnostics!(MANUAL_UNWRAP_OR_DEFAULT_FOR_IF_LET_WITH_EMPTY_STRING, @r"
-    Plugin diagnostic: This can be done in one call with `.unwrap_or_default()`
+    Plugin diagnostic: This can be done in one call with `x.unwrap_or_default()`
      --> lib.cairo:5:3-9:3
         if let Option::Some(v) = x {
      ___^
@@ -312,7 +312,7 @@ fn manual_unwrap_or_default_for_if_let_with_empty_string_fixer() {
 #[test]
 fn manual_unwrap_or_default_for_if_let_with_new_diagnostics() {
     test_lint_diagnostics!(MANUAL_UNWRAP_OR_DEFAULT_FOR_IF_LET_WITH_NEW, @r"
-    Plugin diagnostic: This can be done in one call with `.unwrap_or_default()`
+    Plugin diagnostic: This can be done in one call with `x.unwrap_or_default()`
      --> lib.cairo:5:3-9:3
         if let Option::Some(v) = x {
      ___^
@@ -336,7 +336,7 @@ fn manual_unwrap_or_default_for_if_let_with_new_fixer() {
 #[test]
 fn manual_unwrap_or_default_for_if_let_with_zero_integer_diagnostics() {
     test_lint_diagnostics!(MANUAL_UNWRAP_OR_DEFAULT_FOR_IF_LET_WITH_ZERO_INTEGER, @r"
-    Plugin diagnostic: This can be done in one call with `.unwrap_or_default()`
+    Plugin diagnostic: This can be done in one call with `x.unwrap_or_default()`
      --> lib.cairo:5:3-9:3
         if let Option::Some(v) = x {
      ___^
@@ -360,7 +360,7 @@ fn manual_unwrap_or_default_for_if_let_with_zero_integer_fixer() {
 #[test]
 fn manual_unwrap_or_default_for_if_let_with_fixed_array_diagnostics() {
     test_lint_diagnostics!(MANUAL_UNWRAP_OR_DEFAULT_FOR_IF_LET_WITH_FIXED_ARRAY, @r"
-    Plugin diagnostic: This can be done in one call with `.unwrap_or_default()`
+    Plugin diagnostic: This can be done in one call with `a.unwrap_or_default()`
      --> lib.cairo:5:3-9:3
         if let Option::Some(v) = a {
      ___^
@@ -384,7 +384,7 @@ fn manual_unwrap_or_default_for_if_let_with_fixed_array_fixer() {
 #[test]
 fn manual_unwrap_or_default_for_if_let_with_tuple_diagnostics() {
     test_lint_diagnostics!(MANUAL_UNWRAP_OR_DEFAULT_FOR_IF_LET_WITH_TUPLE, @r"
-    Plugin diagnostic: This can be done in one call with `.unwrap_or_default()`
+    Plugin diagnostic: This can be done in one call with `a.unwrap_or_default()`
      --> lib.cairo:5:3-9:3
         if let Option::Some(v) = a {
      ___^
@@ -408,7 +408,7 @@ fn manual_unwrap_or_default_for_if_let_with_tuple_fixer() {
 #[test]
 fn manual_unwrap_or_default_for_if_let_with_array_diagnostics() {
     test_lint_diagnostics!(MANUAL_UNWRAP_OR_DEFAULT_FOR_IF_LET_WITH_ARRAY, @r"
-    Plugin diagnostic: This can be done in one call with `.unwrap_or_default()`
+    Plugin diagnostic: This can be done in one call with `x.unwrap_or_default()`
      --> lib.cairo:5:3-9:3
         if let Option::Some(v) = x {
      ___^
@@ -432,7 +432,7 @@ fn manual_unwrap_or_default_for_if_let_with_array_fixer() {
 #[test]
 fn manual_unwrap_or_default_for_if_let_with_comments_diagnostics() {
     test_lint_diagnostics!(MANUAL_UNWRAP_OR_DEFAULT_FOR_IF_LET_WITH_COMMENTS, @r"
-    Plugin diagnostic: This can be done in one call with `.unwrap_or_default()`
+    Plugin diagnostic: This can be done in one call with `a.unwrap_or_default()`
      --> lib.cairo:5:3-11:3
         if let Option::Some(v) = a {
      ___^
@@ -539,7 +539,7 @@ fn manual_unwrap_or_default_for_match_with_tuple_without_trigger_fixer() {
 #[test]
 fn manual_unwrap_or_default_for_match_with_zero_integer_diagnostics() {
     test_lint_diagnostics!(MANUAL_UNWRAP_OR_DEFAULT_FOR_MATCH_WITH_ZERO_INTEGER, @r"
-    Plugin diagnostic: This can be done in one call with `.unwrap_or_default()`
+    Plugin diagnostic: This can be done in one call with `x.unwrap_or_default()`
      --> lib.cairo:5:3-8:3
         match x {
      ___^
@@ -563,7 +563,7 @@ fn manual_unwrap_or_default_for_match_with_zero_integer_fixer() {
 #[test]
 fn manual_unwrap_or_default_for_match_with_empty_string_diagnostics() {
     test_lint_diagnostics!(MANUAL_UNWRAP_OR_DEFAULT_FOR_MATCH_WITH_EMPTY_STRING, @r"
-    Plugin diagnostic: This can be done in one call with `.unwrap_or_default()`
+    Plugin diagnostic: This can be done in one call with `x.unwrap_or_default()`
      --> lib.cairo:5:3-8:3
         match x {
      ___^
@@ -587,7 +587,7 @@ fn manual_unwrap_or_default_for_match_with_empty_string_fixer() {
 #[test]
 fn manual_unwrap_or_default_for_match_with_default_diagnostic() {
     test_lint_diagnostics!(MANUAL_UNWRAP_OR_DEFAULT_FOR_MATCH_WITH_DEFAULT, @r"
-    Plugin diagnostic: This can be done in one call with `.unwrap_or_default()`
+    Plugin diagnostic: This can be done in one call with `a.unwrap_or_default()`
      --> lib.cairo:5:3-8:3
         match a {
      ___^
@@ -611,7 +611,7 @@ fn manual_unwrap_or_default_for_match_with_default_fixer() {
 #[test]
 fn manual_unwrap_or_default_for_match_with_new_diagnostic() {
     test_lint_diagnostics!(MANUAL_UNWRAP_OR_DEFAULT_FOR_MATCH_WITH_NEW, @r"
-    Plugin diagnostic: This can be done in one call with `.unwrap_or_default()`
+    Plugin diagnostic: This can be done in one call with `x.unwrap_or_default()`
      --> lib.cairo:5:3-8:3
         match x {
      ___^
@@ -635,7 +635,7 @@ fn manual_unwrap_or_default_for_match_with_new_fixer() {
 #[test]
 fn manual_unwrap_or_default_for_match_with_fixed_array_diagnostic() {
     test_lint_diagnostics!(MANUAL_UNWRAP_OR_DEFAULT_FOR_MATCH_WITH_FIXED_ARRAY, @r"
-    Plugin diagnostic: This can be done in one call with `.unwrap_or_default()`
+    Plugin diagnostic: This can be done in one call with `x.unwrap_or_default()`
      --> lib.cairo:5:3-8:3
         match x {
      ___^
@@ -659,7 +659,7 @@ fn manual_unwrap_or_default_for_match_with_fixed_array_fixer() {
 #[test]
 fn manual_unwrap_or_default_for_match_with_tuple_diagnostics() {
     test_lint_diagnostics!(MANUAL_UNWRAP_OR_DEFAULT_FOR_MATCH_WITH_TUPLE, @r"
-    Plugin diagnostic: This can be done in one call with `.unwrap_or_default()`
+    Plugin diagnostic: This can be done in one call with `x.unwrap_or_default()`
      --> lib.cairo:5:3-8:3
         match x {
      ___^
@@ -683,7 +683,7 @@ fn manual_unwrap_or_default_for_match_with_tuple_fixer() {
 #[test]
 fn manual_unwrap_or_default_for_match_with_array_diagnostics() {
     test_lint_diagnostics!(MANUAL_UNWRAP_OR_DEFAULT_FOR_MATCH_WITH_ARRAY, @r"
-    Plugin diagnostic: This can be done in one call with `.unwrap_or_default()`
+    Plugin diagnostic: This can be done in one call with `x.unwrap_or_default()`
      --> lib.cairo:5:3-8:3
         match x {
      ___^
@@ -102,7 +102,7 @@ fn main() {
 #[test]
 fn single_panic_diagnostics() {
     test_lint_diagnostics!(SINGLE_PANIC, @r#"
-    Plugin diagnostic: Leaving `panic` in the code is discouraged.
+    Plugin diagnostic: Leaving `panic!` in the code is discouraged.
      --> lib.cairo:3:3
       panic!("panic");
       ^^^^^
@@ -121,15 +121,15 @@ fn single_panic_fixer() {
 #[test]
 fn multiple_panic_diagnostics() {
     test_lint_diagnostics!(MULTIPLE_PANIC, @r#"
-    Plugin diagnostic: Leaving `panic` in the code is discouraged.
+    Plugin diagnostic: Leaving `panic!` in the code is discouraged.
      --> lib.cairo:3:3
       panic!("panic");
       ^^^^^
-    Plugin diagnostic: Leaving `panic` in the code is discouraged.
+    Plugin diagnostic: Leaving `panic!` in the code is discouraged.
      --> lib.cairo:4:3
       panic!("panic 2");
       ^^^^^
-    Plugin diagnostic: Leaving `panic` in the code is discouraged.
+    Plugin diagnostic: Leaving `panic!` in the code is discouraged.
      --> lib.cairo:5:3
       panic!("panic 3");
       ^^^^^
@@ -150,11 +150,11 @@ fn multiple_panic_fixer() {
 #[test]
 fn multiple_panic_and_other_macros_diagnostics() {
     test_lint_diagnostics!(MULTIPLE_PANIC_AND_OTHER_MACROS, @r#"
-    Plugin diagnostic: Leaving `panic` in the code is discouraged.
+    Plugin diagnostic: Leaving `panic!` in the code is discouraged.
      --> lib.cairo:3:3
       panic!("panic");
       ^^^^^
-    Plugin diagnostic: Leaving `panic` in the code is discouraged.
+    Plugin diagnostic: Leaving `panic!` in the code is discouraged.
      --> lib.cairo:4:3
       panic!("panic 2");
       ^^^^^
@@ -175,7 +175,7 @@ fn multiple_panic_and_other_macros_fixer() {
 #[test]
 fn empty_panic_diagnostics() {
     test_lint_diagnostics!(EMPTY_PANIC, @r#"
-    Plugin diagnostic: Leaving `panic` in the code is discouraged.
+    Plugin diagnostic: Leaving `panic!` in the code is discouraged.
      --> lib.cairo:3:3
       panic!("");
       ^^^^^
@@ -260,7 +260,7 @@ fn no_panic_fixer() {
 #[test]
 fn panic_inside_function_diagnostics() {
     test_lint_diagnostics!(PANIC_INSIDE_FUNCTION, @r#"
-    Plugin diagnostic: Leaving `panic` in the code is discouraged.
+    Plugin diagnostic: Leaving `panic!` in the code is discouraged.
      --> lib.cairo:4:3
       panic!("panic Alan");
       ^^^^^
@@ -301,7 +301,7 @@ fn multiple_panic_allowed_in_function_fixer() {
 #[test]
 fn panic_in_trait_function_diagnostics() {
     test_lint_diagnostics!(PANIC_IN_TRAIT_FUNCTION, @r#"
-    Plugin diagnostic: Leaving `panic` in the code is discouraged.
+    Plugin diagnostic: Leaving `panic!` in the code is discouraged.
      --> lib.cairo:7:9
             panic!("This is a panic in a trait function");
             ^^^^^
@@ -311,7 +311,7 @@ fn panic_in_trait_function_diagnostics() {
 #[test]
 fn panic_with_args_diagnostics() {
     test_lint_diagnostics!(PANIC_WITH_ARGS, @r#"
-    Plugin diagnostic: Leaving `panic` in the code is discouraged.
+    Plugin diagnostic: Leaving `panic!` in the code is discouraged.
      --> lib.cairo:4:5
         panic!("a shouldn't be equal to {}", a);
         ^^^^^
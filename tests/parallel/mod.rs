@@ -0,0 +1,128 @@
+use cairo_lang_compiler::db::RootDatabase;
+use cairo_lang_semantic::SemanticDiagnostic;
+use cairo_lint::diagnostics::{format_diagnostic, format_diagnostic_with_lint_registry};
+use cairo_lint::parallel::{collect_diagnostics, collect_diagnostics_in_parallel};
+use cairo_lint::plugin::CairoLint;
+
+use crate::helpers::{
+    get_cairo_lint_tool_metadata_with_all_lints_enabled, init_corelib, setup_lint_db,
+    setup_lint_db_with_plugin,
+};
+
+const MANY_MODULES: &str = r#"
+mod first {
+    fn main() {
+        let _a = ((0_u32));
+    }
+}
+
+mod second {
+    fn main() {
+        let a: felt252 = 'hello';
+        let _b = a.clone();
+    }
+
+    mod nested {
+        fn main() {
+            let x = true;
+            if x == false {
+                return;
+            }
+        }
+    }
+}
+
+mod third {
+    fn main() {
+        let _a = ((0_u32));
+    }
+}
+"#;
+
+/// A module with the level attributes used by its submodules, and with the diagnostics carrying notes.
+fn module_with_attributes(index: usize) -> String {
+    format!(
+        r#"
+#[allow(double_parens)]
+mod module_{index} {{
+    fn main() {{
+        let _a = ((0_u32));
+        let a = 1_u32;
+        let _b = a == a;
+    }}
+
+    #[allow(clone_on_copy)]
+    mod nested {{
+        fn main() {{
+            let _a = ((0_u32));
+        }}
+    }}
+
+    enum Wood {{
+        BlackForest,
+        WhiteForest,
+    }}
+}}
+"#
+    )
+}
+
+/// Collects the diagnostics of the code and formats them together with their notes.
+fn diagnostics_with_notes(code: &str, in_parallel: bool) -> Vec<String> {
+    let plugin = CairoLint::new(true, get_cairo_lint_tool_metadata_with_all_lints_enabled());
+    let lint_registry = plugin.lint_registry().clone();
+    let (mut db, crate_id) = setup_lint_db_with_plugin(code, plugin);
+    init_corelib(&mut db);
+    let diagnostics = if in_parallel {
+        collect_diagnostics_in_parallel(&db, &[crate_id])
+    } else {
+        collect_diagnostics(&db, &[crate_id])
+    };
+    diagnostics
+        .iter()
+        .map(|diagnostic| format_diagnostic_with_lint_registry(diagnostic, &db, &lint_registry))
+        .collect()
+}
+
+fn format_diagnostics(db: &RootDatabase, diagnostics: &[SemanticDiagnostic]) -> Vec<String> {
+    diagnostics
+        .iter()
+        .map(|diagnostic| format_diagnostic(diagnostic, db))
+        .collect()
+}
+
+#[test]
+fn parallel_diagnostics_are_the_same_as_sequential_ones() {
+    let tool_metadata = get_cairo_lint_tool_metadata_with_all_lints_enabled();
+
+    let (mut db, crate_id) = setup_lint_db(MANY_MODULES, tool_metadata.clone());
+    init_corelib(&mut db);
+    let sequential = format_diagnostics(&db, &collect_diagnostics(&db, &[crate_id]));
+
+    let (mut db, crate_id) = setup_lint_db(MANY_MODULES, tool_metadata);
+    init_corelib(&mut db);
+    let parallel = format_diagnostics(&db, &collect_diagnostics_in_parallel(&db, &[crate_id]));
+
+    assert!(!sequential.is_empty());
+    assert_eq!(sequential, parallel);
+}
+
+#[test]
+fn parallel_diagnostics_with_attributes_and_notes_are_the_same_as_sequential_ones() {
+    let code = (0..16).map(module_with_attributes).collect::<String>();
+
+    let sequential = diagnostics_with_notes(&code, false);
+    let parallel = diagnostics_with_notes(&code, true);
+
+    // The `allow` attributes of the modules apply to their submodules as well, unlike the ones of the nested modules.
+    assert!(sequential
+        .iter()
+        .any(|diagnostic| diagnostic.contains("not suppressed anywhere")));
+    assert!(sequential
+        .iter()
+        .all(|diagnostic| !diagnostic.contains("double parentheses")));
+    assert!(sequential
+        .iter()
+        .any(|diagnostic| diagnostic.contains("note: this operand is identical to the left one")));
+    assert_eq!(sequential, parallel);
+}
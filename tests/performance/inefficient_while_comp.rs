@@ -62,7 +62,7 @@ fn main() {
 #[test]
 fn while_loop_exit_condition_less_than_diagnostics() {
     test_lint_diagnostics!(WHILE_LOOP_EXIT_CONDITION_LESS_THAN, @r"
-    Plugin diagnostic: using [`<`, `<=`, `>=`, `>`] exit conditions is inefficient. Consider switching to `!=` or using ArrayTrait::multi_pop_front.
+    Plugin diagnostic: using `<` in the exit condition is inefficient. Consider switching to `!=` or using ArrayTrait::multi_pop_front.
      --> lib.cairo:4:11
         while a < 10 {
               ^^^^^^
@@ -84,7 +84,7 @@ fn while_loop_exit_condition_less_than_fixer() {
 #[test]
 fn while_loop_exit_condition_less_than_or_equal_diagnostics() {
     test_lint_diagnostics!(WHILE_LOOP_EXIT_CONDITION_LESS_THAN_OR_EQUAL, @r"
-    Plugin diagnostic: using [`<`, `<=`, `>=`, `>`] exit conditions is inefficient. Consider switching to `!=` or using ArrayTrait::multi_pop_front.
+    Plugin diagnostic: using `<=` in the exit condition is inefficient. Consider switching to `!=` or using ArrayTrait::multi_pop_front.
      --> lib.cairo:4:11
         while a <= 10 {
               ^^^^^^^
@@ -125,7 +125,7 @@ fn while_loop_exit_condition_less_than_or_equal_allowed_fixer() {
 #[test]
 fn while_loop_exit_condition_greater_than_diagnostics() {
     test_lint_diagnostics!(WHILE_LOOP_EXIT_CONDITION_GREATER_THAN, @r"
-    Plugin diagnostic: using [`<`, `<=`, `>=`, `>`] exit conditions is inefficient. Consider switching to `!=` or using ArrayTrait::multi_pop_front.
+    Plugin diagnostic: using `>` in the exit condition is inefficient. Consider switching to `!=` or using ArrayTrait::multi_pop_front.
      --> lib.cairo:4:11
         while a > 0 {
               ^^^^^
@@ -147,7 +147,7 @@ fn while_loop_exit_condition_greater_than_fixer() {
 #[test]
 fn while_loop_exit_condition_greater_than_or_equal_diagnostics() {
     test_lint_diagnostics!(WHILE_LOOP_EXIT_CONDITION_GREATER_THAN_OR_EQUAL, @r"
-    Plugin diagnostic: using [`<`, `<=`, `>=`, `>`] exit conditions is inefficient. Consider switching to `!=` or using ArrayTrait::multi_pop_front.
+    Plugin diagnostic: using `>=` in the exit condition is inefficient. Consider switching to `!=` or using ArrayTrait::multi_pop_front.
      --> lib.cairo:4:11
         while a >= 0 {
               ^^^^^^
@@ -169,11 +169,11 @@ fn while_loop_exit_condition_greater_than_or_equal_fixer() {
 #[test]
 fn while_loop_exit_condition_nested_diagnostics() {
     test_lint_diagnostics!(WHILE_LOOP_EXIT_CONDITION_NESTED, @r"
-    Plugin diagnostic: using [`<`, `<=`, `>=`, `>`] exit conditions is inefficient. Consider switching to `!=` or using ArrayTrait::multi_pop_front.
+    Plugin diagnostic: using `<` in the exit condition is inefficient. Consider switching to `!=` or using ArrayTrait::multi_pop_front.
      --> lib.cairo:5:11
         while a < 10 && b < 5 {
               ^^^^^^
-    Plugin diagnostic: using [`<`, `<=`, `>=`, `>`] exit conditions is inefficient. Consider switching to `!=` or using ArrayTrait::multi_pop_front.
+    Plugin diagnostic: using `<` in the exit condition is inefficient. Consider switching to `!=` or using ArrayTrait::multi_pop_front.
      --> lib.cairo:5:21
         while a < 10 && b < 5 {
                         ^^^^^
@@ -183,6 +183,15 @@ fn while_loop_exit_condition_nested_diagnostics() {
 #[test]
 fn while_loop_exit_condition_nested_fixer() {
     test_lint_fixer!(WHILE_LOOP_EXIT_CONDITION_NESTED, @r#"
-    Contains nested diagnostics can't fix it
+    fn main() {
+        let mut a = 0_u32;
+        let mut b = 0_u32;
+        while a < 10 && b < 5 {
+            a += 1;
+            if a % 2 == 0 {
+                b += 1
+            }
+        }
+    }
     "#, true);
 }
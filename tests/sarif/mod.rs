@@ -0,0 +1,138 @@
+use cairo_lint::context::get_unique_allowed_names;
+use cairo_lint::sarif::format_diagnostics_as_sarif_with_lint_registry;
+use serde_json::Value;
+
+use crate::helpers::{
+    get_cairo_lint_tool_metadata_with_all_lints_enabled, get_diags_and_lint_registry,
+};
+
+const DOUBLE_PARENS: &str = r#"
+fn main() {
+    let _a = ((0_u32));
+}
+"#;
+
+const EQUAL_OPERANDS: &str = r#"
+fn main() {
+    let a = 1_u32;
+    let _b = a == a;
+}
+"#;
+
+const ENUM_WITH_SUFFIX: &str = r#"
+enum Wood {
+    BlackForest,
+    WhiteForest,
+}
+"#;
+
+fn sarif_report(code: &str) -> Value {
+    let (db, diags, lint_registry) =
+        get_diags_and_lint_registry(code, get_cairo_lint_tool_metadata_with_all_lints_enabled());
+    serde_json::from_str(&format_diagnostics_as_sarif_with_lint_registry(
+        &diags,
+        &db,
+        &lint_registry,
+    ))
+    .unwrap()
+}
+
+#[test]
+fn sarif_rules() {
+    let report = sarif_report(DOUBLE_PARENS);
+    assert_eq!(report["version"], "2.1.0");
+
+    let driver = &report["runs"][0]["tool"]["driver"];
+    assert_eq!(driver["name"], "cairo-lint");
+    let rules = driver["rules"].as_array().unwrap();
+    assert_eq!(rules.len(), get_unique_allowed_names().len());
+
+    let rule = rules
+        .iter()
+        .find(|rule| rule["name"] == "double_parens")
+        .unwrap();
+    assert_eq!(rule["id"], "CL0003");
+    assert_eq!(
+        rule["shortDescription"]["text"],
+        "unnecessary double parentheses found. Consider removing them."
+    );
+    assert_eq!(
+        rule["fullDescription"]["text"],
+        "Checks for unnecessary double parentheses in expressions."
+    );
+    assert_eq!(
+        rule["help"]["text"],
+        "Checks for unnecessary double parentheses in expressions."
+    );
+    assert!(rule["help"]["markdown"].as_str().unwrap().starts_with(
+        "## What it does\n\nChecks for unnecessary double parentheses in expressions."
+    ));
+    assert_eq!(
+        rule["helpUri"],
+        "https://docs.swmansion.com/cairo-lint/docs/lints/double_parens.html"
+    );
+    assert_eq!(rule["defaultConfiguration"]["enabled"], true);
+    assert_eq!(rule["properties"]["category"], "style");
+
+    let panic_rule = rules.iter().find(|rule| rule["name"] == "panic").unwrap();
+    assert_eq!(panic_rule["defaultConfiguration"]["enabled"], false);
+}
+
+#[test]
+fn sarif_results() {
+    let report = sarif_report(DOUBLE_PARENS);
+    let run = &report["runs"][0];
+    let results = run["results"].as_array().unwrap();
+    assert_eq!(results.len(), 1);
+
+    let result = &results[0];
+    assert_eq!(result["ruleId"], "CL0003");
+    let rule_index = result["ruleIndex"].as_u64().unwrap() as usize;
+    assert_eq!(
+        run["tool"]["driver"]["rules"][rule_index]["name"],
+        "double_parens"
+    );
+    assert_eq!(result["level"], "warning");
+
+    let location = &result["locations"][0]["physicalLocation"];
+    assert_eq!(location["artifactLocation"]["uri"], "lib.cairo");
+    assert_eq!(location["region"]["startLine"], 3);
+    assert_eq!(location["region"]["startColumn"], 14);
+    assert_eq!(location["region"]["byteOffset"], 26);
+    assert_eq!(location["region"]["byteLength"], 9);
+
+    let fix = &result["fixes"][0];
+    assert_eq!(fix["description"]["text"], "Remove the double parentheses");
+    let replacement = &fix["artifactChanges"][0]["replacements"][0];
+    assert_eq!(replacement["deletedRegion"]["byteOffset"], 26);
+    assert_eq!(replacement["insertedContent"]["text"], "0_u32");
+}
+
+#[test]
+fn sarif_labels_are_related_locations() {
+    let report = sarif_report(EQUAL_OPERANDS);
+    let result = &report["runs"][0]["results"][0];
+    let related_location = &result["relatedLocations"][0];
+    assert_eq!(
+        related_location["message"]["text"],
+        "this operand is identical to the left one"
+    );
+    let region = &related_location["physicalLocation"]["region"];
+    assert_eq!(region["startLine"], 4);
+    assert_eq!(region["startColumn"], 19);
+    assert_eq!(region["byteOffset"], 50);
+    assert_eq!(region["byteLength"], 1);
+}
+
+#[test]
+fn sarif_notes_are_part_of_the_message() {
+    let report = sarif_report(ENUM_WITH_SUFFIX);
+    let result = &report["runs"][0]["results"][0];
+    assert_eq!(
+        result["message"]["text"],
+        "All enum variants are prefixed or suffixed by the same characters.\n\
+         note: all of the variants end with `Forest`\n\
+         help: remove the shared words from the names of the variants"
+    );
+    assert!(result.get("relatedLocations").is_none());
+}
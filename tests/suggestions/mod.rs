@@ -0,0 +1,83 @@
+use cairo_lang_filesystem::span::{TextOffset, TextSpan, TextWidth};
+use cairo_lint::fixes::{Applicability, Edit, Suggestion};
+use cairo_lint::get_fixes;
+
+use crate::helpers::{
+    get_cairo_lint_tool_metadata_with_all_lints_enabled, get_diags_with_tool_metadata,
+};
+
+const DOUBLE_PARENS_AND_EMPTY_VARIANT: &str = r#"
+#[derive(Drop)]
+enum MyEnum {
+    Data: u8,
+    Empty: (),
+}
+
+fn main() -> u32 {
+    ((0))
+}
+"#;
+
+fn offset(text: &str) -> TextOffset {
+    TextOffset::default().add_width(TextWidth::from_str(text))
+}
+
+fn span(start: &str, end: &str) -> TextSpan {
+    TextSpan {
+        start: offset(start),
+        end: offset(end),
+    }
+}
+
+#[test]
+fn suggestions_have_titles_and_applicability() {
+    let (db, diags) = get_diags_with_tool_metadata(
+        DOUBLE_PARENS_AND_EMPTY_VARIANT,
+        get_cairo_lint_tool_metadata_with_all_lints_enabled(),
+    );
+    let mut suggestions: Vec<_> = get_fixes(&db, diags)
+        .into_values()
+        .flatten()
+        .map(|suggestion| (suggestion.title, suggestion.applicability))
+        .collect();
+    suggestions.sort();
+    assert_eq!(
+        suggestions,
+        vec![
+            (
+                "Remove the double parentheses".to_string(),
+                Applicability::MachineApplicable
+            ),
+            (
+                "Remove the empty parentheses from the enum variant".to_string(),
+                Applicability::MaybeIncorrect
+            ),
+        ]
+    );
+}
+
+#[test]
+fn apply_multiple_edits() {
+    let mut text = "let a = b;".to_string();
+    let suggestion = Suggestion::new(
+        "Rename",
+        vec![
+            Edit::replace(span("let ", "let a"), "x"),
+            Edit::insert(offset("let a = b"), ".clone()"),
+        ],
+    );
+    suggestion.apply(&mut text);
+    assert_eq!(text, "let x = b.clone();");
+}
+
+#[test]
+fn overlapping_suggestions() {
+    let outer = Suggestion::new("Outer", vec![Edit::replace(span("", "let a = b;"), "")]);
+    let inner = Suggestion::new("Inner", vec![Edit::replace(span("let ", "let a"), "x")]);
+    let after = Suggestion::new("After", vec![Edit::insert(offset("let a = b;"), "\n")])
+        .with_applicability(Applicability::HasPlaceholders);
+    assert!(outer.overlaps(&inner));
+    assert!(inner.overlaps(&outer));
+    assert!(!inner.overlaps(&after));
+    assert!(!outer.overlaps(&after));
+}
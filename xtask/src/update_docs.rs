@@ -1,5 +1,6 @@
 use anyhow::Result;
 use cairo_lint::context::find_lint_by_struct_name;
+use cairo_lint::explain::LintExplanation;
 use clap::Parser;
 use serde::Serialize;
 use serde_json::{ser::PrettyFormatter, Serializer, Value};
@@ -15,13 +16,16 @@ static LINT_DOCS_BASE_PATH: &str = "website/docs/lints/";
 #[derive(Debug, Serialize)]
 struct LintDoc {
     name: String,
-    docs: Option<String>,
+    docs: String,
     enabled: bool,
     source_link: String,
 }
 
 impl LintDoc {
-    pub fn from_rustdoc_json_item(value: &Value) -> Self {
+    /// Creates the documentation of the predefined lint rule implemented by the rustdoc item.
+    /// Returns `None` for the implementations which are not predefined rules, e.g. the call pattern rules
+    /// created from the user configuration.
+    pub fn from_rustdoc_json_item(value: &Value) -> Option<Self> {
         let lint_struct_name = value
             .pointer("/inner/impl/for/resolved_path/path")
             .unwrap()
@@ -35,24 +39,19 @@ impl LintDoc {
             .unwrap()
             .to_string();
         let struct_start_line = value.pointer("/span/begin/0").unwrap().as_u64().unwrap();
-        let lint = find_lint_by_struct_name(&lint_struct_name).unwrap_or_else(|| {
-            panic!(
-                "Could not find the lint inside the Lint Context: {}",
+        let Some(lint) = find_lint_by_struct_name(&lint_struct_name) else {
+            println!(
+                "Skipping {}, as it is not a predefined lint rule",
                 lint_struct_name
-            )
-        });
-        LintDoc {
+            );
+            return None;
+        };
+        Some(LintDoc {
             name: lint.allowed_name().to_string(),
-            docs: value.get("docs").and_then(|doc| {
-                if doc.is_null() {
-                    None
-                } else {
-                    Some(doc.as_str().unwrap().to_string())
-                }
-            }),
+            docs: LintExplanation::new(&**lint).docs_markdown(),
             enabled: lint.is_enabled(),
             source_link: format!("{}{}#L{}", LINT_REPO_BASE_URL, filename, struct_start_line),
-        }
+        })
     }
 }
 
@@ -105,13 +104,12 @@ pub fn main(_: Args) -> Result<()> {
     // Write docs content inside the markdown file inside the website docs directory.
     for doc in docs.iter() {
         let doc_path = format!("{}{}.md", LINT_DOCS_BASE_PATH, doc.name);
-        let doc_content = doc.docs.clone().unwrap_or(String::new());
         let enabled_text = if doc.enabled { "Enabled" } else { "Disabled" };
         fs::write(
             &doc_path,
             format!(
-                "# {}\n\nDefault: **{enabled_text}**\n\n[Source Code]({})\n\n{}\n",
-                doc.name, doc.source_link, doc_content
+                "# {}\n\nDefault: **{enabled_text}**\n\n[Source Code]({})\n\n{}",
+                doc.name, doc.source_link, doc.docs
             ),
         )
         .unwrap();
@@ -156,7 +154,7 @@ fn get_docs_as_json() -> anyhow::Result<Vec<LintDoc>> {
                         .pointer("/inner/impl/trait/path")
                         .is_some_and(|path| path == "Lint")
                 })
-                .map(LintDoc::from_rustdoc_json_item)
+                .filter_map(LintDoc::from_rustdoc_json_item)
                 .collect());
         }
     }